@@ -0,0 +1,33 @@
+use criterion::{Criterion, black_box, criterion_group, criterion_main};
+use std::collections::HashMap;
+use verdure_context::{ConfigManager, ConfigSource};
+
+fn manager() -> ConfigManager {
+    let manager = ConfigManager::new();
+    let props = HashMap::from([
+        ("server.port".to_string(), "8080".to_string()),
+        ("database.pool.size".to_string(), "16".to_string()),
+    ]);
+    manager.add_source(ConfigSource::Properties(props)).unwrap();
+    // Consulted first on every cache miss, without providing the keys
+    manager.add_source(ConfigSource::Environment).unwrap();
+    manager
+}
+
+fn config_read(c: &mut Criterion) {
+    let cached = manager();
+    c.bench_function("get_cached", |b| {
+        b.iter(|| cached.get(black_box("server.port")))
+    });
+
+    let uncached = manager();
+    c.bench_function("get_uncached", |b| {
+        b.iter(|| {
+            uncached.invalidate_cache();
+            uncached.get(black_box("database.pool.size"))
+        })
+    });
+}
+
+criterion_group!(benches, config_read);
+criterion_main!(benches);
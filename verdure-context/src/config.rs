@@ -4,15 +4,24 @@
 //! It supports hierarchical configuration sources, property binding, type-safe configuration
 //! access, and integration with environment profiles.
 
+mod binder;
+mod remote;
+
+pub use remote::{DynamicProvider, RemoteConfigProvider};
+
 use std::any::TypeId;
 use crate::error::{ContextError, ContextResult};
 use dashmap::{DashMap, DashSet};
 use parking_lot::RwLock;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
-use std::path::Path;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
-use verdure_ioc::ComponentInstance;
+use std::sync::atomic::{AtomicI32, Ordering};
+use std::time::Duration;
+use verdure_core::logging::{LogLevel, Logger};
+use verdure_ioc::{ComponentContainer, ComponentInstance};
 
 pub trait ConfigInitializer {
     fn from_config_manager(config_manager: Arc<ConfigManager>) -> ContextResult<Self>
@@ -29,12 +38,94 @@ pub struct ConfigFactory {
 
 inventory::collect!(ConfigFactory);
 
+/// Creates every registered configuration component and adds it to `container`
+pub(crate) fn register_config_components(
+    container: &ComponentContainer,
+    config_manager: &Arc<ConfigManager>,
+) -> ContextResult<()> {
+    for factory in inventory::iter::<ConfigFactory> {
+        let config_component = (factory.create_fn)(config_manager.clone())?;
+        container.register_component(config_component);
+    }
+    Ok(())
+}
+
+/// Assembles configuration components on a plain [`ComponentContainer`]
+///
+/// [`ApplicationContext`](crate::ApplicationContext) does this while initializing; this
+/// trait lets `#[derive(Configuration)]` structs be used with a container on its own.
+pub trait ConfigContainerExt {
+    /// Registers the configuration manager and all configuration components, then
+    /// initializes the container
+    ///
+    /// # Arguments
+    ///
+    /// * `config_manager` - The configuration manager the components are read from
+    ///
+    /// # Returns
+    ///
+    /// The number of components created by the container, or an error if a configuration
+    /// component cannot be read or the container fails to initialize
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use verdure_context::config::ConfigContainerExt;
+    /// use verdure_context::ConfigManager;
+    /// use verdure_ioc::{ComponentContainer, ComponentFactory};
+    /// use std::sync::Arc;
+    ///
+    /// let container = ComponentContainer::new();
+    /// container.initialize_with_config(Arc::new(ConfigManager::new())).unwrap();
+    ///
+    /// assert!(container.get_component::<ConfigManager>().is_some());
+    /// ```
+    fn initialize_with_config(&self, config_manager: Arc<ConfigManager>) -> ContextResult<usize>;
+}
+
+impl ConfigContainerExt for ComponentContainer {
+    fn initialize_with_config(&self, config_manager: Arc<ConfigManager>) -> ContextResult<usize> {
+        self.register_component(config_manager.clone());
+        register_config_components(self, &config_manager)?;
+        self.initialize()
+            .map_err(ContextError::container_initialization_failed)
+    }
+}
+
 /// Configuration file formats
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 enum ConfigFileFormat {
     Toml,
     Yaml,
     Properties,
+    /// Only used for inline content, files are not detected as JSON
+    Json,
+}
+
+impl ConfigFileFormat {
+    /// Detects the format from a file extension, if it is a known one
+    fn from_extension(path: &str) -> Option<Self> {
+        let path_lower = path.to_lowercase();
+        if path_lower.ends_with(".toml") {
+            Some(ConfigFileFormat::Toml)
+        } else if path_lower.ends_with(".yaml") || path_lower.ends_with(".yml") {
+            Some(ConfigFileFormat::Yaml)
+        } else if path_lower.ends_with(".properties") {
+            Some(ConfigFileFormat::Properties)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the name of the format used in messages
+    fn name(self) -> &'static str {
+        match self {
+            ConfigFileFormat::Toml => "TOML",
+            ConfigFileFormat::Yaml => "YAML",
+            ConfigFileFormat::Properties => "properties",
+            ConfigFileFormat::Json => "JSON",
+        }
+    }
 }
 
 /// Configuration source types
@@ -52,17 +143,92 @@ pub enum ConfigSource {
     /// Configuration from any file (auto-detect format)
     ConfigFile(String),
     /// Configuration from environment variables
+    ///
+    /// A key is looked up under its upper-cased name with `.` and `[` replaced by `_`
+    /// and `]` removed, so `app.port` reads `APP_PORT` and `servers[0].host` reads
+    /// `SERVERS_0_HOST`. If that variable is not set, the indexed variables
+    /// `<NAME>_0`, `<NAME>_1`, ... up to the first missing index form an array value,
+    /// so `DATABASE_HOSTS_0=a` and `DATABASE_HOSTS_1=b` override `database.hosts`.
     Environment,
     /// Configuration from command line arguments
     CommandLine,
     /// In-memory configuration properties
     Properties(HashMap<String, String>),
+    /// Configuration fetched from a remote provider, see [`RemoteConfigProvider`]
+    #[serde(skip)]
+    Remote(Arc<dyn RemoteConfigProvider>),
+    /// TOML content held in memory, e.g. defaults embedded with `include_str!`
+    InlineToml(String),
+    /// YAML content held in memory
+    InlineYaml(String),
+    /// JSON content held in memory
+    InlineJson(String),
+    /// A directory holding one file per key, e.g. a mounted Kubernetes secret
+    ///
+    /// A key is read from the file named like the key (`db.password`), or failing
+    /// that with dots replaced by `/` (`db/password`) or by `_` (`db_password`).
+    /// Trailing newlines are dropped. Like environment variables, keys are looked up
+    /// on demand rather than listed from the directory.
+    SecretDir(String),
+    /// Values produced by a function, e.g. feature flags from a flag service
+    ///
+    /// The provider is called at most once per `ttl`; in between, lookups reuse its
+    /// last output. Values found in or below this source are not kept in the key
+    /// cache, so a refreshed value is seen on the first lookup after the TTL.
+    #[serde(skip)]
+    Dynamic {
+        /// The function returning the current values
+        provider: DynamicProvider,
+        /// How long the provider's output is reused before calling it again
+        ttl: Duration,
+    },
+}
+
+impl ConfigSource {
+    /// Returns a short human-readable description of the source
+    ///
+    /// File-based sources include their path, which makes it possible to tell
+    /// where a configuration value came from in multi-file setups.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use verdure_context::ConfigSource;
+    ///
+    /// let source = ConfigSource::TomlFile("config/app.toml".to_string());
+    /// assert_eq!(source.description(), "TOML file 'config/app.toml'");
+    /// ```
+    pub fn description(&self) -> String {
+        match self {
+            ConfigSource::TomlFile(path) => format!("TOML file '{}'", path),
+            ConfigSource::YamlFile(path) => format!("YAML file '{}'", path),
+            ConfigSource::PropertiesFile(path) => format!("properties file '{}'", path),
+            ConfigSource::ConfigFile(path) => format!("config file '{}'", path),
+            ConfigSource::Environment => "environment variables".to_string(),
+            ConfigSource::CommandLine => "command line".to_string(),
+            ConfigSource::Properties(_) => "in-memory properties".to_string(),
+            ConfigSource::Remote(provider) => provider.description(),
+            ConfigSource::InlineToml(_) => "inline TOML".to_string(),
+            ConfigSource::InlineYaml(_) => "inline YAML".to_string(),
+            ConfigSource::InlineJson(_) => "inline JSON".to_string(),
+            ConfigSource::SecretDir(dir) => format!("secret directory '{}'", dir),
+            ConfigSource::Dynamic { .. } => "dynamic provider".to_string(),
+        }
+    }
 }
 
 /// Configuration value types
 ///
 /// `ConfigValue` represents different types of configuration values
 /// that can be stored and retrieved from the configuration system.
+///
+/// Values compare equal only when they have the same variant and contents, so
+/// `String("1")` is not equal to `Integer(1)`. Floats are compared by their bit
+/// pattern: a `NaN` equals an identical `NaN`, and `0.0` differs from `-0.0`.
+/// This keeps equality reflexive, which change detection relies on.
+///
+/// An explicit YAML or JSON `null` is kept as [`ConfigValue::Null`] rather than an
+/// empty string. Typed getters such as [`ConfigManager::get_string`] treat it as absent.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum ConfigValue {
     /// String value
@@ -77,9 +243,40 @@ pub enum ConfigValue {
     Array(Vec<ConfigValue>),
     /// Nested object/map
     Object(HashMap<String, ConfigValue>),
+    /// Explicitly null value, e.g. YAML `~`
+    Null,
+}
+
+impl PartialEq for ConfigValue {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (ConfigValue::String(a), ConfigValue::String(b)) => a == b,
+            (ConfigValue::Integer(a), ConfigValue::Integer(b)) => a == b,
+            (ConfigValue::Float(a), ConfigValue::Float(b)) => a.to_bits() == b.to_bits(),
+            (ConfigValue::Boolean(a), ConfigValue::Boolean(b)) => a == b,
+            (ConfigValue::Array(a), ConfigValue::Array(b)) => a == b,
+            (ConfigValue::Object(a), ConfigValue::Object(b)) => a == b,
+            (ConfigValue::Null, ConfigValue::Null) => true,
+            _ => false,
+        }
+    }
 }
 
 impl ConfigValue {
+    /// Returns `true` if the value is [`ConfigValue::Null`]
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use verdure_context::ConfigValue;
+    ///
+    /// assert!(ConfigValue::Null.is_null());
+    /// assert!(!ConfigValue::String(String::new()).is_null());
+    /// ```
+    pub fn is_null(&self) -> bool {
+        matches!(self, ConfigValue::Null)
+    }
+
     /// Converts the value to a string if possible
     ///
     /// # Examples
@@ -148,6 +345,10 @@ impl ConfigValue {
 
     /// Converts the value to a boolean if possible
     ///
+    /// Strings are trimmed and matched case-insensitively against
+    /// `true`/`yes`/`on`/`enabled`/`1` and `false`/`no`/`off`/`disabled`/`0`.
+    /// Anything else is ambiguous and yields `None`.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -158,13 +359,16 @@ impl ConfigValue {
     ///
     /// let value = ConfigValue::String("true".to_string());
     /// assert_eq!(value.as_boolean(), Some(true));
+    ///
+    /// let value = ConfigValue::String(" Disabled ".to_string());
+    /// assert_eq!(value.as_boolean(), Some(false));
     /// ```
     pub fn as_boolean(&self) -> Option<bool> {
         match self {
             ConfigValue::Boolean(b) => Some(*b),
-            ConfigValue::String(s) => match s.to_lowercase().as_str() {
-                "true" | "yes" | "on" | "1" => Some(true),
-                "false" | "no" | "off" | "0" => Some(false),
+            ConfigValue::String(s) => match s.trim().to_lowercase().as_str() {
+                "true" | "yes" | "on" | "enabled" | "1" => Some(true),
+                "false" | "no" | "off" | "disabled" | "0" => Some(false),
                 _ => None,
             },
             ConfigValue::Integer(i) => Some(*i != 0),
@@ -214,6 +418,27 @@ impl ConfigValue {
     }
 }
 
+/// A configuration source together with its precedence
+#[derive(Debug, Clone)]
+struct PrioritizedSource {
+    source: ConfigSource,
+    priority: i32,
+    /// Parsed content of an inline source, `None` for other sources and invalid content
+    inline: Option<Arc<HashMap<String, ConfigValue>>>,
+    /// Values fetched from a remote or dynamic source, reused until their TTL expires
+    snapshot: Arc<remote::RemoteSnapshot>,
+}
+
+/// Identifies a parsed configuration file in the parse cache: its path and format,
+/// `None` meaning auto-detected
+type ParsedFileKey = (String, Option<ConfigFileFormat>);
+
+/// Parser for a custom configuration file format, see [`ConfigManager::register_format`]
+///
+/// The parser receives the file content and returns the flattened configuration,
+/// keyed by dotted configuration keys.
+pub type ConfigFormatParser = fn(&str) -> ContextResult<HashMap<String, ConfigValue>>;
+
 /// Configuration manager
 ///
 /// `ConfigManager` provides comprehensive configuration management functionality,
@@ -240,17 +465,38 @@ impl ConfigValue {
 /// ```
 #[derive(Clone)]
 pub struct ConfigManager {
-    /// Configuration sources in precedence order (last added = highest precedence)
-    sources: Arc<RwLock<Vec<ConfigSource>>>,
+    /// Configuration sources sorted by ascending priority (last = highest precedence)
+    sources: Arc<RwLock<Vec<PrioritizedSource>>>,
+
+    /// Priority handed out to the next source added without an explicit one
+    next_priority: Arc<AtomicI32>,
     
     /// Primary configuration cache
     cache: Arc<DashMap<String, ConfigValue>>,
-    
-    /// File content cache to avoid repeated file I/O
-    file_cache: Arc<DashMap<String, HashMap<String, String>>>,
+
+    /// Maximum number of cached entries, unbounded when `None`
+    max_cache_entries: Option<usize>,
+
+    /// Values set at runtime, taking precedence over every source
+    overrides: Arc<DashMap<String, ConfigValue>>,
+
+    /// Description of the source that supplied each cached key
+    provenance: Arc<DashMap<String, String>>,
+
+    /// Environment variable name of each key looked up in the environment
+    environment_names: Arc<DashMap<String, String>>,
     
     /// Cache invalidation tracking
     dirty_keys: Arc<DashSet<String>>,
+
+    /// Parsed configuration files, reused until the cache is invalidated
+    parsed_files: Arc<DashMap<ParsedFileKey, Arc<HashMap<String, ConfigValue>>>>,
+
+    /// Parsers for custom file formats, keyed by lower-case file extension
+    custom_formats: Arc<DashMap<String, ConfigFormatParser>>,
+
+    /// Sink for log messages, `None` until a logger is set
+    logger: Arc<RwLock<Option<Logger>>>,
 }
 
 impl ConfigManager {
@@ -258,19 +504,138 @@ impl ConfigManager {
     pub fn new() -> Self {
         Self {
             sources: Arc::new(RwLock::new(Vec::new())),
+            next_priority: Arc::new(AtomicI32::new(0)),
             cache: Arc::new(DashMap::new()),
-            file_cache: Arc::new(DashMap::new()),
+            max_cache_entries: None,
+            overrides: Arc::new(DashMap::new()),
+            provenance: Arc::new(DashMap::new()),
+            environment_names: Arc::new(DashMap::new()),
             dirty_keys: Arc::new(DashSet::new()),
+            parsed_files: Arc::new(DashMap::new()),
+            custom_formats: Arc::new(DashMap::new()),
+            logger: Arc::new(RwLock::new(None)),
+        }
+    }
+
+    /// Sets the sink that receives the manager's log messages
+    ///
+    /// The manager logs at `Warn` when a configuration file without a known extension
+    /// reads differently in several formats. No messages are produced while no logger
+    /// is set.
+    ///
+    /// # Arguments
+    ///
+    /// * `logger` - Callback receiving the level and message
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use verdure_context::ConfigManager;
+    /// use verdure_core::logging::LogLevel;
+    ///
+    /// let manager = ConfigManager::new();
+    /// manager.set_logger(|level: LogLevel, message: &str| {
+    ///     eprintln!("[{}] {}", level, message);
+    /// });
+    /// ```
+    pub fn set_logger<F>(&self, logger: F)
+    where
+        F: Fn(LogLevel, &str) + Send + Sync + 'static,
+    {
+        self.set_shared_logger(Arc::new(logger));
+    }
+
+    /// Sets an already shared logger, e.g. one also used by an application context
+    pub fn set_shared_logger(&self, logger: Logger) {
+        *self.logger.write() = Some(logger);
+    }
+
+    /// Sends a message to the logger, building it only if a logger is set
+    fn log(&self, level: LogLevel, message: impl FnOnce() -> String) {
+        if let Some(logger) = self.logger.read().as_ref() {
+            logger(level, &message());
         }
     }
 
+    /// Limits the number of entries kept in the read cache
+    ///
+    /// Once the cache is full, an arbitrary entry is evicted to make room for the next
+    /// one. Runtime overrides set with [`set`](Self::set) are not part of the cache and
+    /// are never evicted. The environment variable names remembered for keys looked up
+    /// in [`ConfigSource::Environment`] are limited to the same number.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_entries` - The maximum number of cached keys
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use verdure_context::ConfigManager;
+    ///
+    /// let manager = ConfigManager::new().with_max_cache_entries(1024);
+    /// ```
+    pub fn with_max_cache_entries(mut self, max_entries: usize) -> Self {
+        self.max_cache_entries = Some(max_entries);
+        self
+    }
+
     /// Adds a configuration source
+    ///
+    /// The source is given an auto-incrementing priority, so sources added this
+    /// way keep the "last added wins" behavior among themselves. Use
+    /// [`add_source_with_priority`](Self::add_source_with_priority) to place a
+    /// source explicitly.
     pub fn add_source(&self, source: ConfigSource) -> ContextResult<()> {
+        let priority = self.next_priority.fetch_add(1, Ordering::SeqCst);
+        self.add_source_with_priority(source, priority)
+    }
+
+    /// Adds a configuration source with an explicit priority
+    ///
+    /// Lookups consult sources from the highest priority to the lowest. Sources
+    /// sharing the same priority are consulted in reverse insertion order.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The configuration source to add
+    /// * `priority` - The precedence of the source (higher wins)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use verdure_context::{ConfigManager, ConfigSource};
+    /// use std::collections::HashMap;
+    ///
+    /// let manager = ConfigManager::new();
+    ///
+    /// let mut overrides = HashMap::new();
+    /// overrides.insert("app.name".to_string(), "Override".to_string());
+    /// manager
+    ///     .add_source_with_priority(ConfigSource::Properties(overrides), 100)
+    ///     .unwrap();
+    ///
+    /// // Added later, but with a lower priority
+    /// let mut defaults = HashMap::new();
+    /// defaults.insert("app.name".to_string(), "Default".to_string());
+    /// manager
+    ///     .add_source_with_priority(ConfigSource::Properties(defaults), -100)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(manager.get_string("app.name").unwrap(), "Override");
+    /// ```
+    pub fn add_source_with_priority(
+        &self,
+        source: ConfigSource,
+        priority: i32,
+    ) -> ContextResult<()> {
         {
             let mut sources = self.sources.write();
-            sources.push(source);
+            let index = sources.partition_point(|s| s.priority <= priority);
+            let source = self.prioritized(source, priority);
+            sources.insert(index, source);
         }
-        
+
         self.invalidate_cache();
         Ok(())
     }
@@ -386,6 +751,9 @@ impl ConfigManager {
     /// ```
     /// Gets a configuration value
     pub fn get(&self, key: &str) -> Option<ConfigValue> {
+        if let Some(value) = self.overrides.get(key) {
+            return Some(value.clone());
+        }
         if let Some(cached) = self.cache.get(key) {
             return Some(cached.clone());
         }
@@ -396,9 +764,17 @@ impl ConfigManager {
     /// Internal method to compute and cache configuration values
     fn get_and_cache(&self, key: &str) -> Option<ConfigValue> {
         let sources = self.sources.read();
-        for source in sources.iter().rev() {
-            if let Some(value) = self.get_from_source(source, key) {
-                self.cache.insert(key.to_string(), value.clone());
+        let mut dynamic = false;
+        for entry in sources.iter().rev() {
+            dynamic |= matches!(entry.source, ConfigSource::Dynamic { .. });
+            if let Some(value) = self.get_from_source(entry, key) {
+                // A dynamic source may start or stop providing the key at any time
+                if !dynamic {
+                    self.make_cache_room();
+                    self.cache.insert(key.to_string(), value.clone());
+                }
+                self.provenance
+                    .insert(key.to_string(), entry.source.description());
                 return Some(value);
             }
         }
@@ -406,6 +782,45 @@ impl ConfigManager {
         None
     }
 
+    /// Evicts a cached entry if the cache has reached its configured capacity
+    fn make_cache_room(&self) {
+        let Some(max_entries) = self.max_cache_entries else {
+            return;
+        };
+        while self.cache.len() >= max_entries.max(1) {
+            let victim = self.cache.iter().next().map(|entry| entry.key().clone());
+            match victim {
+                Some(key) => self.invalidate_key(&key),
+                None => break,
+            }
+        }
+    }
+
+    /// Returns whether any runtime value or source provides `key`
+    ///
+    /// A key set to an empty string or an explicit null counts as present. A value
+    /// found in a source is cached, so a following lookup does not search again.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use verdure_context::{ConfigManager, ConfigSource};
+    /// use std::collections::HashMap;
+    ///
+    /// let manager = ConfigManager::new();
+    /// let mut props = HashMap::new();
+    /// props.insert("app.suffix".to_string(), "".to_string());
+    /// manager.add_source(ConfigSource::Properties(props)).unwrap();
+    ///
+    /// assert!(manager.has_key("app.suffix"));
+    /// assert!(!manager.has_key("app.missing"));
+    /// ```
+    pub fn has_key(&self, key: &str) -> bool {
+        self.overrides.contains_key(key)
+            || self.cache.contains_key(key)
+            || self.get_and_cache(key).is_some()
+    }
+
     /// Gets a configuration value as a string
     ///
     /// # Arguments
@@ -440,6 +855,13 @@ impl ConfigManager {
             .ok_or_else(|| ContextError::configuration_not_found(key))
     }
 
+    // Helper method returning the value of `key`, treating an explicit null as absent
+    fn get_present(&self, key: &str) -> ContextResult<ConfigValue> {
+        self.get(key)
+            .filter(|value| !value.is_null())
+            .ok_or_else(|| ContextError::configuration_not_found(key))
+    }
+
     /// Gets a configuration value as an integer
     ///
     /// # Arguments
@@ -469,9 +891,10 @@ impl ConfigManager {
     /// assert_eq!(manager.get_integer("app.port").unwrap(), 8080);
     /// ```
     pub fn get_integer(&self, key: &str) -> ContextResult<i64> {
-        self.get(key)
-            .and_then(|v| v.as_integer())
-            .ok_or_else(|| ContextError::configuration_not_found(key))
+        let value = self.get_present(key)?;
+        value
+            .as_integer()
+            .ok_or_else(|| self.invalid_configuration(key, "expected an integer"))
     }
 
     /// Gets a configuration value as a float
@@ -488,9 +911,10 @@ impl ConfigManager {
     ///
     /// Returns an error if the key is not found or cannot be converted to a float
     pub fn get_float(&self, key: &str) -> ContextResult<f64> {
-        self.get(key)
-            .and_then(|v| v.as_float())
-            .ok_or_else(|| ContextError::configuration_not_found(key))
+        let value = self.get_present(key)?;
+        value
+            .as_float()
+            .ok_or_else(|| self.invalid_configuration(key, "expected a float"))
     }
 
     /// Gets a configuration value as a boolean
@@ -522,9 +946,122 @@ impl ConfigManager {
     /// assert_eq!(manager.get_boolean("app.debug").unwrap(), true);
     /// ```
     pub fn get_boolean(&self, key: &str) -> ContextResult<bool> {
-        self.get(key)
-            .and_then(|v| v.as_boolean())
-            .ok_or_else(|| ContextError::configuration_not_found(key))
+        let value = self.get_present(key)?;
+        value
+            .as_boolean()
+            .ok_or_else(|| self.invalid_configuration(key, "expected a boolean"))
+    }
+
+    /// Gets a configuration value as a list of strings
+    ///
+    /// Array values are returned element by element. String values are split on commas,
+    /// which is how YAML and TOML sequences are flattened; items are trimmed and empty
+    /// items are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The configuration key
+    ///
+    /// # Returns
+    ///
+    /// `None` if the key is not found, otherwise the list of items
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use verdure_context::{ConfigManager, ConfigSource};
+    /// use std::collections::HashMap;
+    ///
+    /// let manager = ConfigManager::new();
+    /// let mut props = HashMap::new();
+    /// props.insert("app.features".to_string(), "auth, logging".to_string());
+    /// manager.add_source(ConfigSource::Properties(props)).unwrap();
+    ///
+    /// assert_eq!(
+    ///     manager.get_list("app.features"),
+    ///     Some(vec!["auth".to_string(), "logging".to_string()])
+    /// );
+    /// assert_eq!(manager.get_list("app.missing"), None);
+    /// ```
+    pub fn get_list(&self, key: &str) -> Option<Vec<String>> {
+        match self.get(key)? {
+            ConfigValue::Array(items) => Some(items.iter().filter_map(|v| v.as_string()).collect()),
+            other => {
+                let value = other.as_string()?;
+                Some(
+                    value
+                        .split(',')
+                        .map(str::trim)
+                        .filter(|item| !item.is_empty())
+                        .map(str::to_string)
+                        .collect(),
+                )
+            }
+        }
+    }
+
+    /// Gets a configuration value as a filesystem path
+    ///
+    /// `$VAR` and `${VAR}` are replaced with the value of the environment variable, and a
+    /// leading `~` with the home directory (`HOME`, or `USERPROFILE` on Windows). Use
+    /// `$$` for a literal `$`. This expands the OS environment only, not other
+    /// configuration keys.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The configuration key
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigurationNotFound` if the key is not found, and
+    /// `InvalidConfiguration` if the value is not a scalar or refers to an environment
+    /// variable that is not set
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use verdure_context::{ConfigManager, ConfigSource};
+    /// use std::collections::HashMap;
+    /// use std::path::PathBuf;
+    ///
+    /// let manager = ConfigManager::new();
+    /// let mut props = HashMap::new();
+    /// props.insert("log.dir".to_string(), "/var/log/$$app".to_string());
+    /// manager.add_source(ConfigSource::Properties(props)).unwrap();
+    ///
+    /// assert_eq!(manager.get_path("log.dir").unwrap(), PathBuf::from("/var/log/$app"));
+    /// ```
+    pub fn get_path(&self, key: &str) -> ContextResult<PathBuf> {
+        let value = self.get_present(key)?;
+        let value = value
+            .as_string()
+            .ok_or_else(|| self.invalid_configuration(key, "expected a path"))?;
+        expand_path(&value).map_err(|reason| self.invalid_configuration(key, reason))
+    }
+
+    /// Gets a configuration value as a list of filesystem paths
+    ///
+    /// Items are read as with [`get_list`](Self::get_list) and expanded as with
+    /// [`get_path`](Self::get_path).
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The configuration key
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigurationNotFound` if the key is not found, and
+    /// `InvalidConfiguration` if an item refers to an environment variable that is not set
+    pub fn get_path_list(&self, key: &str) -> ContextResult<Vec<PathBuf>> {
+        let items = self
+            .get_list(key)
+            .ok_or_else(|| ContextError::configuration_not_found(key))?;
+        items
+            .iter()
+            .map(|item| {
+                expand_path(item).map_err(|reason| self.invalid_configuration(key, reason))
+            })
+            .collect()
     }
 
     /// Gets a configuration value with a default fallback
@@ -567,105 +1104,817 @@ impl ConfigManager {
         self.get_boolean(key).unwrap_or(default)
     }
 
-    /// Sets a runtime configuration value
-    pub fn set(&self, key: &str, value: ConfigValue) {
-        self.cache.insert(key.to_string(), value);
-    }
-
-    /// Gets the number of configuration sources
+    /// Gets a configuration value, falling back to `default` if the key is not set
     ///
-    /// # Returns
+    /// Unlike the typed `*_or_default` helpers, the default keeps its `ConfigValue`
+    /// type, so lists, tables and numbers can be used as defaults.
     ///
-    /// The total number of configuration sources
-    pub fn sources_count(&self) -> usize {
-        self.sources.read().len()
+    /// # Arguments
+    ///
+    /// * `key` - The configuration key
+    /// * `default` - The value to return if the key is not found
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use verdure_context::{ConfigManager, ConfigValue};
+    ///
+    /// let manager = ConfigManager::new();
+    ///
+    /// let value = manager.get_with_default("server.port", ConfigValue::Integer(8080));
+    /// assert_eq!(value, ConfigValue::Integer(8080));
+    /// ```
+    pub fn get_with_default(&self, key: &str, default: ConfigValue) -> ConfigValue {
+        self.get(key).unwrap_or(default)
     }
 
-    /// Invalidates the configuration cache
-    pub fn invalidate_cache(&self) {
-        self.cache.clear();
-        self.dirty_keys.clear();
-    }
-    
-    /// Invalidates specific cache keys
-    pub fn invalidate_keys(&self, keys: &[String]) {
-        for key in keys {
-            self.cache.remove(key);
-            self.dirty_keys.insert(key.clone());
-        }
+    /// Gets a configuration value, storing `default` as a runtime value if the key is not set
+    ///
+    /// The first default stored for a key wins: later calls, as well as
+    /// [`all_entries`](Self::all_entries) and [`all_keys`](Self::all_keys), observe it
+    /// like a value set with [`set`](Self::set).
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The configuration key
+    /// * `default` - The value to store and return if the key is not found
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use verdure_context::{ConfigManager, ConfigValue};
+    ///
+    /// let manager = ConfigManager::new();
+    ///
+    /// let first = manager.get_or_insert_default("server.port", ConfigValue::Integer(8080));
+    /// let second = manager.get_or_insert_default("server.port", ConfigValue::Integer(9090));
+    /// assert_eq!(first, ConfigValue::Integer(8080));
+    /// assert_eq!(second, ConfigValue::Integer(8080));
+    /// assert_eq!(manager.all_entries()["server.port"], ConfigValue::Integer(8080));
+    /// ```
+    pub fn get_or_insert_default(&self, key: &str, default: ConfigValue) -> ConfigValue {
+        if let Some(value) = self.get(key) {
+            return value;
+        }
+        self.overrides
+            .entry(key.to_string())
+            .or_insert(default)
+            .clone()
+    }
+
+    /// Sets a runtime configuration value
+    ///
+    /// Runtime values take precedence over every source and survive cache invalidation
+    /// and the addition of new sources. Use [`remove_override`](Self::remove_override)
+    /// to fall back to the sources again.
+    pub fn set(&self, key: &str, value: ConfigValue) {
+        self.overrides.insert(key.to_string(), value);
+    }
+
+    /// Removes a runtime configuration value set with [`set`](Self::set)
+    ///
+    /// # Returns
+    ///
+    /// The removed value, or `None` if the key had no runtime value
+    pub fn remove_override(&self, key: &str) -> Option<ConfigValue> {
+        self.overrides.remove(key).map(|(_, value)| value)
+    }
+
+    /// Describes the source that supplies the value for a key
+    ///
+    /// # Returns
+    ///
+    /// A description such as `TOML file 'config/app.toml'`, or `None` if the key is not set
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use verdure_context::{ConfigManager, ConfigSource};
+    /// use std::collections::HashMap;
+    ///
+    /// let manager = ConfigManager::new();
+    /// let mut props = HashMap::new();
+    /// props.insert("app.port".to_string(), "8080".to_string());
+    /// manager.add_source(ConfigSource::Properties(props)).unwrap();
+    ///
+    /// assert_eq!(manager.source_of("app.port").unwrap(), "in-memory properties");
+    /// assert!(manager.source_of("app.missing").is_none());
+    /// ```
+    pub fn source_of(&self, key: &str) -> Option<String> {
+        if self.overrides.contains_key(key) {
+            return Some("runtime override".to_string());
+        }
+        self.get(key)?;
+        self.provenance.get(key).map(|source| source.clone())
+    }
+
+    /// Creates an `InvalidConfiguration` error that names the source of the key
+    ///
+    /// The reason is suffixed with the description of the source that supplied the
+    /// value, e.g. `expected an integer (from TOML file 'config/app.toml')`.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The configuration key with the invalid value
+    /// * `reason` - Why the value is invalid
+    pub fn invalid_configuration(&self, key: &str, reason: impl Into<String>) -> ContextError {
+        let reason = reason.into();
+        match self.source_of(key) {
+            Some(source) => {
+                ContextError::invalid_configuration(key, format!("{} (from {})", reason, source))
+            }
+            None => ContextError::invalid_configuration(key, reason),
+        }
+    }
+
+    /// Lists every configuration key known to the manager
+    ///
+    /// Keys are collected from all file and in-memory sources as well as runtime
+    /// values set through [`set`](Self::set). Environment variables are not
+    /// enumerated, since their names cannot be mapped back to dotted keys
+    /// unambiguously; they can still be read with [`get`](Self::get).
+    ///
+    /// # Returns
+    ///
+    /// The keys sorted alphabetically, without duplicates
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use verdure_context::{ConfigManager, ConfigSource};
+    /// use std::collections::HashMap;
+    ///
+    /// let manager = ConfigManager::new();
+    /// let mut props = HashMap::new();
+    /// props.insert("flags.beta".to_string(), "on".to_string());
+    /// props.insert("app.name".to_string(), "MyApp".to_string());
+    /// manager.add_source(ConfigSource::Properties(props)).unwrap();
+    ///
+    /// assert_eq!(manager.all_keys(), vec!["app.name", "flags.beta"]);
+    /// ```
+    pub fn all_keys(&self) -> Vec<String> {
+        let mut keys: Vec<String> = self.cache.iter().map(|e| e.key().clone()).collect();
+        keys.extend(self.overrides.iter().map(|e| e.key().clone()));
+        for entry in self.sources.read().iter() {
+            keys.extend(self.keys_from_source(entry));
+        }
+        keys.sort();
+        keys.dedup();
+        keys
+    }
+
+    /// Collects the effective value of every known key
+    ///
+    /// Keys are resolved with the usual precedence, so runtime values and higher
+    /// priority sources win. Keys only provided by environment variables are not
+    /// enumerable and are therefore not included.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use verdure_context::{ConfigManager, ConfigSource, ConfigValue};
+    /// use std::collections::HashMap;
+    ///
+    /// let manager = ConfigManager::new();
+    /// let mut props = HashMap::new();
+    /// props.insert("app.name".to_string(), "MyApp".to_string());
+    /// manager.add_source(ConfigSource::Properties(props)).unwrap();
+    ///
+    /// let entries = manager.all_entries();
+    /// assert_eq!(entries["app.name"], ConfigValue::String("MyApp".to_string()));
+    /// ```
+    pub fn all_entries(&self) -> HashMap<String, ConfigValue> {
+        self.all_keys()
+            .into_iter()
+            .filter_map(|key| {
+                let value = self.get(&key)?;
+                Some((key, value))
+            })
+            .collect()
+    }
+
+    /// Collects the keys under `prefix` with the prefix stripped, as strings
+    ///
+    /// `get_map("database")` turns `database.host` and `database.pool.size` into
+    /// `host` and `pool.size`; `databases.host` does not match. Values are resolved
+    /// with the usual precedence and values that are not scalars are skipped. Keys
+    /// only provided by environment variables are not enumerable and are therefore
+    /// not included. An empty prefix collects every key.
+    ///
+    /// # Arguments
+    ///
+    /// * `prefix` - The key prefix, e.g. `"database"`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use verdure_context::{ConfigManager, ConfigSource};
+    /// use std::collections::HashMap;
+    ///
+    /// let manager = ConfigManager::new();
+    /// let mut props = HashMap::new();
+    /// props.insert("database.host".to_string(), "localhost".to_string());
+    /// props.insert("database.port".to_string(), "5432".to_string());
+    /// props.insert("app.name".to_string(), "MyApp".to_string());
+    /// manager.add_source(ConfigSource::Properties(props)).unwrap();
+    ///
+    /// let database = manager.get_map("database");
+    /// assert_eq!(database.len(), 2);
+    /// assert_eq!(database["port"], "5432");
+    /// ```
+    pub fn get_map(&self, prefix: &str) -> HashMap<String, String> {
+        self.all_keys()
+            .into_iter()
+            .filter_map(|key| {
+                let sub_key = if prefix.is_empty() {
+                    key.clone()
+                } else {
+                    key.strip_prefix(prefix)?.strip_prefix('.')?.to_string()
+                };
+                let value = self.get(&key)?.as_string()?;
+                Some((sub_key, value))
+            })
+            .collect()
+    }
+
+    /// Binds all keys under `prefix` into a `serde`-deserializable type
+    ///
+    /// The flattened keys are rebuilt into a tree (`a.b` becomes a nested table and
+    /// `list[0].x` a list of tables) and deserialized into `T`. String values are
+    /// parsed into the primitive types `T` asks for, and comma-joined values can be
+    /// read as lists. Keys only provided by environment variables are not enumerable
+    /// and are therefore not bound.
+    ///
+    /// # Arguments
+    ///
+    /// * `prefix` - The key prefix to bind, e.g. `"server"`; an empty prefix binds all keys
+    ///
+    /// # Errors
+    ///
+    /// Returns `ContextError::PropertyBindingError` if the values do not fit `T`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use verdure_context::{ConfigManager, ConfigSource};
+    /// use serde::Deserialize;
+    /// use std::collections::HashMap;
+    ///
+    /// #[derive(Deserialize)]
+    /// struct ServerConfig {
+    ///     host: String,
+    ///     port: u16,
+    /// }
+    ///
+    /// let manager = ConfigManager::new();
+    /// let mut props = HashMap::new();
+    /// props.insert("server.host".to_string(), "localhost".to_string());
+    /// props.insert("server.port".to_string(), "8080".to_string());
+    /// manager.add_source(ConfigSource::Properties(props)).unwrap();
+    ///
+    /// let server: ServerConfig = manager.bind("server").unwrap();
+    /// assert_eq!(server.port, 8080);
+    /// ```
+    pub fn bind<T: DeserializeOwned>(&self, prefix: &str) -> ContextResult<T> {
+        let entries = self.all_keys().into_iter().filter_map(|key| -> Option<Vec<_>> {
+            let relative = if prefix.is_empty() {
+                key.clone()
+            } else {
+                let rest = key.strip_prefix(prefix)?;
+                match rest.strip_prefix('.') {
+                    Some(rest) => rest.to_string(),
+                    None if rest.starts_with('[') => rest.to_string(),
+                    None => return None,
+                }
+            };
+            match self.get(&key)? {
+                // Arrays (e.g. from indexed environment variables) become indexed entries
+                ConfigValue::Array(items) => Some(
+                    items
+                        .iter()
+                        .enumerate()
+                        .filter_map(|(index, item)| {
+                            Some((format!("{}[{}]", relative, index), item.as_string()?))
+                        })
+                        .collect(),
+                ),
+                value => Some(vec![(relative, value.as_string()?)]),
+            }
+        });
+
+        T::deserialize(binder::Node::from_flat(entries.flatten()))
+            .map_err(|e| ContextError::property_binding_error(prefix, e.to_string()))
+    }
+
+    /// Gets the number of configuration sources
+    ///
+    /// # Returns
+    ///
+    /// The total number of configuration sources
+    pub fn sources_count(&self) -> usize {
+        self.sources.read().len()
+    }
+
+    /// Removes all configuration sources
+    ///
+    /// Runtime values set with [`set`](Self::set) are kept. This is mainly useful in
+    /// tests that want to start over without building a new manager.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use verdure_context::{ConfigManager, ConfigSource};
+    /// use std::collections::HashMap;
+    ///
+    /// let manager = ConfigManager::new();
+    /// let mut props = HashMap::new();
+    /// props.insert("app.name".to_string(), "MyApp".to_string());
+    /// manager.add_source(ConfigSource::Properties(props)).unwrap();
+    ///
+    /// manager.clear_sources();
+    /// assert_eq!(manager.sources_count(), 0);
+    /// assert!(manager.get("app.name").is_none());
+    /// ```
+    pub fn clear_sources(&self) {
+        self.sources.write().clear();
+        self.invalidate_cache();
+    }
+
+    /// Replaces all configuration sources
+    ///
+    /// The new sources get auto-incrementing priorities in the given order, as if
+    /// they were added one by one with [`add_source`](Self::add_source). Runtime values
+    /// set with [`set`](Self::set) are kept.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use verdure_context::{ConfigManager, ConfigSource};
+    /// use std::collections::HashMap;
+    ///
+    /// let manager = ConfigManager::new();
+    /// let mut first = HashMap::new();
+    /// first.insert("app.name".to_string(), "First".to_string());
+    /// manager.add_source(ConfigSource::Properties(first)).unwrap();
+    ///
+    /// let mut second = HashMap::new();
+    /// second.insert("app.name".to_string(), "Second".to_string());
+    /// manager.replace_sources(vec![ConfigSource::Properties(second)]);
+    ///
+    /// assert_eq!(manager.sources_count(), 1);
+    /// assert_eq!(manager.get_string("app.name").unwrap(), "Second");
+    /// ```
+    pub fn replace_sources(&self, sources: Vec<ConfigSource>) {
+        let replacement = sources
+            .into_iter()
+            .map(|source| {
+                self.prioritized(source, self.next_priority.fetch_add(1, Ordering::SeqCst))
+            })
+            .collect();
+        *self.sources.write() = replacement;
+        self.invalidate_cache();
+    }
+
+    /// Invalidates the configuration cache
+    ///
+    /// Runtime values set with [`set`](Self::set) are kept. Configuration files are
+    /// read and remote sources fetched again on the next lookup.
+    pub fn invalidate_cache(&self) {
+        self.cache.clear();
+        self.provenance.clear();
+        self.dirty_keys.clear();
+        for entry in self.sources.read().iter() {
+            entry.snapshot.clear();
+        }
+        self.parsed_files.clear();
+    }
+    
+    /// Invalidates a single cache key
+    ///
+    /// The next read of `key` is resolved from the sources again.
+    pub fn invalidate_key(&self, key: &str) {
+        self.cache.remove(key);
+        self.provenance.remove(key);
+        self.dirty_keys.insert(key.to_string());
+    }
+
+    /// Invalidates specific cache keys
+    pub fn invalidate_keys(&self, keys: &[String]) {
+        for key in keys {
+            self.invalidate_key(key);
+        }
+    }
+
+    /// Reads a registered configuration file again
+    ///
+    /// Unlike [`invalidate_cache`](Self::invalidate_cache), only this file is parsed
+    /// again and only the keys it provides are dropped from the cache. If the file
+    /// cannot be read or parsed, the previously parsed content is kept.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path the file source was registered with
+    ///
+    /// # Returns
+    ///
+    /// The keys the file provided before or after the reload, sorted
+    ///
+    /// # Errors
+    ///
+    /// Returns `ContextError::ConfigurationFileError` if no file source with this path
+    /// is registered, or the file cannot be read or parsed
+    pub fn reload_file(&self, path: &str) -> ContextResult<Vec<String>> {
+        let formats: Vec<Option<ConfigFileFormat>> = self
+            .sources
+            .read()
+            .iter()
+            .filter_map(|entry| match &entry.source {
+                ConfigSource::TomlFile(p) if p == path => Some(Some(ConfigFileFormat::Toml)),
+                ConfigSource::YamlFile(p) if p == path => Some(Some(ConfigFileFormat::Yaml)),
+                ConfigSource::PropertiesFile(p) if p == path => {
+                    Some(Some(ConfigFileFormat::Properties))
+                }
+                ConfigSource::ConfigFile(p) if p == path => Some(None),
+                _ => None,
+            })
+            .collect();
+        if formats.is_empty() {
+            return Err(ContextError::configuration_file_error(format!(
+                "'{}' is not a registered configuration file",
+                path
+            )));
+        }
+
+        // Parse every registration first so a failure leaves the cache untouched
+        let mut reloaded = Vec::new();
+        for format in formats {
+            let props = match format {
+                Some(format) => self.load_file_config(path, format)?,
+                None => self.load_file_config_auto_detect(path)?,
+            };
+            reloaded.push(((path.to_string(), format), Arc::new(props)));
+        }
+
+        let mut keys = HashSet::new();
+        for (cache_key, props) in reloaded {
+            keys.extend(props.keys().cloned());
+            if let Some(previous) = self.parsed_files.insert(cache_key, props) {
+                keys.extend(previous.keys().cloned());
+            }
+        }
+        let mut keys: Vec<String> = keys.into_iter().collect();
+        keys.sort();
+        self.invalidate_keys(&keys);
+        Ok(keys)
+    }
+
+    // Helper method looking up a key in the environment, mapping each key to its
+    // variable name only once since keys missing from the cache are looked up again
+    fn environment_value(&self, key: &str) -> Option<ConfigValue> {
+        if let Some(name) = self.environment_names.get(key) {
+            return environment_value(&name);
+        }
+        let name = environment_variable_name(key);
+        let value = environment_value(&name);
+        if let Some(max_entries) = self.max_cache_entries
+            && self.environment_names.len() >= max_entries.max(1)
+        {
+            // Names are cheap to compute again, so start over rather than pick victims
+            self.environment_names.clear();
+        }
+        self.environment_names.insert(key.to_string(), name);
+        value
     }
 
     // Helper method to get value from a specific source
-    fn get_from_source(&self, source: &ConfigSource, key: &str) -> Option<ConfigValue> {
-        match source {
+    fn get_from_source(&self, entry: &PrioritizedSource, key: &str) -> Option<ConfigValue> {
+        match &entry.source {
             ConfigSource::Properties(props) => {
                 props.get(key).map(|v| ConfigValue::String(v.clone()))
             }
-            ConfigSource::Environment => {
-                // Convert key to environment variable format (e.g., "app.port" -> "APP_PORT")
-                let env_key = key.to_uppercase().replace('.', "_");
-                std::env::var(&env_key).ok().map(|v| ConfigValue::String(v))
+            ConfigSource::Environment => self.environment_value(key),
+            ConfigSource::SecretDir(dir) => secret_value(dir, key),
+            ConfigSource::TomlFile(path) => {
+                self.file_value(path, Some(ConfigFileFormat::Toml), key)
+            }
+            ConfigSource::YamlFile(path) => {
+                self.file_value(path, Some(ConfigFileFormat::Yaml), key)
+            }
+            ConfigSource::PropertiesFile(path) => {
+                self.file_value(path, Some(ConfigFileFormat::Properties), key)
             }
-            ConfigSource::TomlFile(path) => self
-                .load_file_config(path, ConfigFileFormat::Toml)
-                .and_then(|props| props.get(key).map(|v| ConfigValue::String(v.clone()))),
-            ConfigSource::YamlFile(path) => self
-                .load_file_config(path, ConfigFileFormat::Yaml)
-                .and_then(|props| props.get(key).map(|v| ConfigValue::String(v.clone()))),
-            ConfigSource::PropertiesFile(path) => self
-                .load_file_config(path, ConfigFileFormat::Properties)
-                .and_then(|props| props.get(key).map(|v| ConfigValue::String(v.clone()))),
-            ConfigSource::ConfigFile(path) => self
-                .load_file_config_auto_detect(path)
-                .and_then(|props| props.get(key).map(|v| ConfigValue::String(v.clone()))),
+            ConfigSource::ConfigFile(path) => self.file_value(path, None, key),
+            ConfigSource::InlineToml(_)
+            | ConfigSource::InlineYaml(_)
+            | ConfigSource::InlineJson(_) => entry.inline.as_ref()?.get(key).cloned(),
+            ConfigSource::Remote(provider) => entry
+                .snapshot
+                .values(provider)
+                .ok()
+                .and_then(|values| values.get(key).cloned()),
+            ConfigSource::Dynamic { provider, ttl } => entry
+                .snapshot
+                .dynamic_values(provider, *ttl)
+                .get(key)
+                .cloned(),
             _ => None, // TODO: Implement other source types
         }
     }
 
+    // Helper method to list the keys provided by a specific source
+    fn keys_from_source(&self, entry: &PrioritizedSource) -> Vec<String> {
+        let file = match &entry.source {
+            ConfigSource::Properties(props) => return props.keys().cloned().collect(),
+            ConfigSource::TomlFile(path) => self.parsed_file(path, Some(ConfigFileFormat::Toml)),
+            ConfigSource::YamlFile(path) => self.parsed_file(path, Some(ConfigFileFormat::Yaml)),
+            ConfigSource::PropertiesFile(path) => {
+                self.parsed_file(path, Some(ConfigFileFormat::Properties))
+            }
+            ConfigSource::ConfigFile(path) => self.parsed_file(path, None),
+            ConfigSource::InlineToml(_)
+            | ConfigSource::InlineYaml(_)
+            | ConfigSource::InlineJson(_) => {
+                return entry
+                    .inline
+                    .as_ref()
+                    .map(|props| props.keys().cloned().collect())
+                    .unwrap_or_default();
+            }
+            ConfigSource::Remote(provider) => {
+                return entry
+                    .snapshot
+                    .values(provider)
+                    .map(|values| values.keys().cloned().collect())
+                    .unwrap_or_default();
+            }
+            ConfigSource::Dynamic { provider, ttl } => {
+                return entry
+                    .snapshot
+                    .dynamic_values(provider, *ttl)
+                    .keys()
+                    .cloned()
+                    .collect();
+            }
+            _ => return Vec::new(),
+        };
+        file.map(|props| props.keys().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    // Helper method to look up a key in a parsed configuration file
+    fn file_value(
+        &self,
+        path: &str,
+        format: Option<ConfigFileFormat>,
+        key: &str,
+    ) -> Option<ConfigValue> {
+        self.parsed_file(path, format)
+            .ok()
+            .and_then(|props| props.get(key).cloned())
+    }
+
+    // Helper method returning a configuration file parsed once until the cache is invalidated
+    //
+    // Errors are not cached, so a file that appears later is picked up by the next lookup.
+    fn parsed_file(
+        &self,
+        path: &str,
+        format: Option<ConfigFileFormat>,
+    ) -> ContextResult<Arc<HashMap<String, ConfigValue>>> {
+        let cache_key = (path.to_string(), format);
+        if let Some(props) = self.parsed_files.get(&cache_key) {
+            return Ok(props.clone());
+        }
+
+        let props = Arc::new(match format {
+            Some(format) => self.load_file_config(path, format)?,
+            None => self.load_file_config_auto_detect(path)?,
+        });
+        self.parsed_files.insert(cache_key, props.clone());
+        Ok(props)
+    }
+
+    // Helper method pairing a source with its priority, parsing inline content once
+    fn prioritized(&self, source: ConfigSource, priority: i32) -> PrioritizedSource {
+        let inline = self
+            .parse_inline(&source)
+            .and_then(Result::ok)
+            .map(Arc::new);
+        PrioritizedSource {
+            source,
+            priority,
+            inline,
+            snapshot: Arc::default(),
+        }
+    }
+
+    // Helper method parsing the content of an inline source
+    //
+    // Returns `None` if `source` is not an inline source.
+    fn parse_inline(
+        &self,
+        source: &ConfigSource,
+    ) -> Option<ContextResult<HashMap<String, ConfigValue>>> {
+        let (content, format) = match source {
+            ConfigSource::InlineToml(content) => (content, ConfigFileFormat::Toml),
+            ConfigSource::InlineYaml(content) => (content, ConfigFileFormat::Yaml),
+            ConfigSource::InlineJson(content) => (content, ConfigFileFormat::Json),
+            _ => return None,
+        };
+        Some(self.parse_content(None, content, format))
+    }
+
     // Helper method to load configuration from file
     fn load_file_config(
         &self,
         path: &str,
         format: ConfigFileFormat,
-    ) -> Option<HashMap<String, String>> {
-        let content = std::fs::read_to_string(path).ok()?;
+    ) -> ContextResult<HashMap<String, ConfigValue>> {
+        let content = read_config_file(path)?;
+        self.parse_content(Some(path), &content, format)
+    }
 
+    // Helper method to parse file or inline content in the given format
+    //
+    // `path` is the file the content was read from, `None` for inline content.
+    fn parse_content(
+        &self,
+        path: Option<&str>,
+        content: &str,
+        format: ConfigFileFormat,
+    ) -> ContextResult<HashMap<String, ConfigValue>> {
         match format {
             ConfigFileFormat::Toml => {
-                let toml_value: toml::Value = toml::from_str(&content).ok()?;
-                self.toml_value_to_config_map(&toml_value, "").ok()
+                let toml_value: toml::Value = toml::from_str(content)
+                    .map_err(|e| parse_error(path, format, e))?;
+                self.toml_value_to_config_map(&toml_value, "").map(string_values)
             }
             ConfigFileFormat::Yaml => {
-                let yaml_value: serde_yaml::Value = serde_yaml::from_str(&content).ok()?;
-                self.yaml_value_to_config_map(&yaml_value, "").ok()
+                let yaml_value: serde_yaml::Value = serde_yaml::from_str(content)
+                    .map_err(|e| parse_error(path, format, e))?;
+                self.yaml_value_to_config_map(&yaml_value, "")
+            }
+            ConfigFileFormat::Properties => self.parse_properties(content).map(string_values),
+            ConfigFileFormat::Json => {
+                let json_value: serde_yaml::Value = serde_json::from_str(content)
+                    .map_err(|e| parse_error(path, format, e))?;
+                self.yaml_value_to_config_map(&json_value, "")
             }
-            ConfigFileFormat::Properties => self.parse_properties(&content).ok(),
         }
     }
 
     // Helper method to auto-detect file format and load configuration
-    fn load_file_config_auto_detect(&self, path: &str) -> Option<HashMap<String, String>> {
-        let path_lower = path.to_lowercase();
-
-        // Try to detect format by extension first
-        if path_lower.ends_with(".toml") {
-            return self.load_file_config(path, ConfigFileFormat::Toml);
-        } else if path_lower.ends_with(".yaml") || path_lower.ends_with(".yml") {
-            return self.load_file_config(path, ConfigFileFormat::Yaml);
-        } else if path_lower.ends_with(".properties") {
-            return self.load_file_config(path, ConfigFileFormat::Properties);
+    fn load_file_config_auto_detect(
+        &self,
+        path: &str,
+    ) -> ContextResult<HashMap<String, ConfigValue>> {
+        // Registered custom formats take precedence over the built-in ones
+        if let Some(parser) = self.custom_format_parser(path) {
+            let content = read_config_file(path)?;
+            return parser(&content).map_err(|e| ContextError::file_parse_error(path, e));
         }
 
-        // If extension doesn't match known formats, try parsing in order: TOML, YAML, Properties
-        if let Some(config) = self.load_file_config(path, ConfigFileFormat::Toml) {
-            return Some(config);
+        // A known extension is authoritative: its parse errors are reported as-is
+        if let Some(format) = ConfigFileFormat::from_extension(path) {
+            return self.load_file_config(path, format);
         }
 
-        if let Some(config) = self.load_file_config(path, ConfigFileFormat::Yaml) {
-            return Some(config);
+        // If extension doesn't match known formats, try parsing in order: TOML, YAML,
+        // Properties, and use the first format that yields any entries. Plain text is
+        // valid YAML (a single scalar), so an empty result does not count as a match.
+        let content = read_config_file(path)?;
+        let candidates: Vec<(ConfigFileFormat, HashMap<String, ConfigValue>)> = [
+            ConfigFileFormat::Toml,
+            ConfigFileFormat::Yaml,
+            ConfigFileFormat::Properties,
+        ]
+        .into_iter()
+        .filter_map(|format| {
+            let map = self.parse_content(Some(path), &content, format).ok()?;
+            (!map.is_empty()).then_some((format, map))
+        })
+        .collect();
+
+        // Formats reading a key with different values (e.g. `name = "App"`, which keeps
+        // its quotes as a property) leave the intended meaning open; the first one is used
+        let mut candidates = candidates.into_iter();
+        let Some((format, map)) = candidates.next() else {
+            return Ok(HashMap::new());
+        };
+        for (other_format, other_map) in candidates {
+            let differs = other_map
+                .iter()
+                .any(|(key, value)| map.get(key).is_some_and(|first| first != value));
+            if differs {
+                self.log(LogLevel::Warn, || {
+                    format!(
+                        "'{}' parses as both {} and {} with different values; reading it \
+                         as {}, use a known file extension or a format-specific source",
+                        path,
+                        format.name(),
+                        other_format.name(),
+                        format.name()
+                    )
+                });
+            }
         }
+        Ok(map)
+    }
 
-        self.load_file_config(path, ConfigFileFormat::Properties)
+    // Helper method returning the custom parser registered for the extension of `path`
+    fn custom_format_parser(&self, path: &str) -> Option<ConfigFormatParser> {
+        let extension = Path::new(path).extension()?.to_str()?.to_lowercase();
+        self.custom_formats.get(&extension).map(|parser| *parser)
+    }
+
+    /// Registers a parser for a configuration file format the crate does not support
+    ///
+    /// Files added with [`ConfigSource::ConfigFile`] (or `load_from_config_file`) whose
+    /// extension matches are parsed with `parser` instead of being auto-detected.
+    /// Registering an extension again replaces its parser.
+    ///
+    /// # Arguments
+    ///
+    /// * `extension` - The file extension, with or without the leading dot (case-insensitive)
+    /// * `parser` - Function turning the file content into flattened configuration values
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use verdure_context::{ConfigManager, ConfigValue, ContextResult};
+    /// use std::collections::HashMap;
+    ///
+    /// // A format with one `key -> value` pair per line
+    /// fn parse_arrows(content: &str) -> ContextResult<HashMap<String, ConfigValue>> {
+    ///     Ok(content
+    ///         .lines()
+    ///         .filter_map(|line| line.split_once("->"))
+    ///         .map(|(key, value)| {
+    ///             (key.trim().to_string(), ConfigValue::String(value.trim().to_string()))
+    ///         })
+    ///         .collect())
+    /// }
+    ///
+    /// let manager = ConfigManager::new();
+    /// manager.register_format("arrows", parse_arrows);
+    /// ```
+    pub fn register_format(&self, extension: &str, parser: ConfigFormatParser) {
+        let extension = extension.trim_start_matches('.').to_lowercase();
+        self.custom_formats.insert(extension, parser);
+        self.invalidate_cache();
+    }
+
+    /// Checks that a file-based or remote configuration source can be read and parsed
+    ///
+    /// Lookups treat unreadable files and unreachable remote sources as empty, so this
+    /// method is the way to surface a missing or malformed file, or a failing remote
+    /// fetch, as an error. Other sources always pass.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The configuration source to check
+    ///
+    /// # Errors
+    ///
+    /// Returns `ContextError::ConfigurationFileError` with the file path and the
+    /// underlying I/O or parse error, or the error returned by a remote provider
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use verdure_context::{ConfigManager, ConfigSource, ContextError};
+    ///
+    /// let manager = ConfigManager::new();
+    /// let result = manager.check_source(&ConfigSource::TomlFile("missing.toml".to_string()));
+    /// assert!(matches!(result, Err(ContextError::ConfigurationFileError { .. })));
+    /// assert!(manager.check_source(&ConfigSource::Environment).is_ok());
+    /// ```
+    pub fn check_source(&self, source: &ConfigSource) -> ContextResult<()> {
+        match source {
+            ConfigSource::TomlFile(path) => self.load_file_config(path, ConfigFileFormat::Toml),
+            ConfigSource::YamlFile(path) => self.load_file_config(path, ConfigFileFormat::Yaml),
+            ConfigSource::PropertiesFile(path) => {
+                self.load_file_config(path, ConfigFileFormat::Properties)
+            }
+            ConfigSource::ConfigFile(path) => {
+                return self.load_file_config_auto_detect(path).map(|_| ());
+            }
+            ConfigSource::Remote(provider) => {
+                return provider.fetch().map(|_| ());
+            }
+            ConfigSource::SecretDir(dir) => {
+                return std::fs::read_dir(dir)
+                    .map(|_| ())
+                    .map_err(|e| ContextError::file_read_error(dir, e));
+            }
+            ConfigSource::InlineToml(_)
+            | ConfigSource::InlineYaml(_)
+            | ConfigSource::InlineJson(_) => {
+                return self.parse_inline(source).transpose().map(|_| ());
+            }
+            _ => return Ok(()),
+        }
+        .map(|_| ())
     }
 
     // Helper method to convert YAML value to flat configuration map
@@ -673,7 +1922,7 @@ impl ConfigManager {
         &self,
         value: &serde_yaml::Value,
         prefix: &str,
-    ) -> ContextResult<HashMap<String, String>> {
+    ) -> ContextResult<HashMap<String, ConfigValue>> {
         let mut map = HashMap::new();
 
         match value {
@@ -692,18 +1941,35 @@ impl ConfigManager {
                                 let nested_map = self.yaml_value_to_config_map(val, &full_key)?;
                                 map.extend(nested_map);
                             }
+                            serde_yaml::Value::Sequence(seq)
+                                if seq.iter().any(|v| v.is_mapping() || v.is_sequence()) =>
+                            {
+                                // Sequences of mappings are flattened with index notation
+                                let nested_map = self.yaml_value_to_config_map(val, &full_key)?;
+                                map.extend(nested_map);
+                            }
                             _ => {
                                 // Convert primitive values to strings
-                                map.insert(full_key, self.yaml_value_to_string(val));
+                                map.insert(full_key, self.yaml_value_to_config_value(val));
                             }
                         }
                     }
                 }
             }
+            serde_yaml::Value::Sequence(seq)
+                if seq.iter().any(|v| v.is_mapping() || v.is_sequence()) =>
+            {
+                // e.g. servers[0].host, servers[1].host
+                for (index, item) in seq.iter().enumerate() {
+                    let nested_map =
+                        self.yaml_value_to_config_map(item, &format!("{}[{}]", prefix, index))?;
+                    map.extend(nested_map);
+                }
+            }
             _ => {
                 // For non-mapping values, use the prefix as the key
                 if !prefix.is_empty() {
-                    map.insert(prefix.to_string(), self.yaml_value_to_string(value));
+                    map.insert(prefix.to_string(), self.yaml_value_to_config_value(value));
                 }
             }
         }
@@ -711,6 +1977,14 @@ impl ConfigManager {
         Ok(map)
     }
 
+    // Helper method to convert a YAML leaf value, keeping an explicit null
+    fn yaml_value_to_config_value(&self, value: &serde_yaml::Value) -> ConfigValue {
+        match value {
+            serde_yaml::Value::Null => ConfigValue::Null,
+            _ => ConfigValue::String(self.yaml_value_to_string(value)),
+        }
+    }
+
     // Helper method to convert YAML value to string
     fn yaml_value_to_string(&self, value: &serde_yaml::Value) -> String {
         match value {
@@ -787,6 +2061,13 @@ impl ConfigManager {
                             let nested_map = self.toml_value_to_config_map(val, &full_key)?;
                             map.extend(nested_map);
                         }
+                        toml::Value::Array(arr)
+                            if arr.iter().any(|v| v.is_table() || v.is_array()) =>
+                        {
+                            // Arrays of tables are flattened with index notation
+                            let nested_map = self.toml_value_to_config_map(val, &full_key)?;
+                            map.extend(nested_map);
+                        }
                         _ => {
                             // Convert primitive values to strings
                             map.insert(full_key, self.toml_value_to_string(val));
@@ -794,6 +2075,14 @@ impl ConfigManager {
                     }
                 }
             }
+            toml::Value::Array(arr) if arr.iter().any(|v| v.is_table() || v.is_array()) => {
+                // e.g. servers[0].host, servers[1].host
+                for (index, item) in arr.iter().enumerate() {
+                    let nested_map =
+                        self.toml_value_to_config_map(item, &format!("{}[{}]", prefix, index))?;
+                    map.extend(nested_map);
+                }
+            }
             _ => {
                 // For non-table values, use the prefix as the key
                 if !prefix.is_empty() {
@@ -802,26 +2091,182 @@ impl ConfigManager {
             }
         }
 
-        Ok(map)
+        Ok(map)
+    }
+
+    // Helper method to convert TOML value to string
+    fn toml_value_to_string(&self, value: &toml::Value) -> String {
+        match value {
+            toml::Value::String(s) => s.clone(),
+            toml::Value::Integer(i) => i.to_string(),
+            toml::Value::Float(f) => f.to_string(),
+            toml::Value::Boolean(b) => b.to_string(),
+            toml::Value::Array(arr) => {
+                // Convert array to comma-separated string
+                arr.iter()
+                    .map(|v| self.toml_value_to_string(v))
+                    .collect::<Vec<_>>()
+                    .join(",")
+            }
+            _ => value.to_string(),
+        }
+    }
+}
+
+/// Wraps the values of a parsed configuration file as `ConfigValue::String`
+fn string_values(props: HashMap<String, String>) -> HashMap<String, ConfigValue> {
+    props
+        .into_iter()
+        .map(|(key, value)| (key, ConfigValue::String(value)))
+        .collect()
+}
+
+/// Parses a duration such as `30s`, `1500ms`, `5m` or `2h`
+///
+/// A number without a unit is read as seconds. This is how `#[derive(Configuration)]`
+/// reads `Duration` fields, which have no `FromStr` implementation.
+///
+/// # Errors
+///
+/// Returns a description of the problem if `value` is not a whole number optionally
+/// followed by `ms`, `s`, `m` or `h`
+///
+/// # Examples
+///
+/// ```rust
+/// use verdure_context::config::parse_duration;
+/// use std::time::Duration;
+///
+/// assert_eq!(parse_duration("1500ms"), Ok(Duration::from_millis(1500)));
+/// assert_eq!(parse_duration("5m"), Ok(Duration::from_secs(300)));
+/// assert_eq!(parse_duration("30"), Ok(Duration::from_secs(30)));
+/// assert!(parse_duration("soon").is_err());
+/// ```
+pub fn parse_duration(value: &str) -> Result<Duration, String> {
+    let value = value.trim();
+    let split = value
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(value.len());
+    let (number, unit) = value.split_at(split);
+    let amount: u64 = number
+        .parse()
+        .map_err(|_| format!("invalid duration '{}'", value))?;
+    match unit.trim() {
+        "ms" => Ok(Duration::from_millis(amount)),
+        "" | "s" => Ok(Duration::from_secs(amount)),
+        "m" => Ok(Duration::from_secs(amount.saturating_mul(60))),
+        "h" => Ok(Duration::from_secs(amount.saturating_mul(3600))),
+        other => Err(format!(
+            "invalid duration unit '{}', expected ms, s, m or h",
+            other
+        )),
+    }
+}
+
+/// Expands `~`, `$VAR` and `${VAR}` in a path, see [`ConfigManager::get_path`]
+fn expand_path(value: &str) -> Result<PathBuf, String> {
+    let mut expanded = String::with_capacity(value.len());
+    let mut rest = value;
+    if let Some(after) = rest.strip_prefix('~')
+        && (after.is_empty() || after.starts_with(['/', '\\']))
+    {
+        let home = std::env::var("HOME")
+            .or_else(|_| std::env::var("USERPROFILE"))
+            .map_err(|_| "cannot expand '~': the home directory is not set".to_string())?;
+        expanded.push_str(&home);
+        rest = after;
+    }
+
+    while let Some(index) = rest.find('$') {
+        expanded.push_str(&rest[..index]);
+        let after = &rest[index + 1..];
+        let (name, remaining) = if let Some(braced) = after.strip_prefix('{') {
+            let end = braced
+                .find('}')
+                .ok_or_else(|| format!("unclosed '${{' in '{}'", value))?;
+            (&braced[..end], &braced[end + 1..])
+        } else if let Some(remaining) = after.strip_prefix('$') {
+            expanded.push('$');
+            rest = remaining;
+            continue;
+        } else {
+            let end = after
+                .find(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+                .unwrap_or(after.len());
+            after.split_at(end)
+        };
+        if name.is_empty() {
+            // A `$` not followed by a variable name is kept as is
+            expanded.push('$');
+            rest = after;
+            continue;
+        }
+        let var = std::env::var(name)
+            .map_err(|_| format!("environment variable '{}' is not set", name))?;
+        expanded.push_str(&var);
+        rest = remaining;
+    }
+    expanded.push_str(rest);
+    Ok(PathBuf::from(expanded))
+}
+
+/// Maps a configuration key to the environment variable it is read from
+fn environment_variable_name(key: &str) -> String {
+    key.to_uppercase()
+        .replace(['.', '['], "_")
+        .replace(']', "")
+}
+
+/// Looks up the variable `name` of a configuration key, see [`ConfigSource::Environment`]
+fn environment_value(name: &str) -> Option<ConfigValue> {
+    if let Ok(value) = std::env::var(name) {
+        return Some(ConfigValue::String(value));
+    }
+
+    let items: Vec<ConfigValue> = (0..)
+        .map_while(|index| std::env::var(format!("{}_{}", name, index)).ok())
+        .map(ConfigValue::String)
+        .collect();
+    (!items.is_empty()).then_some(ConfigValue::Array(items))
+}
+
+/// Looks up a configuration key in a secret directory, see [`ConfigSource::SecretDir`]
+fn secret_value(dir: &str, key: &str) -> Option<ConfigValue> {
+    // Keys map to file names only, never to paths outside the directory
+    if key
+        .split('.')
+        .any(|segment| segment.is_empty() || segment.contains(['/', '\\']))
+    {
+        return None;
+    }
+
+    let dir = Path::new(dir);
+    [key.to_string(), key.replace('.', "/"), key.replace('.', "_")]
+        .iter()
+        .find_map(|name| std::fs::read_to_string(dir.join(name)).ok())
+        .map(|content| ConfigValue::String(content.trim_end_matches(['\r', '\n']).to_string()))
+}
+
+/// Creates the error for content that could not be parsed, `path` being `None` for
+/// inline content
+fn parse_error(
+    path: Option<&str>,
+    format: ConfigFileFormat,
+    error: impl std::error::Error + Send + Sync + 'static,
+) -> ContextError {
+    match path {
+        Some(path) => ContextError::file_parse_error(path, error),
+        None => ContextError::inline_parse_error(format.name(), error),
     }
+}
 
-    // Helper method to convert TOML value to string
-    fn toml_value_to_string(&self, value: &toml::Value) -> String {
-        match value {
-            toml::Value::String(s) => s.clone(),
-            toml::Value::Integer(i) => i.to_string(),
-            toml::Value::Float(f) => f.to_string(),
-            toml::Value::Boolean(b) => b.to_string(),
-            toml::Value::Array(arr) => {
-                // Convert array to comma-separated string
-                arr.iter()
-                    .map(|v| self.toml_value_to_string(v))
-                    .collect::<Vec<_>>()
-                    .join(",")
-            }
-            _ => value.to_string(),
-        }
-    }
+/// Reads a configuration file as UTF-8, dropping a leading byte order mark
+fn read_config_file(path: &str) -> ContextResult<String> {
+    let bytes = std::fs::read(path).map_err(|e| ContextError::file_read_error(path, e))?;
+    let bytes = bytes.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(&bytes);
+    std::str::from_utf8(bytes)
+        .map(str::to_string)
+        .map_err(|e| ContextError::file_encoding_error(path, e))
 }
 
 impl Default for ConfigManager {
@@ -833,6 +2278,7 @@ impl Default for ConfigManager {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_env::EnvVars;
 
     #[test]
     fn test_config_value_conversions() {
@@ -866,110 +2312,741 @@ mod tests {
         let value = ConfigValue::String("not_a_number".to_string());
         assert_eq!(value.as_integer(), None);
 
-        let value = ConfigValue::Array(vec![]);
-        assert_eq!(value.as_string(), None);
+        let value = ConfigValue::Array(vec![]);
+        assert_eq!(value.as_string(), None);
+    }
+
+    #[test]
+    fn test_boolean_coercion() {
+        let parse = |s: &str| ConfigValue::String(s.to_string()).as_boolean();
+
+        assert_eq!(parse("TRUE"), Some(true));
+        assert_eq!(parse("Yes"), Some(true));
+        assert_eq!(parse("Enabled"), Some(true));
+        assert_eq!(parse("disabled"), Some(false));
+        assert_eq!(parse("OFF"), Some(false));
+        assert_eq!(parse("  true\n"), Some(true));
+        assert_eq!(parse("\t0 "), Some(false));
+        assert_eq!(parse(" enabled "), Some(true));
+
+        assert_eq!(parse("maybe"), None);
+        assert_eq!(parse(""), None);
+        assert_eq!(parse("2"), None);
+    }
+
+    #[test]
+    fn test_config_value_equality() {
+        assert_eq!(
+            ConfigValue::String("a".to_string()),
+            ConfigValue::String("a".to_string())
+        );
+        assert_ne!(ConfigValue::String("1".to_string()), ConfigValue::Integer(1));
+        assert_eq!(ConfigValue::Float(f64::NAN), ConfigValue::Float(f64::NAN));
+        assert_ne!(ConfigValue::Float(0.0), ConfigValue::Float(-0.0));
+        assert_eq!(
+            ConfigValue::Array(vec![ConfigValue::Boolean(true)]),
+            ConfigValue::Array(vec![ConfigValue::Boolean(true)])
+        );
+    }
+
+    #[test]
+    fn test_config_manager_creation() {
+        let manager = ConfigManager::new();
+        assert_eq!(manager.sources_count(), 0);
+    }
+
+    #[test]
+    fn test_config_manager_properties_source() {
+        let manager = ConfigManager::new();
+        let mut props = HashMap::new();
+        props.insert("app.name".to_string(), "TestApp".to_string());
+        props.insert("app.port".to_string(), "8080".to_string());
+        props.insert("app.debug".to_string(), "true".to_string());
+
+        manager.add_source(ConfigSource::Properties(props)).unwrap();
+
+        assert_eq!(manager.get_string("app.name").unwrap(), "TestApp");
+        assert_eq!(manager.get_integer("app.port").unwrap(), 8080);
+        assert!(manager.get_boolean("app.debug").unwrap());
+
+        // Test missing key
+        assert!(manager.get_string("missing.key").is_err());
+    }
+
+    #[test]
+    fn test_config_manager_defaults() {
+        let manager = ConfigManager::new();
+
+        assert_eq!(
+            manager.get_string_or_default("missing.key", "default"),
+            "default"
+        );
+        assert_eq!(manager.get_integer_or_default("missing.key", 42), 42);
+        assert!(manager.get_boolean_or_default("missing.key", true));
+    }
+
+    #[test]
+    fn test_config_manager_source_precedence() {
+        let manager = ConfigManager::new();
+
+        // Add first source
+        let mut props1 = HashMap::new();
+        props1.insert("app.name".to_string(), "App1".to_string());
+        props1.insert("app.version".to_string(), "1.0".to_string());
+        manager
+            .add_source(ConfigSource::Properties(props1))
+            .unwrap();
+
+        // Add second source with overlapping key
+        let mut props2 = HashMap::new();
+        props2.insert("app.name".to_string(), "App2".to_string());
+        props2.insert("app.port".to_string(), "8080".to_string());
+        manager
+            .add_source(ConfigSource::Properties(props2))
+            .unwrap();
+
+        // Second source should take precedence
+        assert_eq!(manager.get_string("app.name").unwrap(), "App2");
+        assert_eq!(manager.get_string("app.version").unwrap(), "1.0"); // Only in first source
+        assert_eq!(manager.get_string("app.port").unwrap(), "8080"); // Only in second source
+    }
+
+    #[test]
+    fn test_config_manager_source_priority() {
+        let manager = ConfigManager::new();
+
+        let mut high = HashMap::new();
+        high.insert("app.name".to_string(), "High".to_string());
+        manager
+            .add_source_with_priority(ConfigSource::Properties(high), 100)
+            .unwrap();
+
+        // Added later with a default priority, which stays below 100
+        let mut default = HashMap::new();
+        default.insert("app.name".to_string(), "Default".to_string());
+        default.insert("app.port".to_string(), "8080".to_string());
+        manager
+            .add_source(ConfigSource::Properties(default))
+            .unwrap();
+
+        let mut low = HashMap::new();
+        low.insert("app.port".to_string(), "9090".to_string());
+        low.insert("app.mode".to_string(), "low".to_string());
+        manager
+            .add_source_with_priority(ConfigSource::Properties(low), -1)
+            .unwrap();
+
+        assert_eq!(manager.sources_count(), 3);
+        assert_eq!(manager.get_string("app.name").unwrap(), "High");
+        assert_eq!(manager.get_string("app.port").unwrap(), "8080");
+        assert_eq!(manager.get_string("app.mode").unwrap(), "low");
+
+        // Equal priorities fall back to insertion order
+        let mut tie = HashMap::new();
+        tie.insert("app.name".to_string(), "Tie".to_string());
+        manager
+            .add_source_with_priority(ConfigSource::Properties(tie), 100)
+            .unwrap();
+        assert_eq!(manager.get_string("app.name").unwrap(), "Tie");
+    }
+
+    #[test]
+    fn test_config_manager_cache() {
+        let manager = ConfigManager::new();
+        let mut props = HashMap::new();
+        props.insert("test.key".to_string(), "test.value".to_string());
+
+        manager.add_source(ConfigSource::Properties(props)).unwrap();
+
+        // First access should populate cache
+        assert_eq!(manager.get_string("test.key").unwrap(), "test.value");
+
+        // Second access should use cache
+        assert_eq!(manager.get_string("test.key").unwrap(), "test.value");
+
+        // Manual cache update
+        manager.set(
+            "runtime.key",
+            ConfigValue::String("runtime.value".to_string()),
+        );
+        assert_eq!(manager.get_string("runtime.key").unwrap(), "runtime.value");
+    }
+
+    #[test]
+    fn test_config_manager_cache_invalidation() {
+        let manager = ConfigManager::new();
+        let mut props = HashMap::new();
+        props.insert("test.key".to_string(), "test.value".to_string());
+
+        manager.add_source(ConfigSource::Properties(props)).unwrap();
+
+        // Access to populate cache
+        assert_eq!(manager.get_string("test.key").unwrap(), "test.value");
+
+        // Invalidate cache
+        manager.invalidate_cache();
+
+        // Should still work (reloaded from source)
+        assert_eq!(manager.get_string("test.key").unwrap(), "test.value");
+    }
+
+    #[test]
+    fn test_all_entries_uses_effective_values() {
+        let manager = ConfigManager::new();
+        let mut props = HashMap::new();
+        props.insert("app.name".to_string(), "Low".to_string());
+        props.insert("app.port".to_string(), "8080".to_string());
+        manager.add_source(ConfigSource::Properties(props)).unwrap();
+        manager.set("app.name", ConfigValue::String("Runtime".to_string()));
+
+        let entries = manager.all_entries();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries["app.name"].as_string().unwrap(), "Runtime");
+        assert_eq!(entries["app.port"].as_string().unwrap(), "8080");
+    }
+
+    #[test]
+    fn test_bom_prefixed_file_is_parsed() {
+        let path = std::env::temp_dir()
+            .join(format!("verdure_bom_{}.properties", std::process::id()));
+        std::fs::write(&path, b"\xEF\xBB\xBFapp.name=WithBom\napp.port=8080\n").unwrap();
+
+        let manager = ConfigManager::new();
+        let source = ConfigSource::PropertiesFile(path.to_string_lossy().to_string());
+        manager.check_source(&source).unwrap();
+        manager.add_source(source).unwrap();
+
+        assert_eq!(manager.get_string("app.name").unwrap(), "WithBom");
+        assert_eq!(manager.all_keys(), vec!["app.name", "app.port"]);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_non_utf8_file_reports_encoding_error() {
+        let path = std::env::temp_dir()
+            .join(format!("verdure_latin1_{}.toml", std::process::id()));
+        std::fs::write(&path, b"name = \"caf\xE9\"\n").unwrap();
+
+        let manager = ConfigManager::new();
+        let source = ConfigSource::TomlFile(path.to_string_lossy().to_string());
+        let result = manager.check_source(&source);
+        std::fs::remove_file(&path).unwrap();
+
+        match result {
+            Err(ContextError::ConfigurationFileError { message, source, .. }) => {
+                assert!(message.contains("not valid UTF-8"));
+                assert!(source.is_some());
+            }
+            other => panic!("expected a configuration file error, got {:?}", other),
+        }
+    }
+
+    struct InMemoryRemote {
+        values: HashMap<String, ConfigValue>,
+        fetches: std::sync::atomic::AtomicUsize,
+    }
+
+    impl RemoteConfigProvider for InMemoryRemote {
+        fn fetch(&self) -> ContextResult<HashMap<String, ConfigValue>> {
+            self.fetches.fetch_add(1, Ordering::SeqCst);
+            Ok(self.values.clone())
+        }
+
+        fn description(&self) -> String {
+            "in-memory remote".to_string()
+        }
+    }
+
+    #[test]
+    fn test_remote_source() {
+        let remote = Arc::new(InMemoryRemote {
+            values: HashMap::from([
+                ("app.name".to_string(), ConfigValue::String("Remote".to_string())),
+                ("app.port".to_string(), ConfigValue::Integer(9090)),
+            ]),
+            fetches: std::sync::atomic::AtomicUsize::new(0),
+        });
+
+        let manager = ConfigManager::new();
+        let mut local = HashMap::new();
+        local.insert("app.name".to_string(), "Local".to_string());
+        local.insert("app.mode".to_string(), "dev".to_string());
+        manager.add_source(ConfigSource::Properties(local)).unwrap();
+        manager.add_source(ConfigSource::Remote(remote.clone())).unwrap();
+
+        assert_eq!(manager.get_string("app.name").unwrap(), "Remote");
+        assert_eq!(manager.get_integer("app.port").unwrap(), 9090);
+        assert_eq!(manager.get_string("app.mode").unwrap(), "dev");
+        assert_eq!(manager.source_of("app.port").unwrap(), "in-memory remote");
+        assert_eq!(manager.all_keys(), vec!["app.mode", "app.name", "app.port"]);
+
+        // Lookups of different keys share a single fetch within the TTL
+        assert_eq!(remote.fetches.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_replaced_remote_source_is_fetched() {
+        let remote = |name: &str| {
+            Arc::new(InMemoryRemote {
+                values: HashMap::from([(
+                    "app.name".to_string(),
+                    ConfigValue::String(name.to_string()),
+                )]),
+                fetches: std::sync::atomic::AtomicUsize::new(0),
+            })
+        };
+
+        let manager = ConfigManager::new();
+        manager.add_source(ConfigSource::Remote(remote("First"))).unwrap();
+        assert_eq!(manager.get_string("app.name").unwrap(), "First");
+
+        let second = remote("Second");
+        manager.replace_sources(vec![ConfigSource::Remote(second.clone())]);
+        assert_eq!(manager.get_string("app.name").unwrap(), "Second");
+        assert_eq!(second.fetches.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_dynamic_source_refreshes_after_ttl() {
+        let calls = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let counter = calls.clone();
+        let provider = DynamicProvider::new(move || {
+            let call = counter.fetch_add(1, Ordering::SeqCst) + 1;
+            HashMap::from([
+                ("features.beta".to_string(), ConfigValue::Boolean(call % 2 == 1)),
+                ("features.calls".to_string(), ConfigValue::Integer(call as i64)),
+            ])
+        });
+
+        let manager = ConfigManager::new();
+        let mut local = HashMap::new();
+        local.insert("features.beta".to_string(), "false".to_string());
+        local.insert("app.name".to_string(), "flags".to_string());
+        manager.add_source(ConfigSource::Properties(local)).unwrap();
+        manager
+            .add_source(ConfigSource::Dynamic {
+                provider: provider.clone(),
+                ttl: Duration::from_secs(60),
+            })
+            .unwrap();
+
+        // Within the TTL every lookup shares one call
+        assert!(manager.get_boolean("features.beta").unwrap());
+        assert!(manager.get_boolean("features.beta").unwrap());
+        assert_eq!(manager.get_string("app.name").unwrap(), "flags");
+        assert_eq!(manager.source_of("features.beta").unwrap(), "dynamic provider");
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        let manager = ConfigManager::new();
+        manager
+            .add_source(ConfigSource::Dynamic {
+                provider,
+                ttl: Duration::ZERO,
+            })
+            .unwrap();
+
+        // Values are not kept in the key cache, so an expired TTL is seen immediately
+        assert_eq!(manager.get_integer("features.calls").unwrap(), 2);
+        assert_eq!(manager.get_integer("features.calls").unwrap(), 3);
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn test_check_source_reports_remote_errors() {
+        struct Unreachable;
+
+        impl RemoteConfigProvider for Unreachable {
+            fn fetch(&self) -> ContextResult<HashMap<String, ConfigValue>> {
+                Err(ContextError::configuration_not_found("config server unreachable"))
+            }
+        }
+
+        let manager = ConfigManager::new();
+        let source = ConfigSource::Remote(Arc::new(Unreachable));
+        assert!(manager.check_source(&source).is_err());
+
+        manager.add_source(source).unwrap();
+        assert!(manager.get("app.name").is_none());
+        assert!(manager.all_keys().is_empty());
+    }
+
+    #[test]
+    fn test_invalidate_key() {
+        let manager = ConfigManager::new();
+        let mut props = HashMap::new();
+        props.insert("a.key".to_string(), "a".to_string());
+        props.insert("b.key".to_string(), "b".to_string());
+        manager.add_source(ConfigSource::Properties(props)).unwrap();
+
+        manager.get("a.key");
+        manager.get("b.key");
+        assert_eq!(manager.cache.len(), 2);
+
+        manager.invalidate_key("a.key");
+        assert_eq!(manager.cache.len(), 1);
+        assert!(!manager.cache.contains_key("a.key"));
+        assert_eq!(manager.get_string("a.key").unwrap(), "a");
+    }
+
+    #[test]
+    fn test_runtime_override_survives_invalidation() {
+        let manager = ConfigManager::new();
+        let mut props = HashMap::new();
+        props.insert("app.name".to_string(), "FromSource".to_string());
+        manager.add_source(ConfigSource::Properties(props)).unwrap();
+
+        manager.set("app.name", ConfigValue::String("Runtime".to_string()));
+        manager.invalidate_cache();
+        assert_eq!(manager.get_string("app.name").unwrap(), "Runtime");
+
+        let mut higher = HashMap::new();
+        higher.insert("app.name".to_string(), "Higher".to_string());
+        manager
+            .add_source_with_priority(ConfigSource::Properties(higher), 100)
+            .unwrap();
+        assert_eq!(manager.get_string("app.name").unwrap(), "Runtime");
+        assert_eq!(manager.source_of("app.name").unwrap(), "runtime override");
+
+        assert!(manager.remove_override("app.name").is_some());
+        assert_eq!(manager.get_string("app.name").unwrap(), "Higher");
+    }
+
+    #[test]
+    fn test_clear_and_replace_sources() {
+        let manager = ConfigManager::new();
+        let mut first = HashMap::new();
+        first.insert("app.name".to_string(), "First".to_string());
+        first.insert("app.mode".to_string(), "dev".to_string());
+        manager.add_source(ConfigSource::Properties(first)).unwrap();
+        manager.set("app.runtime", ConfigValue::String("kept".to_string()));
+        assert_eq!(manager.get_string("app.name").unwrap(), "First");
+
+        let mut second = HashMap::new();
+        second.insert("app.name".to_string(), "Second".to_string());
+        let mut third = HashMap::new();
+        third.insert("app.name".to_string(), "Third".to_string());
+        manager.replace_sources(vec![
+            ConfigSource::Properties(second),
+            ConfigSource::Properties(third),
+        ]);
+        assert_eq!(manager.sources_count(), 2);
+        assert_eq!(manager.get_string("app.name").unwrap(), "Third");
+        assert!(manager.get("app.mode").is_none());
+
+        manager.clear_sources();
+        assert_eq!(manager.sources_count(), 0);
+        assert!(manager.get("app.name").is_none());
+        assert_eq!(manager.all_keys(), vec!["app.runtime"]);
+    }
+
+    #[test]
+    fn test_get_path_expands_environment() {
+        let _env = EnvVars::set(&[("VERDURE_PATH_TEST_ROOT", "/srv/app")]);
+        let home = std::env::var("HOME")
+            .or_else(|_| std::env::var("USERPROFILE"))
+            .unwrap();
+
+        let manager = ConfigManager::new();
+        let mut props = HashMap::new();
+        for (key, value) in [
+            ("paths.data", "${VERDURE_PATH_TEST_ROOT}/data"),
+            ("paths.logs", "$VERDURE_PATH_TEST_ROOT/logs-$$1"),
+            ("paths.cache", "~/cache"),
+            ("paths.plain", "relative/a~b/$"),
+            ("paths.list", "$VERDURE_PATH_TEST_ROOT/a, ~/b"),
+            ("paths.unset", "$VERDURE_PATH_TEST_UNSET/x"),
+            ("paths.unclosed", "${VERDURE_PATH_TEST_ROOT/x"),
+        ] {
+            props.insert(key.to_string(), value.to_string());
+        }
+        manager.add_source(ConfigSource::Properties(props)).unwrap();
+
+        assert_eq!(
+            manager.get_path("paths.data").unwrap(),
+            PathBuf::from("/srv/app/data")
+        );
+        assert_eq!(
+            manager.get_path("paths.logs").unwrap(),
+            PathBuf::from("/srv/app/logs-$1")
+        );
+        assert_eq!(
+            manager.get_path("paths.cache").unwrap(),
+            PathBuf::from(format!("{}/cache", home))
+        );
+        assert_eq!(
+            manager.get_path("paths.plain").unwrap(),
+            PathBuf::from("relative/a~b/$")
+        );
+        assert_eq!(
+            manager.get_path_list("paths.list").unwrap(),
+            vec![
+                PathBuf::from("/srv/app/a"),
+                PathBuf::from(format!("{}/b", home))
+            ]
+        );
+
+        match manager.get_path("paths.unset") {
+            Err(ContextError::InvalidConfiguration { reason, .. }) => {
+                assert!(reason.contains("'VERDURE_PATH_TEST_UNSET' is not set"));
+            }
+            other => panic!("expected an invalid configuration error, got {:?}", other),
+        }
+        assert!(manager.get_path("paths.unclosed").is_err());
+        assert!(matches!(
+            manager.get_path_list("paths.missing"),
+            Err(ContextError::ConfigurationNotFound { .. })
+        ));
+    }
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("250ms"), Ok(Duration::from_millis(250)));
+        assert_eq!(parse_duration(" 45 s "), Ok(Duration::from_secs(45)));
+        assert_eq!(parse_duration("2h"), Ok(Duration::from_secs(7200)));
+        assert_eq!(parse_duration("0"), Ok(Duration::ZERO));
+        assert!(parse_duration("").is_err());
+        assert!(parse_duration("-5s").is_err());
+        assert!(parse_duration("1.5s").is_err());
+        assert!(parse_duration("3d").unwrap_err().contains("expected ms, s, m or h"));
     }
 
     #[test]
-    fn test_config_manager_creation() {
+    fn test_has_key() {
         let manager = ConfigManager::new();
-        assert_eq!(manager.sources_count(), 0);
+        let mut props = HashMap::new();
+        props.insert("app.name".to_string(), "MyApp".to_string());
+        props.insert("app.suffix".to_string(), "".to_string());
+        manager.add_source(ConfigSource::Properties(props)).unwrap();
+        manager
+            .add_source(ConfigSource::InlineYaml("app:\n  owner: ~\n".to_string()))
+            .unwrap();
+        manager.set("app.runtime", ConfigValue::Boolean(true));
+
+        assert!(manager.has_key("app.name"));
+        assert!(manager.has_key("app.suffix"));
+        assert!(manager.has_key("app.owner"));
+        assert!(manager.has_key("app.runtime"));
+        assert!(!manager.has_key("app.missing"));
+        assert!(manager.cache.contains_key("app.name"));
     }
 
     #[test]
-    fn test_config_manager_properties_source() {
+    fn test_get_with_default_keeps_value_type() {
         let manager = ConfigManager::new();
         let mut props = HashMap::new();
-        props.insert("app.name".to_string(), "TestApp".to_string());
-        props.insert("app.port".to_string(), "8080".to_string());
-        props.insert("app.debug".to_string(), "true".to_string());
-
+        props.insert("app.name".to_string(), "MyApp".to_string());
         manager.add_source(ConfigSource::Properties(props)).unwrap();
 
-        assert_eq!(manager.get_string("app.name").unwrap(), "TestApp");
-        assert_eq!(manager.get_integer("app.port").unwrap(), 8080);
-        assert_eq!(manager.get_boolean("app.debug").unwrap(), true);
+        let hosts = ConfigValue::Array(vec![ConfigValue::String("localhost".to_string())]);
+        assert_eq!(manager.get_with_default("app.hosts", hosts.clone()), hosts);
+        assert_eq!(
+            manager.get_with_default("app.name", hosts.clone()),
+            ConfigValue::String("MyApp".to_string())
+        );
+        assert!(manager.get("app.hosts").is_none());
 
-        // Test missing key
-        assert!(manager.get_string("missing.key").is_err());
+        assert_eq!(
+            manager.get_or_insert_default("app.name", ConfigValue::Integer(1)),
+            ConfigValue::String("MyApp".to_string())
+        );
+        assert_eq!(manager.get_or_insert_default("app.hosts", hosts.clone()), hosts);
+        assert_eq!(manager.all_entries()["app.hosts"], hosts);
+        assert_eq!(manager.source_of("app.hosts").unwrap(), "runtime override");
     }
 
     #[test]
-    fn test_config_manager_defaults() {
+    fn test_config_file_is_parsed_once() {
+        let path = std::env::temp_dir().join(format!(
+            "verdure_parsed_once_{}.toml",
+            std::process::id()
+        ));
+        std::fs::write(&path, "[app]\nname = \"Cached\"\nport = 8080\n").unwrap();
+
         let manager = ConfigManager::new();
+        manager
+            .add_source(ConfigSource::TomlFile(path.to_string_lossy().to_string()))
+            .unwrap();
+        assert_eq!(manager.get_string("app.name").unwrap(), "Cached");
 
-        assert_eq!(
-            manager.get_string_or_default("missing.key", "default"),
-            "default"
-        );
-        assert_eq!(manager.get_integer_or_default("missing.key", 42), 42);
-        assert_eq!(manager.get_boolean_or_default("missing.key", true), true);
+        // Other keys are served from the parsed file without reading it again
+        std::fs::write(&path, "[app]\nname = \"Changed\"\nport = 9090\n").unwrap();
+        assert_eq!(manager.get_integer("app.port").unwrap(), 8080);
+        assert_eq!(manager.parsed_files.len(), 1);
+
+        manager.invalidate_cache();
+        assert_eq!(manager.get_string("app.name").unwrap(), "Changed");
+        assert_eq!(manager.get_integer("app.port").unwrap(), 9090);
+
+        std::fs::remove_file(&path).unwrap();
     }
 
     #[test]
-    fn test_config_manager_source_precedence() {
+    fn test_secret_dir_source() {
+        let dir = std::env::temp_dir().join(format!("verdure_secrets_{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("db")).unwrap();
+        std::fs::write(dir.join("api.token"), "t0ken\n").unwrap();
+        std::fs::write(dir.join("db").join("password"), "s3cret\r\n").unwrap();
+        std::fs::write(dir.join("db_user"), "admin").unwrap();
+        std::fs::write(std::env::temp_dir().join("verdure_outside_secret"), "leak").unwrap();
+
         let manager = ConfigManager::new();
+        let source = ConfigSource::SecretDir(dir.to_string_lossy().to_string());
+        assert!(manager.check_source(&source).is_ok());
+        manager.add_source(source).unwrap();
+
+        assert_eq!(manager.get_string("api.token").unwrap(), "t0ken");
+        assert_eq!(manager.get_string("db.password").unwrap(), "s3cret");
+        assert_eq!(manager.get_string("db.user").unwrap(), "admin");
+        assert!(manager.get("db.missing").is_none());
+        assert!(manager.get("..verdure_outside_secret").is_none());
+        assert!(manager.source_of("api.token").unwrap().starts_with("secret directory"));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+        std::fs::remove_file(std::env::temp_dir().join("verdure_outside_secret")).unwrap();
+        let missing = ConfigSource::SecretDir(dir.to_string_lossy().to_string());
+        assert!(matches!(
+            manager.check_source(&missing),
+            Err(ContextError::ConfigurationFileError { .. })
+        ));
+    }
 
-        // Add first source
-        let mut props1 = HashMap::new();
-        props1.insert("app.name".to_string(), "App1".to_string());
-        props1.insert("app.version".to_string(), "1.0".to_string());
-        manager
-            .add_source(ConfigSource::Properties(props1))
-            .unwrap();
+    #[test]
+    fn test_environment_indexed_array_overrides_file() {
+        #[derive(Debug, Deserialize)]
+        struct Database {
+            hosts: Vec<String>,
+            replicas: Vec<Replica>,
+        }
 
-        // Add second source with overlapping key
-        let mut props2 = HashMap::new();
-        props2.insert("app.name".to_string(), "App2".to_string());
-        props2.insert("app.port".to_string(), "8080".to_string());
+        #[derive(Debug, Deserialize)]
+        struct Replica {
+            host: String,
+        }
+
+        assert_eq!(environment_variable_name("app.port"), "APP_PORT");
+        assert_eq!(
+            environment_variable_name("servers[0].host"),
+            "SERVERS_0_HOST"
+        );
+
+        let path = std::env::temp_dir().join(format!(
+            "verdure_env_array_{}.toml",
+            std::process::id()
+        ));
+        std::fs::write(
+            &path,
+            "[verdure_env_test]\nhosts = [\"file-a\", \"file-b\", \"file-c\"]\n\
+             [[verdure_env_test.replicas]]\nhost = \"file-replica\"\n",
+        )
+        .unwrap();
+
+        let _env = EnvVars::set(&[
+            ("VERDURE_ENV_TEST_HOSTS_0", "env-a"),
+            ("VERDURE_ENV_TEST_HOSTS_1", "env-b"),
+            ("VERDURE_ENV_TEST_REPLICAS_0_HOST", "env-replica"),
+        ]);
+
+        let manager = ConfigManager::new();
         manager
-            .add_source(ConfigSource::Properties(props2))
+            .add_source(ConfigSource::TomlFile(path.to_string_lossy().to_string()))
             .unwrap();
+        manager.add_source(ConfigSource::Environment).unwrap();
 
-        // Second source should take precedence
-        assert_eq!(manager.get_string("app.name").unwrap(), "App2");
-        assert_eq!(manager.get_string("app.version").unwrap(), "1.0"); // Only in first source
-        assert_eq!(manager.get_string("app.port").unwrap(), "8080"); // Only in second source
+        assert_eq!(
+            manager.get_list("verdure_env_test.hosts"),
+            Some(vec!["env-a".to_string(), "env-b".to_string()])
+        );
+        let database: Database = manager.bind("verdure_env_test").unwrap();
+        assert_eq!(database.hosts, vec!["env-a", "env-b"]);
+        assert_eq!(database.replicas[0].host, "env-replica");
+        assert!(manager.get("verdure_env_test.missing").is_none());
+
+        std::fs::remove_file(&path).unwrap();
     }
 
     #[test]
-    fn test_config_manager_cache() {
+    fn test_environment_names_are_reused_after_invalidation() {
+        let mut env = EnvVars::set(&[("VERDURE_ENV_NAME_TEST_PORT", "8080")]);
         let manager = ConfigManager::new();
-        let mut props = HashMap::new();
-        props.insert("test.key".to_string(), "test.value".to_string());
+        manager.add_source(ConfigSource::Environment).unwrap();
+        assert_eq!(manager.get_string("verdure_env_name_test.port").unwrap(), "8080");
 
-        manager.add_source(ConfigSource::Properties(props)).unwrap();
+        // Only the variable name is kept, the value is read again
+        env.set_var("VERDURE_ENV_NAME_TEST_PORT", "9090");
+        manager.invalidate_cache();
+        assert_eq!(manager.get_string("verdure_env_name_test.port").unwrap(), "9090");
+        assert_eq!(
+            manager
+                .environment_names
+                .get("verdure_env_name_test.port")
+                .as_deref()
+                .map(String::as_str),
+            Some("VERDURE_ENV_NAME_TEST_PORT")
+        );
+    }
 
-        // First access should populate cache
-        assert_eq!(manager.get_string("test.key").unwrap(), "test.value");
+    #[test]
+    fn test_register_custom_format() {
+        fn parse_arrows(content: &str) -> ContextResult<HashMap<String, ConfigValue>> {
+            content
+                .lines()
+                .map(|line| {
+                    let (key, value) = line.split_once("->").ok_or_else(|| {
+                        ContextError::serialization_error(format!("missing '->' in '{}'", line))
+                    })?;
+                    let value = match value.trim().parse::<i64>() {
+                        Ok(number) => ConfigValue::Integer(number),
+                        Err(_) => ConfigValue::String(value.trim().to_string()),
+                    };
+                    Ok((key.trim().to_string(), value))
+                })
+                .collect()
+        }
 
-        // Second access should use cache
-        assert_eq!(manager.get_string("test.key").unwrap(), "test.value");
+        let dir = std::env::temp_dir();
+        let path = dir.join(format!("verdure_custom_{}.ARROWS", std::process::id()));
+        std::fs::write(&path, "app.name -> Arrows\napp.port -> 8080\n").unwrap();
+        let broken = dir.join(format!("verdure_custom_broken_{}.arrows", std::process::id()));
+        std::fs::write(&broken, "app.name = Broken\n").unwrap();
 
-        // Manual cache update
-        manager.set(
-            "runtime.key",
-            ConfigValue::String("runtime.value".to_string()),
-        );
-        assert_eq!(manager.get_string("runtime.key").unwrap(), "runtime.value");
+        let manager = ConfigManager::new();
+        manager.register_format(".arrows", parse_arrows);
+        manager
+            .add_source(ConfigSource::ConfigFile(path.to_string_lossy().to_string()))
+            .unwrap();
+        assert_eq!(manager.get_string("app.name").unwrap(), "Arrows");
+        assert_eq!(manager.get("app.port"), Some(ConfigValue::Integer(8080)));
+
+        let source = ConfigSource::ConfigFile(broken.to_string_lossy().to_string());
+        let error = manager.check_source(&source).unwrap_err();
+        assert!(error.to_string().contains("missing '->'"));
+
+        std::fs::remove_file(&path).unwrap();
+        std::fs::remove_file(&broken).unwrap();
     }
 
     #[test]
-    fn test_config_manager_cache_invalidation() {
-        let manager = ConfigManager::new();
+    fn test_max_cache_entries() {
+        let manager = ConfigManager::new().with_max_cache_entries(2);
         let mut props = HashMap::new();
-        props.insert("test.key".to_string(), "test.value".to_string());
-
+        for i in 0..5 {
+            props.insert(format!("key.{}", i), i.to_string());
+        }
         manager.add_source(ConfigSource::Properties(props)).unwrap();
+        manager.set("runtime.key", ConfigValue::String("kept".to_string()));
 
-        // Access to populate cache
-        assert_eq!(manager.get_string("test.key").unwrap(), "test.value");
-
-        // Invalidate cache
-        manager.invalidate_cache();
+        for i in 0..5 {
+            assert_eq!(manager.get_integer(&format!("key.{}", i)).unwrap(), i);
+            assert!(manager.cache.len() <= 2);
+        }
+        assert_eq!(manager.get_string("runtime.key").unwrap(), "kept");
 
-        // Should still work (reloaded from source)
-        assert_eq!(manager.get_string("test.key").unwrap(), "test.value");
+        let manager = ConfigManager::new().with_max_cache_entries(2);
+        manager.add_source(ConfigSource::Environment).unwrap();
+        for i in 0..5 {
+            assert!(manager.get(&format!("verdure_missing.key_{}", i)).is_none());
+            assert!(manager.environment_names.len() <= 2);
+        }
     }
 
     #[test]
@@ -990,18 +3067,221 @@ database:
         let manager = ConfigManager::new();
         let yaml_value: serde_yaml::Value = serde_yaml::from_str(yaml_content).unwrap();
         let config_map = manager.yaml_value_to_config_map(&yaml_value, "").unwrap();
+        let value = |key: &str| config_map.get(key).and_then(ConfigValue::as_string);
+
+        assert_eq!(value("app.name").as_deref(), Some("TestApp"));
+        assert_eq!(value("app.port").as_deref(), Some("8080"));
+        assert_eq!(value("database.host").as_deref(), Some("localhost"));
+        assert_eq!(value("database.ssl").as_deref(), Some("true"));
+        assert_eq!(value("app.features").as_deref(), Some("auth,logging"));
+    }
+
+    #[test]
+    fn test_get_list() {
+        let manager = ConfigManager::new();
+        let mut props = HashMap::new();
+        props.insert("app.features".to_string(), "auth,logging, metrics".to_string());
+        props.insert("app.empty".to_string(), "".to_string());
+        props.insert("app.single".to_string(), "only".to_string());
+        manager.add_source(ConfigSource::Properties(props)).unwrap();
 
-        assert_eq!(config_map.get("app.name"), Some(&"TestApp".to_string()));
-        assert_eq!(config_map.get("app.port"), Some(&"8080".to_string()));
         assert_eq!(
-            config_map.get("database.host"),
-            Some(&"localhost".to_string())
+            manager.get_list("app.features"),
+            Some(vec![
+                "auth".to_string(),
+                "logging".to_string(),
+                "metrics".to_string()
+            ])
         );
-        assert_eq!(config_map.get("database.ssl"), Some(&"true".to_string()));
+        assert_eq!(manager.get_list("app.empty"), Some(vec![]));
+        assert_eq!(manager.get_list("app.single"), Some(vec!["only".to_string()]));
+        assert_eq!(manager.get_list("app.missing"), None);
+    }
+
+    #[test]
+    fn test_bind_nested_struct() {
+        #[derive(Debug, Deserialize, PartialEq)]
+        struct Endpoint {
+            host: String,
+            port: u16,
+        }
+
+        #[derive(Debug, Deserialize)]
+        struct Database {
+            pool_size: u32,
+        }
+
+        #[derive(Debug, Deserialize)]
+        struct AppConfig {
+            name: String,
+            debug: bool,
+            features: Vec<String>,
+            endpoints: Vec<Endpoint>,
+            database: Database,
+            timeout: Option<u64>,
+        }
+
+        let yaml = r#"
+app:
+  name: Demo
+  debug: true
+  features: [auth, metrics]
+  endpoints:
+    - host: a.local
+      port: 80
+    - host: b.local
+      port: 81
+  database:
+    pool_size: 8
+other:
+  ignored: 1
+"#;
+        let manager = ConfigManager::new();
+        manager
+            .add_source(ConfigSource::InlineYaml(yaml.to_string()))
+            .unwrap();
+
+        let config: AppConfig = manager.bind("app").unwrap();
+        assert_eq!(config.name, "Demo");
+        assert!(config.debug);
+        assert_eq!(config.features, vec!["auth", "metrics"]);
+        assert_eq!(
+            config.endpoints,
+            vec![
+                Endpoint {
+                    host: "a.local".to_string(),
+                    port: 80
+                },
+                Endpoint {
+                    host: "b.local".to_string(),
+                    port: 81
+                },
+            ]
+        );
+        assert_eq!(config.database.pool_size, 8);
+        assert_eq!(config.timeout, None);
+
+        let endpoints: Vec<Endpoint> = manager.bind("app.endpoints").unwrap();
+        assert_eq!(endpoints.len(), 2);
+    }
+
+    #[test]
+    fn test_bind_reports_invalid_values() {
+        #[derive(Debug, Deserialize)]
+        struct Server {
+            #[allow(dead_code)]
+            port: u16,
+        }
+
+        let manager = ConfigManager::new();
+        let mut props = HashMap::new();
+        props.insert("server.port".to_string(), "eighty".to_string());
+        manager.add_source(ConfigSource::Properties(props)).unwrap();
+
+        match manager.bind::<Server>("server") {
+            Err(ContextError::PropertyBindingError { property, reason }) => {
+                assert_eq!(property, "server");
+                assert!(reason.contains("eighty"));
+            }
+            other => panic!("expected PropertyBindingError, got {:?}", other),
+        }
+        assert!(manager.bind::<Server>("missing").is_err());
+    }
+
+    #[test]
+    fn test_yaml_sequence_of_mappings() {
+        let yaml_content = r#"
+servers:
+  - host: "alpha"
+    port: 8080
+  - host: "beta"
+    tags: ["a", "b"]
+matrix:
+  - [1, 2]
+  - [3]
+"#;
+
+        let manager = ConfigManager::new();
+        let yaml_value: serde_yaml::Value = serde_yaml::from_str(yaml_content).unwrap();
+        let config_map = manager.yaml_value_to_config_map(&yaml_value, "").unwrap();
+        let value = |key: &str| config_map.get(key).and_then(ConfigValue::as_string);
+
+        assert_eq!(value("servers[0].host").as_deref(), Some("alpha"));
+        assert_eq!(value("servers[0].port").as_deref(), Some("8080"));
+        assert_eq!(value("servers[1].host").as_deref(), Some("beta"));
+        assert_eq!(value("servers[1].tags").as_deref(), Some("a,b"));
+        assert_eq!(value("matrix[0]").as_deref(), Some("1,2"));
+        assert_eq!(value("matrix[1]").as_deref(), Some("3"));
+        assert!(!config_map.contains_key("servers"));
+    }
+
+    #[test]
+    fn test_yaml_null_values() {
+        #[derive(Debug, Deserialize)]
+        struct Server {
+            host: Option<String>,
+            name: String,
+        }
+
+        let manager = ConfigManager::new();
+        manager
+            .add_source(ConfigSource::InlineYaml(
+                "server:\n  host: ~\n  port: null\n  name: \"\"\n".to_string(),
+            ))
+            .unwrap();
+        manager
+            .add_source(ConfigSource::InlineJson(r#"{"json": {"value": null}}"#.to_string()))
+            .unwrap();
+
+        assert_eq!(manager.get("server.host"), Some(ConfigValue::Null));
+        assert_eq!(manager.get("json.value"), Some(ConfigValue::Null));
+        assert_eq!(manager.get("server.name"), Some(ConfigValue::String(String::new())));
+        assert_eq!(ConfigValue::Null.as_string(), None);
+
+        assert!(matches!(
+            manager.get_string("server.host"),
+            Err(ContextError::ConfigurationNotFound { .. })
+        ));
+        assert!(matches!(
+            manager.get_integer("server.port"),
+            Err(ContextError::ConfigurationNotFound { .. })
+        ));
+        assert_eq!(manager.get_integer_or_default("server.port", 8080), 8080);
+        assert_eq!(manager.get_list("server.host"), None);
+        assert_eq!(manager.get_string("server.name").unwrap(), "");
+
+        let server: Server = manager.bind("server").unwrap();
+        assert_eq!(server.host, None);
+        assert_eq!(server.name, "");
+    }
+
+    #[test]
+    fn test_toml_arrays_of_tables() {
+        let toml_content = r#"
+ports = [80, 443]
+
+[[servers]]
+host = "alpha"
+port = 8080
+
+[[servers]]
+host = "beta"
+inline = { enabled = true }
+"#;
+
+        let manager = ConfigManager::new();
+        let toml_value: toml::Value = toml::from_str(toml_content).unwrap();
+        let config_map = manager.toml_value_to_config_map(&toml_value, "").unwrap();
+
+        assert_eq!(config_map.get("ports"), Some(&"80,443".to_string()));
+        assert_eq!(config_map.get("servers[0].host"), Some(&"alpha".to_string()));
+        assert_eq!(config_map.get("servers[0].port"), Some(&"8080".to_string()));
+        assert_eq!(config_map.get("servers[1].host"), Some(&"beta".to_string()));
         assert_eq!(
-            config_map.get("app.features"),
-            Some(&"auth,logging".to_string())
+            config_map.get("servers[1].inline.enabled"),
+            Some(&"true".to_string())
         );
+        assert!(!config_map.contains_key("servers"));
     }
 
     #[test]
@@ -1091,4 +3371,225 @@ file.path=C:\\Users\\Test
         assert_eq!(manager.get_string("app.version").unwrap(), "1.0"); // Only in first source
         assert_eq!(manager.get_string("app.env").unwrap(), "test"); // Only in second source
     }
+
+    #[test]
+    fn test_all_keys() {
+        let manager = ConfigManager::new();
+        assert!(manager.all_keys().is_empty());
+
+        let mut props1 = HashMap::new();
+        props1.insert("app.name".to_string(), "App1".to_string());
+        props1.insert("flags.beta".to_string(), "on".to_string());
+        manager.add_source(ConfigSource::Properties(props1)).unwrap();
+
+        let mut props2 = HashMap::new();
+        props2.insert("app.name".to_string(), "App2".to_string());
+        manager.add_source(ConfigSource::Properties(props2)).unwrap();
+        manager.set("runtime.key", ConfigValue::String("value".to_string()));
+
+        assert_eq!(
+            manager.all_keys(),
+            vec!["app.name", "flags.beta", "runtime.key"]
+        );
+    }
+
+    #[test]
+    fn test_check_source_reports_file_errors() {
+        let manager = ConfigManager::new();
+        let dir = std::env::temp_dir();
+
+        let missing = dir.join(format!("verdure_check_missing_{}.toml", std::process::id()));
+        match manager.check_source(&ConfigSource::TomlFile(
+            missing.to_string_lossy().to_string(),
+        )) {
+            Err(ContextError::ConfigurationFileError {
+                source: Some(source),
+                ..
+            }) => assert!(source.get().downcast_ref::<std::io::Error>().is_some()),
+            other => panic!("expected a read error, got {:?}", other),
+        }
+
+        let malformed = dir.join(format!("verdure_check_malformed_{}.toml", std::process::id()));
+        std::fs::write(&malformed, "[server\nport = ").unwrap();
+        let path_str = malformed.to_string_lossy().to_string();
+        match manager.check_source(&ConfigSource::TomlFile(path_str.clone())) {
+            Err(ContextError::ConfigurationFileError { message, path, .. }) => {
+                assert!(message.starts_with("failed to parse"));
+                assert_eq!(path, Some(path_str));
+            }
+            other => panic!("expected a parse error, got {:?}", other),
+        }
+        std::fs::remove_file(&malformed).unwrap();
+
+        assert!(manager.check_source(&ConfigSource::Environment).is_ok());
+    }
+
+    #[test]
+    fn test_broken_yaml_with_known_extension_is_not_reparsed() {
+        let path = std::env::temp_dir().join(format!(
+            "verdure_broken_{}.yaml",
+            std::process::id()
+        ));
+        std::fs::write(&path, "server:\n  port: 8080\n name: [unclosed\n").unwrap();
+        let path_str = path.to_string_lossy().to_string();
+
+        let manager = ConfigManager::new();
+        let source = ConfigSource::ConfigFile(path_str.clone());
+        match manager.check_source(&source) {
+            Err(ContextError::ConfigurationFileError { message, path, .. }) => {
+                assert!(message.starts_with("failed to parse"));
+                assert_eq!(path, Some(path_str));
+            }
+            other => panic!("expected a YAML parse error, got {:?}", other),
+        }
+
+        // No junk keys from a Properties fallback
+        manager.add_source(source).unwrap();
+        assert!(manager.all_keys().is_empty());
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_unknown_extension_falls_back() {
+        let path = std::env::temp_dir().join(format!(
+            "verdure_fallback_{}.conf",
+            std::process::id()
+        ));
+        std::fs::write(&path, "server.port=8080\nserver.host=localhost\n").unwrap();
+
+        let manager = ConfigManager::new();
+        let source = ConfigSource::ConfigFile(path.to_string_lossy().to_string());
+        assert!(manager.check_source(&source).is_ok());
+        manager.add_source(source).unwrap();
+        assert_eq!(manager.get_string("server.host").unwrap(), "localhost");
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_ambiguous_unknown_extension_is_logged() {
+        let dir = std::env::temp_dir();
+        let write = |name: &str, content: &str| {
+            let path = dir.join(format!("verdure_{}_{}.conf", name, std::process::id()));
+            std::fs::write(&path, content).unwrap();
+            path.to_string_lossy().to_string()
+        };
+        // Quoted TOML strings keep their quotes when read as properties
+        let ambiguous = write("ambiguous", "name = \"App\"\nport = 8080\n");
+        // Nested YAML shares no keys with its properties reading, and YAML-compatible
+        // properties read the same
+        let nested = write("nested_yaml", "server:\n  port: 8080\n");
+        let flat = write("flat_yaml", "app.name: Demo\n");
+
+        let manager = ConfigManager::new();
+        let warnings = Arc::new(parking_lot::Mutex::new(Vec::new()));
+        let sink = warnings.clone();
+        manager.set_logger(move |level, message| {
+            assert_eq!(level, LogLevel::Warn);
+            sink.lock().push(message.to_string());
+        });
+
+        manager.add_source(ConfigSource::ConfigFile(ambiguous.clone())).unwrap();
+        assert_eq!(manager.get_string("name").unwrap(), "App");
+        assert_eq!(manager.get_integer("port").unwrap(), 8080);
+        assert_eq!(warnings.lock().len(), 1);
+        assert!(warnings.lock()[0].contains("both TOML and properties"));
+        assert!(warnings.lock()[0].contains(&ambiguous));
+
+        manager.add_source(ConfigSource::ConfigFile(nested.clone())).unwrap();
+        manager.add_source(ConfigSource::ConfigFile(flat.clone())).unwrap();
+        assert_eq!(manager.get_integer("server.port").unwrap(), 8080);
+        assert_eq!(manager.get_string("app.name").unwrap(), "Demo");
+        assert!(warnings.lock().iter().all(|warning| warning.contains(&ambiguous)));
+
+        for path in [ambiguous, nested, flat] {
+            std::fs::remove_file(path).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_invalid_value_reports_source_file() {
+        let path = std::env::temp_dir().join(format!(
+            "verdure_provenance_{}.toml",
+            std::process::id()
+        ));
+        std::fs::write(&path, "[server]\nport = \"eighty\"\nhost = \"localhost\"\n").unwrap();
+
+        let manager = ConfigManager::new();
+        let path_str = path.to_string_lossy().to_string();
+        manager
+            .add_source(ConfigSource::TomlFile(path_str.clone()))
+            .unwrap();
+
+        let mut props = HashMap::new();
+        props.insert("server.host".to_string(), "example.com".to_string());
+        manager.add_source(ConfigSource::Properties(props)).unwrap();
+
+        assert_eq!(
+            manager.source_of("server.port").unwrap(),
+            format!("TOML file '{}'", path_str)
+        );
+        assert_eq!(
+            manager.source_of("server.host").unwrap(),
+            "in-memory properties"
+        );
+
+        match manager.get_integer("server.port") {
+            Err(ContextError::InvalidConfiguration { key, reason }) => {
+                assert_eq!(key, "server.port");
+                assert!(reason.contains(&path_str));
+            }
+            other => panic!("expected InvalidConfiguration, got {:?}", other),
+        }
+        assert!(matches!(
+            manager.get_integer("server.missing"),
+            Err(ContextError::ConfigurationNotFound { .. })
+        ));
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_inline_sources() {
+        let manager = ConfigManager::new();
+        manager
+            .add_source(ConfigSource::InlineToml(
+                "[server]\nport = 8080\nhost = \"localhost\"\n".to_string(),
+            ))
+            .unwrap();
+        manager
+            .add_source(ConfigSource::InlineYaml("server:\n  host: example.org\n".to_string()))
+            .unwrap();
+        manager
+            .add_source(ConfigSource::InlineJson(
+                r#"{"server": {"workers": 4}, "features": ["search", "chat"]}"#.to_string(),
+            ))
+            .unwrap();
+
+        assert_eq!(manager.get_integer("server.port").unwrap(), 8080);
+        assert_eq!(manager.get_string("server.host").unwrap(), "example.org");
+        assert_eq!(manager.get_integer("server.workers").unwrap(), 4);
+        assert!(manager.get("features").is_some());
+        assert!(manager.sources.read().iter().all(|entry| entry.inline.is_some()));
+        assert!(manager.parsed_files.is_empty());
+    }
+
+    #[test]
+    fn test_invalid_inline_source() {
+        let manager = ConfigManager::new();
+        let source = ConfigSource::InlineJson("{ not json".to_string());
+        assert_eq!(source.description(), "inline JSON");
+
+        let error = manager.check_source(&source).unwrap_err();
+        assert!(error.to_string().contains("inline JSON"), "{}", error);
+        assert!(matches!(
+            error,
+            ContextError::ConfigurationFileError { path: None, .. }
+        ));
+
+        manager.add_source(source).unwrap();
+        assert!(manager.get("anything").is_none());
+        assert!(manager.sources.read()[0].inline.is_none());
+    }
 }
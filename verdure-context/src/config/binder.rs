@@ -0,0 +1,476 @@
+//! Binding flattened configuration into `serde` types
+//!
+//! Configuration sources are stored as flat `dotted.key -> string` maps. This module
+//! rebuilds a tree from such keys (including `list[0]` index notation) and provides a
+//! lenient `serde` deserializer over it: string leaves are parsed into whatever
+//! primitive the target type asks for, and comma-joined leaves can be read as sequences.
+
+use serde::de::value::StrDeserializer;
+use serde::de::{self, DeserializeSeed, IntoDeserializer, Visitor};
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// Error produced while binding configuration into a type
+#[derive(Debug)]
+pub(crate) struct BindError(String);
+
+impl fmt::Display for BindError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for BindError {}
+
+impl de::Error for BindError {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        BindError(msg.to_string())
+    }
+}
+
+/// A node of the un-flattened configuration tree
+#[derive(Debug)]
+pub(crate) enum Node {
+    Leaf { key: String, value: String },
+    Map(BTreeMap<String, Node>),
+    Seq(BTreeMap<usize, Node>),
+}
+
+enum Segment {
+    Key(String),
+    Index(usize),
+}
+
+impl Node {
+    /// Builds a tree from `(relative key, value)` pairs
+    pub(crate) fn from_flat<I>(entries: I) -> Node
+    where
+        I: IntoIterator<Item = (String, String)>,
+    {
+        let mut root = Node::Map(BTreeMap::new());
+        for (key, value) in entries {
+            let path = parse_path(&key);
+            root.insert(&path, key, value);
+        }
+        root
+    }
+
+    fn insert(&mut self, path: &[Segment], key: String, value: String) {
+        let Some((first, rest)) = path.split_first() else {
+            // A nested value takes precedence over a scalar at the same key
+            if !matches!(self, Node::Map(_) | Node::Seq(_)) || self.is_empty() {
+                *self = Node::Leaf { key, value };
+            }
+            return;
+        };
+
+        match first {
+            Segment::Key(name) => {
+                if !matches!(self, Node::Map(_)) {
+                    *self = Node::Map(BTreeMap::new());
+                }
+                if let Node::Map(map) = self {
+                    map.entry(name.clone())
+                        .or_insert_with(|| Node::Map(BTreeMap::new()))
+                        .insert(rest, key, value);
+                }
+            }
+            Segment::Index(index) => {
+                if !matches!(self, Node::Seq(_)) {
+                    *self = Node::Seq(BTreeMap::new());
+                }
+                if let Node::Seq(items) = self {
+                    items
+                        .entry(*index)
+                        .or_insert_with(|| Node::Map(BTreeMap::new()))
+                        .insert(rest, key, value);
+                }
+            }
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        match self {
+            Node::Leaf { .. } => false,
+            Node::Map(map) => map.is_empty(),
+            Node::Seq(items) => items.is_empty(),
+        }
+    }
+}
+
+/// Splits `servers[0].host` into `servers`, `0`, `host`
+fn parse_path(key: &str) -> Vec<Segment> {
+    let mut path = Vec::new();
+    for part in key.split('.') {
+        let (name, mut indices) = match part.find('[') {
+            Some(pos) => (&part[..pos], &part[pos..]),
+            None => (part, ""),
+        };
+        if !name.is_empty() {
+            path.push(Segment::Key(name.to_string()));
+        }
+        while let Some(rest) = indices.strip_prefix('[') {
+            let Some(end) = rest.find(']') else { break };
+            match rest[..end].parse() {
+                Ok(index) => path.push(Segment::Index(index)),
+                Err(_) => path.push(Segment::Key(rest[..end].to_string())),
+            }
+            indices = &rest[end + 1..];
+        }
+    }
+    path
+}
+
+impl Node {
+    fn describe(&self) -> String {
+        match self {
+            Node::Leaf { key, value } => format!("'{}' = '{}'", key, value),
+            Node::Map(_) => "a table".to_string(),
+            Node::Seq(_) => "a list".to_string(),
+        }
+    }
+
+    fn leaf(self, expected: &str) -> Result<(String, String), BindError> {
+        match self {
+            Node::Leaf { key, value } => Ok((key, value)),
+            other => Err(BindError(format!(
+                "expected {}, found {}",
+                expected,
+                other.describe()
+            ))),
+        }
+    }
+}
+
+macro_rules! deserialize_parsed {
+    ($($method:ident => $visit:ident: $ty:ty),* $(,)?) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, BindError> {
+                let (key, value) = self.leaf(stringify!($ty))?;
+                let parsed = value.trim().parse::<$ty>().map_err(|e| {
+                    BindError(format!(
+                        "invalid {} for '{}': '{}' ({})",
+                        stringify!($ty),
+                        key,
+                        value,
+                        e
+                    ))
+                })?;
+                visitor.$visit(parsed)
+            }
+        )*
+    };
+}
+
+impl<'de> de::Deserializer<'de> for Node {
+    type Error = BindError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, BindError> {
+        match self {
+            Node::Leaf { value, .. } => visitor.visit_string(value),
+            Node::Map(map) => visitor.visit_map(MapAccess::new(map)),
+            Node::Seq(items) => visitor.visit_seq(SeqAccess::new(items.into_values().collect())),
+        }
+    }
+
+    fn deserialize_bool<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, BindError> {
+        let (key, value) = self.leaf("bool")?;
+        match crate::config::ConfigValue::String(value.clone()).as_boolean() {
+            Some(b) => visitor.visit_bool(b),
+            None => Err(BindError(format!(
+                "invalid bool for '{}': '{}'",
+                key, value
+            ))),
+        }
+    }
+
+    deserialize_parsed! {
+        deserialize_i8 => visit_i8: i8,
+        deserialize_i16 => visit_i16: i16,
+        deserialize_i32 => visit_i32: i32,
+        deserialize_i64 => visit_i64: i64,
+        deserialize_i128 => visit_i128: i128,
+        deserialize_u8 => visit_u8: u8,
+        deserialize_u16 => visit_u16: u16,
+        deserialize_u32 => visit_u32: u32,
+        deserialize_u64 => visit_u64: u64,
+        deserialize_u128 => visit_u128: u128,
+        deserialize_f32 => visit_f32: f32,
+        deserialize_f64 => visit_f64: f64,
+        deserialize_char => visit_char: char,
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, BindError> {
+        let (_, value) = self.leaf("a string")?;
+        visitor.visit_string(value)
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, BindError> {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_bytes<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, BindError> {
+        let (_, value) = self.leaf("bytes")?;
+        visitor.visit_byte_buf(value.into_bytes())
+    }
+
+    fn deserialize_byte_buf<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, BindError> {
+        self.deserialize_bytes(visitor)
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, BindError> {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, BindError> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, BindError> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, BindError> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, BindError> {
+        match self {
+            Node::Seq(items) => visitor.visit_seq(SeqAccess::new(items.into_values().collect())),
+            // Flattened scalar lists arrive comma-joined
+            Node::Leaf { key, value } => {
+                let items = value
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|item| !item.is_empty())
+                    .enumerate()
+                    .map(|(index, item)| Node::Leaf {
+                        key: format!("{}[{}]", key, index),
+                        value: item.to_string(),
+                    })
+                    .collect();
+                visitor.visit_seq(SeqAccess::new(items))
+            }
+            Node::Map(map) if map.is_empty() => visitor.visit_seq(SeqAccess::new(Vec::new())),
+            other => Err(BindError(format!(
+                "expected a list, found {}",
+                other.describe()
+            ))),
+        }
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(
+        self,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, BindError> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, BindError> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_map<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, BindError> {
+        match self {
+            Node::Map(map) => visitor.visit_map(MapAccess::new(map)),
+            other => Err(BindError(format!(
+                "expected a table, found {}",
+                other.describe()
+            ))),
+        }
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, BindError> {
+        self.deserialize_map(visitor)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, BindError> {
+        let (key, value) = self.leaf("an enum variant")?;
+        let deserializer: StrDeserializer<'_, BindError> = value.trim().into_deserializer();
+        visitor.visit_enum(deserializer).map_err(|_| {
+            BindError(format!(
+                "invalid value for '{}': '{}', expected one of {:?}",
+                key, value, variants
+            ))
+        })
+    }
+
+    fn deserialize_identifier<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, BindError> {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, BindError> {
+        visitor.visit_unit()
+    }
+}
+
+struct MapAccess {
+    entries: std::collections::btree_map::IntoIter<String, Node>,
+    value: Option<Node>,
+}
+
+impl MapAccess {
+    fn new(map: BTreeMap<String, Node>) -> Self {
+        Self {
+            entries: map.into_iter(),
+            value: None,
+        }
+    }
+}
+
+impl<'de> de::MapAccess<'de> for MapAccess {
+    type Error = BindError;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, BindError> {
+        match self.entries.next() {
+            Some((key, value)) => {
+                self.value = Some(value);
+                seed.deserialize(key.into_deserializer()).map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, BindError> {
+        match self.value.take() {
+            Some(value) => seed.deserialize(value),
+            None => Err(BindError("value requested before key".to_string())),
+        }
+    }
+}
+
+struct SeqAccess {
+    items: std::vec::IntoIter<Node>,
+}
+
+impl SeqAccess {
+    fn new(items: Vec<Node>) -> Self {
+        Self {
+            items: items.into_iter(),
+        }
+    }
+}
+
+impl<'de> de::SeqAccess<'de> for SeqAccess {
+    type Error = BindError;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, BindError> {
+        match self.items.next() {
+            Some(item) => seed.deserialize(item).map(Some),
+            None => Ok(None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+    use std::collections::HashMap;
+
+    fn tree(entries: &[(&str, &str)]) -> Node {
+        Node::from_flat(entries.iter().map(|(k, v)| (k.to_string(), v.to_string())))
+    }
+
+    #[test]
+    fn test_unflatten_index_notation() {
+        #[derive(Debug, Deserialize, PartialEq)]
+        struct Server {
+            host: String,
+            port: u16,
+        }
+
+        let node = tree(&[
+            ("servers[1].host", "b"),
+            ("servers[1].port", "81"),
+            ("servers[0].host", "a"),
+            ("servers[0].port", "80"),
+        ]);
+
+        let map: HashMap<String, Vec<Server>> = Deserialize::deserialize(node).unwrap();
+        assert_eq!(
+            map["servers"],
+            vec![
+                Server {
+                    host: "a".to_string(),
+                    port: 80
+                },
+                Server {
+                    host: "b".to_string(),
+                    port: 81
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lenient_primitives_and_errors() {
+        #[derive(Debug, Deserialize)]
+        #[serde(rename_all = "lowercase")]
+        enum Mode {
+            Fast,
+            Safe,
+        }
+
+        #[derive(Debug, Deserialize)]
+        struct Settings {
+            enabled: bool,
+            ratio: f64,
+            mode: Mode,
+            tags: Vec<String>,
+            missing: Option<u32>,
+        }
+
+        let settings: Settings = Deserialize::deserialize(tree(&[
+            ("enabled", "Yes"),
+            ("ratio", "0.5"),
+            ("mode", "safe"),
+            ("tags", "a, b"),
+        ]))
+        .unwrap();
+        assert!(settings.enabled);
+        assert_eq!(settings.ratio, 0.5);
+        assert!(matches!(settings.mode, Mode::Safe));
+        assert_eq!(settings.tags, vec!["a", "b"]);
+        assert_eq!(settings.missing, None);
+
+        let err = Settings::deserialize(tree(&[
+            ("enabled", "true"),
+            ("ratio", "half"),
+            ("mode", "fast"),
+            ("tags", ""),
+        ]))
+        .unwrap_err();
+        assert!(err.to_string().contains("'ratio'"));
+    }
+}
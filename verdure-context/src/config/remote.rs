@@ -0,0 +1,284 @@
+//! Pluggable remote configuration sources
+//!
+//! A [`RemoteConfigProvider`] adapts any configuration service (Consul, etcd, an HTTP
+//! endpoint, ...) to the configuration manager without this crate depending on a
+//! specific client. Fetched values are kept for the provider's [`ttl`] so that looking
+//! up many keys does not issue one request per key.
+//!
+//! A [`DynamicProvider`] is the closure-based counterpart used by
+//! [`ConfigSource::Dynamic`](crate::ConfigSource::Dynamic), e.g. for feature flags.
+//!
+//! [`ttl`]: RemoteConfigProvider::ttl
+
+use crate::config::ConfigValue;
+use crate::error::ContextResult;
+use parking_lot::Mutex;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// A source of configuration values fetched from outside the process
+///
+/// # Examples
+///
+/// ```rust
+/// use verdure_context::config::RemoteConfigProvider;
+/// use verdure_context::{ConfigManager, ConfigSource, ConfigValue, ContextResult};
+/// use std::collections::HashMap;
+/// use std::sync::Arc;
+///
+/// struct StaticProvider;
+///
+/// impl RemoteConfigProvider for StaticProvider {
+///     fn fetch(&self) -> ContextResult<HashMap<String, ConfigValue>> {
+///         let mut values = HashMap::new();
+///         values.insert("app.name".to_string(), ConfigValue::String("Remote".to_string()));
+///         Ok(values)
+///     }
+/// }
+///
+/// let manager = ConfigManager::new();
+/// manager.add_source(ConfigSource::Remote(Arc::new(StaticProvider))).unwrap();
+/// assert_eq!(manager.get_string("app.name").unwrap(), "Remote");
+/// ```
+pub trait RemoteConfigProvider: Send + Sync {
+    /// Fetches all configuration values provided by the remote source
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the remote source cannot be reached or its response is invalid
+    fn fetch(&self) -> ContextResult<HashMap<String, ConfigValue>>;
+
+    /// Returns a short human-readable description of the provider
+    fn description(&self) -> String {
+        "remote source".to_string()
+    }
+
+    /// Returns how long fetched values are reused before fetching again
+    fn ttl(&self) -> Duration {
+        Duration::from_secs(30)
+    }
+}
+
+impl fmt::Debug for dyn RemoteConfigProvider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("RemoteConfigProvider")
+            .field(&self.description())
+            .finish()
+    }
+}
+
+/// A function producing configuration values, consulted again once its TTL expires
+///
+/// # Examples
+///
+/// ```rust
+/// use verdure_context::config::DynamicProvider;
+/// use verdure_context::{ConfigManager, ConfigSource, ConfigValue};
+/// use std::collections::HashMap;
+/// use std::time::Duration;
+///
+/// let flags = DynamicProvider::new(|| {
+///     HashMap::from([("features.beta".to_string(), ConfigValue::Boolean(true))])
+/// });
+///
+/// let manager = ConfigManager::new();
+/// manager
+///     .add_source(ConfigSource::Dynamic {
+///         provider: flags,
+///         ttl: Duration::from_secs(10),
+///     })
+///     .unwrap();
+/// assert!(manager.get_boolean("features.beta").unwrap());
+/// ```
+#[derive(Clone)]
+pub struct DynamicProvider(Arc<dyn Fn() -> HashMap<String, ConfigValue> + Send + Sync>);
+
+impl DynamicProvider {
+    /// Wraps a function returning the current configuration values
+    pub fn new<F>(provider: F) -> Self
+    where
+        F: Fn() -> HashMap<String, ConfigValue> + Send + Sync + 'static,
+    {
+        Self(Arc::new(provider))
+    }
+}
+
+impl fmt::Debug for DynamicProvider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("DynamicProvider")
+    }
+}
+
+/// Values fetched from a provider and the time they were fetched
+struct Snapshot {
+    fetched_at: Instant,
+    values: Arc<HashMap<String, ConfigValue>>,
+}
+
+/// Caches the values fetched for one source until their TTL expires
+///
+/// The lock is held while fetching, so concurrent lookups of a missing or expired
+/// snapshot fetch only once.
+#[derive(Default)]
+pub(crate) struct RemoteSnapshot {
+    snapshot: Mutex<Option<Snapshot>>,
+}
+
+impl RemoteSnapshot {
+    /// Returns the cached values of `provider`, fetching them if missing or expired
+    pub(crate) fn values(
+        &self,
+        provider: &Arc<dyn RemoteConfigProvider>,
+    ) -> ContextResult<Arc<HashMap<String, ConfigValue>>> {
+        self.cached(provider.ttl(), || provider.fetch())
+    }
+
+    /// Returns the cached values of a dynamic `provider`, calling it if missing or expired
+    pub(crate) fn dynamic_values(
+        &self,
+        provider: &DynamicProvider,
+        ttl: Duration,
+    ) -> Arc<HashMap<String, ConfigValue>> {
+        self.cached(ttl, || Ok((provider.0)())).unwrap_or_default()
+    }
+
+    fn cached(
+        &self,
+        ttl: Duration,
+        fetch: impl FnOnce() -> ContextResult<HashMap<String, ConfigValue>>,
+    ) -> ContextResult<Arc<HashMap<String, ConfigValue>>> {
+        let mut snapshot = self.snapshot.lock();
+        if let Some(snapshot) = snapshot.as_ref()
+            && snapshot.fetched_at.elapsed() < ttl
+        {
+            return Ok(snapshot.values.clone());
+        }
+
+        let values = Arc::new(fetch()?);
+        *snapshot = Some(Snapshot {
+            fetched_at: Instant::now(),
+            values: values.clone(),
+        });
+        Ok(values)
+    }
+
+    /// Drops the cached values so the next lookup fetches again
+    pub(crate) fn clear(&self) {
+        *self.snapshot.lock() = None;
+    }
+}
+
+impl fmt::Debug for RemoteSnapshot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("RemoteSnapshot")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ContextError;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    struct CountingProvider {
+        fetches: AtomicUsize,
+        ttl: Duration,
+    }
+
+    impl RemoteConfigProvider for CountingProvider {
+        fn fetch(&self) -> ContextResult<HashMap<String, ConfigValue>> {
+            let count = self.fetches.fetch_add(1, Ordering::SeqCst) + 1;
+            let mut values = HashMap::new();
+            values.insert(
+                "fetch.count".to_string(),
+                ConfigValue::Integer(count as i64),
+            );
+            Ok(values)
+        }
+
+        fn ttl(&self) -> Duration {
+            self.ttl
+        }
+    }
+
+    struct FailingProvider;
+
+    impl RemoteConfigProvider for FailingProvider {
+        fn fetch(&self) -> ContextResult<HashMap<String, ConfigValue>> {
+            Err(ContextError::configuration_not_found("remote unreachable"))
+        }
+
+        fn description(&self) -> String {
+            "failing provider".to_string()
+        }
+    }
+
+    #[test]
+    fn test_values_are_reused_within_ttl() {
+        let provider: Arc<dyn RemoteConfigProvider> = Arc::new(CountingProvider {
+            fetches: AtomicUsize::new(0),
+            ttl: Duration::from_secs(60),
+        });
+        let snapshots = RemoteSnapshot::default();
+
+        snapshots.values(&provider).unwrap();
+        let values = snapshots.values(&provider).unwrap();
+        assert_eq!(values["fetch.count"], ConfigValue::Integer(1));
+
+        snapshots.clear();
+        let values = snapshots.values(&provider).unwrap();
+        assert_eq!(values["fetch.count"], ConfigValue::Integer(2));
+    }
+
+    #[test]
+    fn test_values_are_fetched_again_after_ttl() {
+        let provider: Arc<dyn RemoteConfigProvider> = Arc::new(CountingProvider {
+            fetches: AtomicUsize::new(0),
+            ttl: Duration::ZERO,
+        });
+        let snapshots = RemoteSnapshot::default();
+
+        snapshots.values(&provider).unwrap();
+        let values = snapshots.values(&provider).unwrap();
+        assert_eq!(values["fetch.count"], ConfigValue::Integer(2));
+    }
+
+    #[test]
+    fn test_fetch_errors_are_not_cached() {
+        let provider: Arc<dyn RemoteConfigProvider> = Arc::new(FailingProvider);
+        let snapshots = RemoteSnapshot::default();
+
+        assert!(snapshots.values(&provider).is_err());
+        assert!(snapshots.snapshot.lock().is_none());
+        assert_eq!(
+            format!("{:?}", provider),
+            "RemoteConfigProvider(\"failing provider\")"
+        );
+    }
+
+    #[test]
+    fn test_concurrent_lookups_fetch_once() {
+        struct SlowProvider(AtomicUsize);
+
+        impl RemoteConfigProvider for SlowProvider {
+            fn fetch(&self) -> ContextResult<HashMap<String, ConfigValue>> {
+                self.0.fetch_add(1, Ordering::SeqCst);
+                std::thread::sleep(Duration::from_millis(20));
+                Ok(HashMap::new())
+            }
+        }
+
+        let slow = Arc::new(SlowProvider(AtomicUsize::new(0)));
+        let provider: Arc<dyn RemoteConfigProvider> = slow.clone();
+        let snapshots = RemoteSnapshot::default();
+
+        std::thread::scope(|scope| {
+            for _ in 0..4 {
+                scope.spawn(|| snapshots.values(&provider).unwrap());
+            }
+        });
+        assert_eq!(slow.0.load(Ordering::SeqCst), 1);
+    }
+}
@@ -4,15 +4,22 @@
 //! central hub for application-wide state, configuration, environment management, and
 //! integration with the IoC container.
 
-use crate::config::{ConfigFactory, ConfigManager, ConfigSource, ConfigValue};
+use crate::config::{ConfigManager, ConfigSource, ConfigValue, register_config_components};
+use crate::environment::{ENVIRONMENT_KEY, Environment};
 use crate::error::{ContextError, ContextResult};
 use crate::event::{
-    ConfigurationChangedEvent, ContextAwareEventListener, ContextInitializedEvent,
+    ConfigKeyListener, ConfigurationChangedEvent, ContextAwareEventListener,
+    ContextInitializedEvent, ContextListenerDefinition,
+    ContextRefreshedEvent,
     ContextInitializingEvent, Event, EventListener, EventPublisher,
 };
-use dashmap::DashMap;
-use std::path::Path;
-use std::sync::Arc;
+use crate::wildcard;
+use parking_lot::RwLock;
+use serde::de::DeserializeOwned;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Weak};
+use verdure_core::logging::{LogLevel, Logger};
 use verdure_ioc::{ComponentContainer, ComponentFactory, ComponentInstance};
 
 /// Application context builder
@@ -34,7 +41,12 @@ use verdure_ioc::{ComponentContainer, ComponentFactory, ComponentInstance};
 #[derive(Debug)]
 pub struct ApplicationContextBuilder {
     config_sources: Vec<ConfigSource>,
+    required_sources: Vec<usize>,
+    /// Wildcard patterns of configuration files, with the number of sources added before
+    config_globs: Vec<(usize, PathBuf)>,
     properties: std::collections::HashMap<String, String>,
+    use_global_container: bool,
+    environment: bool,
 }
 
 impl ApplicationContextBuilder {
@@ -50,7 +62,11 @@ impl ApplicationContextBuilder {
     pub fn new() -> Self {
         Self {
             config_sources: Vec::new(),
+            required_sources: Vec::new(),
+            config_globs: Vec::new(),
             properties: std::collections::HashMap::new(),
+            use_global_container: false,
+            environment: false,
         }
     }
 
@@ -143,6 +159,12 @@ impl ApplicationContextBuilder {
     /// - `.properties` -> Properties format
     /// - Others -> Attempts to parse as TOML first, then YAML, then Properties
     ///
+    /// A known extension is authoritative: a malformed `.yaml` file is reported as a
+    /// YAML error by [`with_config_file_required`](Self::with_config_file_required)
+    /// rather than being re-read as another format. A file with another extension that
+    /// two formats read differently, such as `name = "App"`, is read in the first of
+    /// them and a warning is logged; use a format-specific method for it.
+    ///
     /// # Arguments
     ///
     /// * `path` - Path to the configuration file
@@ -163,6 +185,216 @@ impl ApplicationContextBuilder {
         self
     }
 
+    /// Loads every configuration file matching a wildcard pattern
+    ///
+    /// The last path component may contain `*` (any characters) and `?` (one
+    /// character), e.g. `config/*.yml` or `conf.d/??-*.toml`; the directory part is
+    /// taken literally. Matching files are added as with
+    /// [`with_config_file`](Self::with_config_file) in sorted file name order, so later
+    /// files override earlier ones and `10-override.yml` wins over `00-base.yml`.
+    /// Hidden files only match a pattern that starts with `.`.
+    ///
+    /// The pattern is expanded by [`build`](Self::build), so files created after this
+    /// call are picked up. If nothing matches, or the directory does not exist, no
+    /// source is added.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - Path of the files, with wildcards in the file name
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use verdure_context::ApplicationContextBuilder;
+    ///
+    /// let builder = ApplicationContextBuilder::new()
+    ///     .with_config_files_glob("config/conf.d/*.yml");
+    /// ```
+    pub fn with_config_files_glob<P: AsRef<Path>>(mut self, pattern: P) -> Self {
+        self.config_globs
+            .push((self.config_sources.len(), pattern.as_ref().to_path_buf()));
+        self
+    }
+
+    /// Loads a required configuration file with automatic format detection
+    ///
+    /// Unlike [`with_config_file`](Self::with_config_file), which treats a missing or
+    /// unreadable file as empty, [`build`](Self::build) fails if this file cannot be
+    /// read or parsed.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to the configuration file
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use verdure_context::{ApplicationContextBuilder, ContextError};
+    ///
+    /// let result = ApplicationContextBuilder::new()
+    ///     .with_config_file_required("does/not/exist.yml")
+    ///     .build();
+    ///
+    /// assert!(matches!(result, Err(ContextError::ConfigurationFileError { .. })));
+    /// ```
+    pub fn with_config_file_required<P: AsRef<Path>>(mut self, path: P) -> Self {
+        self.required_sources.push(self.config_sources.len());
+        self.with_config_file(path)
+    }
+
+    /// Adds configuration from TOML content held in memory
+    ///
+    /// Useful for defaults embedded with `include_str!`. Sources added later take
+    /// precedence, so add embedded defaults before the files that override them.
+    /// [`build`](Self::build) fails if the content cannot be parsed.
+    ///
+    /// # Arguments
+    ///
+    /// * `content` - The TOML content
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use verdure_context::ApplicationContextBuilder;
+    ///
+    /// let context = ApplicationContextBuilder::new()
+    ///     .with_inline_toml("[server]\nport = 8080\n")
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(context.get_config("server.port"), "8080");
+    /// ```
+    pub fn with_inline_toml(self, content: impl Into<String>) -> Self {
+        self.with_inline_source(ConfigSource::InlineToml(content.into()))
+    }
+
+    /// Adds configuration from YAML content held in memory
+    ///
+    /// See [`with_inline_toml`](Self::with_inline_toml).
+    ///
+    /// # Arguments
+    ///
+    /// * `content` - The YAML content
+    pub fn with_inline_yaml(self, content: impl Into<String>) -> Self {
+        self.with_inline_source(ConfigSource::InlineYaml(content.into()))
+    }
+
+    /// Adds configuration from JSON content held in memory
+    ///
+    /// See [`with_inline_toml`](Self::with_inline_toml).
+    ///
+    /// # Arguments
+    ///
+    /// * `content` - The JSON content
+    pub fn with_inline_json(self, content: impl Into<String>) -> Self {
+        self.with_inline_source(ConfigSource::InlineJson(content.into()))
+    }
+
+    /// Reads secrets from a directory holding one file per key
+    ///
+    /// This matches how Kubernetes and Vault agents mount secrets: `db.password` is
+    /// read from `{path}/db.password`, `{path}/db/password` or `{path}/db_password`,
+    /// with trailing newlines dropped. See [`ConfigSource::SecretDir`].
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to the secret directory
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use verdure_context::ApplicationContextBuilder;
+    ///
+    /// let builder = ApplicationContextBuilder::new()
+    ///     .with_secret_dir("/var/run/secrets/app");
+    /// ```
+    pub fn with_secret_dir<P: AsRef<Path>>(self, path: P) -> Self {
+        let path_str = path.as_ref().to_string_lossy().to_string();
+        self.with_config_source(ConfigSource::SecretDir(path_str))
+    }
+
+    /// Reads configuration from environment variables
+    ///
+    /// Disabled by default. When enabled, [`build`](Self::build) adds a
+    /// [`ConfigSource::Environment`] source after every source added on the builder
+    /// and before the [`with_property`](Self::with_property) values. Environment
+    /// variables therefore override configuration files, inline content and
+    /// [`with_config_source`](Self::with_config_source) sources, but not properties
+    /// set on the builder or sources added to the context after it is built.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether to add the environment source
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use verdure_context::ApplicationContextBuilder;
+    ///
+    /// let builder = ApplicationContextBuilder::new()
+    ///     .with_config_file("config/app.toml")
+    ///     .with_environment(true);
+    /// ```
+    pub fn with_environment(mut self, enabled: bool) -> Self {
+        self.environment = enabled;
+        self
+    }
+
+    /// Adds an inline source that must parse for [`build`](Self::build) to succeed
+    fn with_inline_source(mut self, source: ConfigSource) -> Self {
+        self.required_sources.push(self.config_sources.len());
+        self.with_config_source(source)
+    }
+
+    /// Loads the conventional `application` configuration file, if there is one
+    ///
+    /// Looks for `application.yml`, `application.yaml`, `application.toml` and
+    /// `application.properties`, in that order, first in the working directory and then
+    /// in its `config/` subdirectory. The first file found is added as with
+    /// [`with_config_file`](Self::with_config_file); if none exists, nothing is added.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use verdure_context::ApplicationContextBuilder;
+    ///
+    /// let context = ApplicationContextBuilder::new()
+    ///     .with_defaults()
+    ///     .with_property("app.name", "MyApp")
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn with_defaults(self) -> Self {
+        self.with_defaults_in(".")
+    }
+
+    /// Loads the conventional `application` configuration file from `dir`
+    ///
+    /// Behaves like [`with_defaults`](Self::with_defaults), searching `dir` and
+    /// `dir/config` instead of the working directory.
+    ///
+    /// # Arguments
+    ///
+    /// * `dir` - The directory to search
+    pub fn with_defaults_in<P: AsRef<Path>>(self, dir: P) -> Self {
+        const DEFAULT_CONFIG_FILES: [&str; 4] = [
+            "application.yml",
+            "application.yaml",
+            "application.toml",
+            "application.properties",
+        ];
+
+        let dir = dir.as_ref();
+        let found = [dir.to_path_buf(), dir.join("config")]
+            .into_iter()
+            .flat_map(|dir| DEFAULT_CONFIG_FILES.map(|name| dir.join(name)))
+            .find(|path| path.is_file());
+        match found {
+            Some(path) => self.with_config_file(path),
+            None => self,
+        }
+    }
+
     /// Sets a property value
     ///
     /// # Arguments
@@ -183,6 +415,94 @@ impl ApplicationContextBuilder {
         self
     }
 
+    /// Sets several property values at once
+    ///
+    /// Entries are merged into the builder's properties; later entries overwrite
+    /// earlier ones with the same key.
+    ///
+    /// # Arguments
+    ///
+    /// * `properties` - Key-value pairs to set
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use verdure_context::ApplicationContextBuilder;
+    ///
+    /// let context = ApplicationContextBuilder::new()
+    ///     .with_properties([("app.name", "MyApplication"), ("app.port", "8080")])
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(context.get_config("app.port"), "8080");
+    /// ```
+    pub fn with_properties<I, K, V>(mut self, properties: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.properties
+            .extend(properties.into_iter().map(|(k, v)| (k.into(), v.into())));
+        self
+    }
+
+    /// Sets a property value only if `condition` is true
+    ///
+    /// # Arguments
+    ///
+    /// * `condition` - Whether the property should be set
+    /// * `key` - The property key
+    /// * `value` - The property value
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use verdure_context::ApplicationContextBuilder;
+    ///
+    /// let verbose = false;
+    /// let builder = ApplicationContextBuilder::new()
+    ///     .with_property_if(verbose, "logging.level", "debug");
+    /// ```
+    pub fn with_property_if(
+        self,
+        condition: bool,
+        key: impl Into<String>,
+        value: impl Into<String>,
+    ) -> Self {
+        if condition {
+            self.with_property(key, value)
+        } else {
+            self
+        }
+    }
+
+    /// Makes the context manage the process-wide container
+    ///
+    /// By default each context owns a private `ComponentContainer`, so components
+    /// resolved through a separately created container are not visible to it. With
+    /// this option the context uses [`ComponentContainer::global`] instead, so code
+    /// that works with the global container directly sees the same components.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use verdure_context::ApplicationContextBuilder;
+    /// use verdure_ioc::ComponentContainer;
+    /// use std::sync::Arc;
+    ///
+    /// let context = ApplicationContextBuilder::new()
+    ///     .use_global_container()
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert!(Arc::ptr_eq(&context.container(), &ComponentContainer::global()));
+    /// ```
+    pub fn use_global_container(mut self) -> Self {
+        self.use_global_container = true;
+        self
+    }
+
     /// Builds the application context
     ///
     /// # Returns
@@ -204,12 +524,40 @@ impl ApplicationContextBuilder {
     ///     .unwrap();
     /// ```
     pub fn build(self) -> ContextResult<ApplicationContext> {
-        let context = ApplicationContext::new();
+        let context = if self.use_global_container {
+            ApplicationContext::with_container(ComponentContainer::global())
+        } else {
+            ApplicationContext::new()
+        };
 
-        // Add configuration sources
-        for source in self.config_sources {
+        // Add configuration sources, expanding each glob where it was added
+        let mut globs = self.config_globs.into_iter().peekable();
+        let add_matching_files = |pattern: &Path| -> ContextResult<()> {
+            for path in config_files_matching(pattern) {
+                let path = path.to_string_lossy().to_string();
+                context
+                    .config_manager
+                    .add_source(ConfigSource::ConfigFile(path))?;
+            }
+            Ok(())
+        };
+        for (index, source) in self.config_sources.into_iter().enumerate() {
+            while let Some((_, pattern)) = globs.next_if(|(position, _)| *position == index) {
+                add_matching_files(&pattern)?;
+            }
+            if self.required_sources.contains(&index) {
+                context.config_manager.check_source(&source)?;
+            }
             context.config_manager.add_source(source)?;
         }
+        for (_, pattern) in globs {
+            add_matching_files(&pattern)?;
+        }
+
+        // Environment variables override the sources above, but not explicit properties
+        if self.environment {
+            context.config_manager.add_source(ConfigSource::Environment)?;
+        }
 
         // Add properties as a configuration source
         if !self.properties.is_empty() {
@@ -254,11 +602,13 @@ pub struct ApplicationContext {
     /// Configuration manager
     config_manager: Arc<ConfigManager>,
     /// Event publisher for application-wide events
-    event_publisher: EventPublisher,
+    event_publisher: Arc<EventPublisher>,
     /// IoC container integration
     container: Arc<ComponentContainer>,
-    /// Application properties cache
-    properties_cache: DashMap<String, ConfigValue>,
+    /// Optional sink for framework log messages
+    logger: RwLock<Option<Logger>>,
+    /// Snapshot of the effective configuration, dropped whenever the configuration changes
+    config_snapshot: RwLock<Option<Arc<HashMap<String, ConfigValue>>>>,
 }
 
 impl ApplicationContext {
@@ -272,11 +622,62 @@ impl ApplicationContext {
     /// let context = ApplicationContext::new();
     /// ```
     pub fn new() -> Self {
+        Self::with_container(Arc::new(ComponentContainer::new()))
+    }
+
+    /// Creates a new application context that manages the given container
+    ///
+    /// Listeners registered with `context_event_listener!` are subscribed here.
+    fn with_container(container: Arc<ComponentContainer>) -> Self {
+        let event_publisher = EventPublisher::new();
+        for definition in inventory::iter::<ContextListenerDefinition> {
+            (definition.subscribe)(&event_publisher);
+        }
+
         Self {
             config_manager: Arc::new(ConfigManager::new()),
-            event_publisher: EventPublisher::new(),
-            container: Arc::new(ComponentContainer::new()),
-            properties_cache: DashMap::new(),
+            event_publisher: Arc::new(event_publisher),
+            container,
+            logger: RwLock::new(None),
+            config_snapshot: RwLock::new(None),
+        }
+    }
+
+    /// Sets the sink that receives framework log messages
+    ///
+    /// The logger is shared with the context's container and configuration manager, so it
+    /// receives their messages and the context's own: initialization milestones at `Info`,
+    /// configuration changes at `Debug`, and initialization failures at `Error`.
+    ///
+    /// # Arguments
+    ///
+    /// * `logger` - Callback receiving the level and message
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use verdure_context::ApplicationContext;
+    /// use verdure_core::logging::LogLevel;
+    ///
+    /// let context = ApplicationContext::new();
+    /// context.set_logger(|level: LogLevel, message: &str| {
+    ///     eprintln!("[{}] {}", level, message);
+    /// });
+    /// ```
+    pub fn set_logger<F>(&self, logger: F)
+    where
+        F: Fn(LogLevel, &str) + Send + Sync + 'static,
+    {
+        let logger: Logger = Arc::new(logger);
+        self.container.set_shared_logger(logger.clone());
+        self.config_manager.set_shared_logger(logger.clone());
+        *self.logger.write() = Some(logger);
+    }
+
+    /// Sends a message to the logger, building it only if a logger is set
+    fn log(&self, level: LogLevel, message: impl FnOnce() -> String) {
+        if let Some(logger) = self.logger.read().as_ref() {
+            logger(level, &message());
         }
     }
 
@@ -295,23 +696,27 @@ impl ApplicationContext {
     pub fn builder() -> ApplicationContextBuilder {
         ApplicationContextBuilder::new()
     }
+    // The config manager and configuration components are registered ahead of container
+    // initialization so that components can `#[autowired]` them while being created
     fn initialize_early(&self) -> ContextResult<()> {
         self.container.register_component(self.config_manager.clone());
+        self.container
+            .register_component(Arc::new(self.context_ref()));
 
-        for factory in inventory::iter::<ConfigFactory> {
-            let config_component = (factory.create_fn)(self.config_manager.clone())?;
-            self.container.register_component(config_component);
-        }
-        Ok(())
+        register_config_components(&self.container, &self.config_manager)
     }
     /// Initializes the application context
     ///
     /// This method initializes the IoC container and performs any other
-    /// necessary initialization steps.
+    /// necessary initialization steps. The configuration manager and every
+    /// `#[derive(Configuration)]` struct are registered before the container creates
+    /// components, so components can autowire them.
     ///
     /// # Returns
     ///
-    /// `Ok(())` if initialization succeeds, an error otherwise
+    /// `Ok(())` if initialization succeeds, an error otherwise. Container failures are
+    /// reported as [`ContextError::ContainerInitializationFailed`] carrying the original
+    /// `ContainerError`.
     ///
     /// # Examples
     ///
@@ -322,6 +727,25 @@ impl ApplicationContext {
     /// context.initialize().unwrap();
     /// ```
     pub fn initialize(&self) -> ContextResult<()> {
+        self.log(LogLevel::Info, || {
+            format!(
+                "Application context initialization started ({} config sources)",
+                self.config_manager.sources_count()
+            )
+        });
+        let result = self.initialize_context(std::time::Instant::now());
+        match &result {
+            Ok(()) => self.log(LogLevel::Info, || {
+                "Application context initialization completed".to_string()
+            }),
+            Err(e) => self.log(LogLevel::Error, || {
+                format!("Application context initialization failed: {}", e)
+            }),
+        }
+        result
+    }
+
+    fn initialize_context(&self, started: std::time::Instant) -> ContextResult<()> {
         self.initialize_early()?;
         // Publish context-initializing event at the start
         let initializing_event = ContextInitializingEvent {
@@ -332,17 +756,15 @@ impl ApplicationContext {
             .publish_with_context(&initializing_event, self);
 
         // Initialize the IoC container
-        self.container.initialize().map_err(|e| {
-            ContextError::initialization_failed(format!(
-                "IoC container initialization failed: {}",
-                e
-            ))
-        })?;
+        self.container
+            .initialize()
+            .map_err(ContextError::container_initialization_failed)?;
 
         // Publish context initialized event at the end
         let initialized_event = ContextInitializedEvent {
             config_sources_count: self.config_manager.sources_count(),
             timestamp: std::time::SystemTime::now(),
+            duration: started.elapsed(),
         };
         self.event_publisher
             .publish_with_context(&initialized_event, self);
@@ -415,15 +837,23 @@ impl ApplicationContext {
         let value = self.config_manager.get_string(key)?;
         value
             .parse::<T>()
-            .map_err(|e| ContextError::invalid_configuration(key, e.to_string()))
+            .map_err(|e| self.config_manager.invalid_configuration(key, e.to_string()))
     }
 
-    /// Gets a configuration value with a default
+    /// Gets an optional configuration value as a specific type
+    ///
+    /// Unlike [`get_config_as`](Self::get_config_as), a missing key is not an error,
+    /// which allows optional settings that are still validated when present.
     ///
     /// # Arguments
     ///
     /// * `key` - The configuration key
-    /// * `default` - The default value to return if key is not found
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(None)` if the key is absent
+    /// * `Ok(Some(value))` if the key is present and parses as `T`
+    /// * `Err(InvalidConfiguration)` if the key is present but cannot be parsed
     ///
     /// # Examples
     ///
@@ -431,54 +861,370 @@ impl ApplicationContext {
     /// use verdure_context::ApplicationContext;
     ///
     /// let context = ApplicationContext::new();
-    /// let port = context.get_config_or_default("app.port", "8080");
-    /// assert_eq!(port, "8080");
+    /// context.set_config("app.port", "8080");
+    /// context.set_config("app.timeout", "soon");
+    ///
+    /// assert_eq!(context.get_config_opt::<u16>("app.port").unwrap(), Some(8080));
+    /// assert_eq!(context.get_config_opt::<u16>("app.missing").unwrap(), None);
+    /// assert!(context.get_config_opt::<u64>("app.timeout").is_err());
     /// ```
-    pub fn get_config_or_default(&self, key: &str, default: &str) -> String {
-        self.config_manager.get_string_or_default(key, default)
+    pub fn get_config_opt<T>(&self, key: &str) -> ContextResult<Option<T>>
+    where
+        T: std::str::FromStr,
+        T::Err: std::fmt::Display,
+    {
+        let value = match self.config_manager.get(key) {
+            Some(value) => value,
+            None => return Ok(None),
+        };
+        let value = value
+            .as_string()
+            .ok_or_else(|| self.config_manager.invalid_configuration(key, "expected a scalar value"))?;
+        value
+            .parse::<T>()
+            .map(Some)
+            .map_err(|e| self.config_manager.invalid_configuration(key, e.to_string()))
     }
 
-    /// Sets a configuration property
+    /// Gets a configuration value by deserializing it as JSON
+    ///
+    /// This reaches types [`get_config_as`](Self::get_config_as) cannot parse, such as
+    /// lists, maps or structs stored in a single key. Use
+    /// [`ConfigManager::bind`](crate::ConfigManager::bind) to build a type from all keys
+    /// under a prefix instead.
     ///
     /// # Arguments
     ///
-    /// * `key` - The configuration key
-    /// * `value` - The configuration value
+    /// * `key` - The configuration key holding a JSON document
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the key is not found or its value is not valid JSON for `T`
     ///
     /// # Examples
     ///
     /// ```rust
     /// use verdure_context::ApplicationContext;
     ///
-    /// let mut context = ApplicationContext::new();
-    /// context.set_config("runtime.property", "runtime.value");
+    /// let context = ApplicationContext::new();
+    /// context.set_config("app.ports", "[8080, 8443]");
     ///
-    /// assert_eq!(context.get_config("runtime.property"), "runtime.value");
+    /// let ports: Vec<u16> = context.get_config_json("app.ports").unwrap();
+    /// assert_eq!(ports, vec![8080, 8443]);
     /// ```
-    /// Sets a configuration property
-    pub fn set_config(&self, key: &str, value: &str) {
-        let old_value = self.get_config(key);
-        let old_value_opt = if old_value.is_empty() {
-            None
-        } else {
-            Some(old_value)
-        };
-
-        self.config_manager
-            .set(key, ConfigValue::String(value.to_string()));
-
-        let event = ConfigurationChangedEvent {
-            key: key.to_string(),
-            old_value: old_value_opt,
-            new_value: value.to_string(),
-            timestamp: std::time::SystemTime::now(),
-        };
-        self.event_publisher.publish(&event);
+    pub fn get_config_json<T: DeserializeOwned>(&self, key: &str) -> ContextResult<T> {
+        let value = self.config_manager.get_string(key)?;
+        serde_json::from_str(&value)
+            .map_err(|e| self.config_manager.invalid_configuration(key, e.to_string()))
     }
 
-    /// Adds a configuration source
+    /// Returns whether a configuration key is set
+    ///
+    /// Unlike checking `get_config(key)` for an empty string, a key set to `""`
+    /// counts as present. See [`ConfigManager::has_key`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use verdure_context::ApplicationContext;
+    ///
+    /// let context = ApplicationContext::new();
+    /// context.set_config("app.suffix", "");
+    ///
+    /// assert!(context.has_config("app.suffix"));
+    /// assert!(!context.has_config("app.missing"));
+    /// ```
+    pub fn has_config(&self, key: &str) -> bool {
+        self.config_manager.has_key(key)
+    }
+
+    /// Gets all configuration values under `prefix`, keyed without the prefix
+    ///
+    /// A simple string view of a configuration section, e.g. to hand it to a
+    /// component. See [`ConfigManager::get_map`].
+    ///
+    /// # Arguments
+    ///
+    /// * `prefix` - The key prefix, e.g. `"database"`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use verdure_context::ApplicationContext;
+    ///
+    /// let context = ApplicationContext::builder()
+    ///     .with_property("database.host", "localhost")
+    ///     .with_property("database.port", "5432")
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let database = context.get_config_map("database");
+    /// assert_eq!(database["host"], "localhost");
+    /// ```
+    pub fn get_config_map(&self, prefix: &str) -> HashMap<String, String> {
+        self.config_manager.get_map(prefix)
+    }
+
+    /// Gets a configuration value with a default
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The configuration key
+    /// * `default` - The default value to return if key is not found
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use verdure_context::ApplicationContext;
+    ///
+    /// let context = ApplicationContext::new();
+    /// let port = context.get_config_or_default("app.port", "8080");
+    /// assert_eq!(port, "8080");
+    /// ```
+    pub fn get_config_or_default(&self, key: &str, default: &str) -> String {
+        self.config_manager.get_string_or_default(key, default)
+    }
+
+    /// Sets a configuration property
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The configuration key
+    /// * `value` - The configuration value
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use verdure_context::ApplicationContext;
+    ///
+    /// let mut context = ApplicationContext::new();
+    /// context.set_config("runtime.property", "runtime.value");
+    ///
+    /// assert_eq!(context.get_config("runtime.property"), "runtime.value");
+    /// ```
+    /// Sets a configuration property
+    ///
+    /// A `ConfigurationChangedEvent` is published only if the value actually changes.
+    pub fn set_config(&self, key: &str, value: &str) {
+        let new_config_value = ConfigValue::String(value.to_string());
+        if self.config_manager.get(key).as_ref() == Some(&new_config_value) {
+            return;
+        }
+
+        let old_value = self.get_config(key);
+        let old_value_opt = if old_value.is_empty() {
+            None
+        } else {
+            Some(old_value)
+        };
+
+        self.config_manager.set(key, new_config_value);
+        self.invalidate_config_snapshot();
+        self.log(LogLevel::Debug, || {
+            format!("Configuration '{}' changed", key)
+        });
+
+        let event = ConfigurationChangedEvent {
+            key: key.to_string(),
+            old_value: old_value_opt,
+            new_value: value.to_string(),
+            timestamp: std::time::SystemTime::now(),
+        };
+        self.event_publisher.publish(&event);
+    }
+
+    /// Adds a configuration source
     pub fn add_config_source(&self, source: ConfigSource) -> ContextResult<()> {
-        self.config_manager.add_source(source)
+        self.config_manager.add_source(source)?;
+        self.invalidate_config_snapshot();
+        Ok(())
+    }
+
+    /// Adds a configuration source with an explicit priority
+    ///
+    /// See [`ConfigManager::add_source_with_priority`] for how priorities are ordered.
+    pub fn add_config_source_with_priority(
+        &self,
+        source: ConfigSource,
+        priority: i32,
+    ) -> ContextResult<()> {
+        self.config_manager.add_source_with_priority(source, priority)?;
+        self.invalidate_config_snapshot();
+        Ok(())
+    }
+
+    /// Returns an immutable snapshot of the effective configuration
+    ///
+    /// The snapshot is built from [`ConfigManager::all_entries`] and shared between
+    /// callers until the configuration changes through this context (a
+    /// `ConfigurationChangedEvent` or a new source), so a caller holding it reads
+    /// consistent values across keys even while the live configuration is updated.
+    /// Changes made directly on the [`ConfigManager`] are not tracked.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use verdure_context::ApplicationContext;
+    ///
+    /// let context = ApplicationContext::builder()
+    ///     .with_property("app.name", "MyApp")
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let snapshot = context.config_snapshot();
+    /// context.set_config("app.name", "Renamed");
+    ///
+    /// assert_eq!(snapshot["app.name"].as_string().unwrap(), "MyApp");
+    /// assert_eq!(context.config_snapshot()["app.name"].as_string().unwrap(), "Renamed");
+    /// ```
+    pub fn config_snapshot(&self) -> Arc<HashMap<String, ConfigValue>> {
+        if let Some(snapshot) = self.config_snapshot.read().as_ref() {
+            return snapshot.clone();
+        }
+
+        // Build under the write lock so a concurrent change cannot be overwritten by
+        // a snapshot taken before it
+        let mut guard = self.config_snapshot.write();
+        guard
+            .get_or_insert_with(|| Arc::new(self.config_manager.all_entries()))
+            .clone()
+    }
+
+    fn invalidate_config_snapshot(&self) {
+        *self.config_snapshot.write() = None;
+    }
+
+    /// Reloads the configuration and publishes a single [`ContextRefreshedEvent`]
+    ///
+    /// Cached values are dropped so that file and remote sources are read again. The
+    /// reloaded configuration is compared with the current
+    /// [`config_snapshot`](Self::config_snapshot), which then becomes the new snapshot,
+    /// and the event carrying every added, removed or changed key is published to
+    /// regular and context-aware listeners. The event is published even if nothing
+    /// changed.
+    ///
+    /// Sources are read lazily, so a change is only detected against a snapshot
+    /// taken before it; a snapshot is taken here if none exists yet.
+    ///
+    /// # Returns
+    ///
+    /// The changed keys, sorted
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use verdure_context::ApplicationContext;
+    ///
+    /// let context = ApplicationContext::builder()
+    ///     .with_property("app.name", "MyApp")
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert!(context.refresh().is_empty());
+    /// ```
+    pub fn refresh(&self) -> Vec<String> {
+        let old_entries = self.config_snapshot();
+        self.config_manager.invalidate_cache();
+        let new_entries = Arc::new(self.config_manager.all_entries());
+        *self.config_snapshot.write() = Some(new_entries.clone());
+
+        let mut changed_keys: Vec<String> = old_entries
+            .keys()
+            .chain(new_entries.keys().filter(|key| !old_entries.contains_key(*key)))
+            .filter(|key| old_entries.get(*key) != new_entries.get(*key))
+            .cloned()
+            .collect();
+        changed_keys.sort();
+
+        self.log(LogLevel::Info, || {
+            format!("Configuration refreshed ({} keys changed)", changed_keys.len())
+        });
+
+        let event = ContextRefreshedEvent {
+            changed_keys: changed_keys.clone(),
+            timestamp: std::time::SystemTime::now(),
+        };
+        self.event_publisher.publish_with_context(&event, self);
+        changed_keys
+    }
+
+    /// Reads one configuration file again and publishes its changes
+    ///
+    /// A lighter alternative to [`refresh`](Self::refresh), e.g. for a reload triggered
+    /// by an operator: only `path` is parsed again, and a [`ConfigurationChangedEvent`]
+    /// is published for every key whose effective value changed. A key that is no
+    /// longer set is reported with an empty `new_value`. If the file cannot be read or
+    /// parsed, the previous configuration stays in effect.
+    ///
+    /// Files are read lazily, so changes made before the file was first read are not
+    /// detected.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path the file source was registered with
+    ///
+    /// # Returns
+    ///
+    /// The changed keys, sorted
+    ///
+    /// # Errors
+    ///
+    /// Returns `ContextError::ConfigurationFileError` if no file source with this path
+    /// is registered, or the file cannot be read or parsed
+    pub fn reload_config_file(&self, path: &str) -> ContextResult<Vec<String>> {
+        let old_entries = self.config_snapshot();
+        let keys = self.config_manager.reload_file(path)?;
+        self.invalidate_config_snapshot();
+
+        let changed_keys: Vec<String> = keys
+            .into_iter()
+            .filter(|key| old_entries.get(key) != self.config_manager.get(key).as_ref())
+            .collect();
+
+        self.log(LogLevel::Info, || {
+            format!(
+                "Configuration file '{}' reloaded ({} keys changed)",
+                path,
+                changed_keys.len()
+            )
+        });
+
+        for key in &changed_keys {
+            let event = ConfigurationChangedEvent {
+                key: key.clone(),
+                old_value: old_entries.get(key).and_then(ConfigValue::as_string),
+                new_value: self.config_manager.get_string(key).unwrap_or_default(),
+                timestamp: std::time::SystemTime::now(),
+            };
+            self.event_publisher.publish(&event);
+        }
+        Ok(changed_keys)
+    }
+
+    /// Creates a weak handle to this context
+    ///
+    /// The same kind of handle is registered in the container during
+    /// [`initialize`](Self::initialize), so components can autowire it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use verdure_context::ApplicationContext;
+    ///
+    /// let context = ApplicationContext::builder()
+    ///     .with_property("app.name", "MyApp")
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let handle = context.context_ref();
+    /// assert_eq!(handle.get_config("app.name"), Some("MyApp".to_string()));
+    /// ```
+    pub fn context_ref(&self) -> ApplicationContextRef {
+        ApplicationContextRef {
+            config_manager: Arc::downgrade(&self.config_manager),
+            event_publisher: Arc::downgrade(&self.event_publisher),
+            container: Arc::downgrade(&self.container),
+        }
     }
 
     /// Gets the IoC container
@@ -498,6 +1244,22 @@ impl ApplicationContext {
     pub fn container(&self) -> Arc<ComponentContainer> {
         self.container.clone()
     }
+
+    /// Gets the number of component instances held by the context's container
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use verdure_context::ApplicationContext;
+    ///
+    /// let context = ApplicationContext::new();
+    /// context.initialize().unwrap();
+    /// // The config manager and the context reference are always registered
+    /// assert!(context.component_count() >= 2);
+    /// ```
+    pub fn component_count(&self) -> usize {
+        self.container.component_count()
+    }
     
     /// Gets a shared reference to the ConfigManager for IoC registration
     pub fn config_manager(&self) -> Arc<ConfigManager> {
@@ -536,6 +1298,85 @@ impl ApplicationContext {
     pub fn get_component<T: 'static + Send + Sync>(&self) -> Option<Arc<T>> {
         self.container.get_component()
     }
+
+    /// Gets a component, returning an error that names the type if it is missing
+    ///
+    /// # Errors
+    ///
+    /// Returns `ContextError::ComponentNotFound` with `std::any::type_name::<T>()`
+    /// if no component of that type is registered
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use verdure_context::{ApplicationContext, ContextError};
+    /// use std::sync::Arc;
+    ///
+    /// struct UserService;
+    ///
+    /// let context = ApplicationContext::new();
+    /// assert!(matches!(
+    ///     context.require_component::<UserService>(),
+    ///     Err(ContextError::ComponentNotFound { .. })
+    /// ));
+    ///
+    /// context.register_component(Arc::new(UserService));
+    /// assert!(context.require_component::<UserService>().is_ok());
+    /// ```
+    pub fn require_component<T: 'static + Send + Sync>(&self) -> ContextResult<Arc<T>> {
+        self.get_component()
+            .ok_or_else(|| ContextError::component_not_found(std::any::type_name::<T>()))
+    }
+
+    /// Gets a component by its concrete type and returns it as a trait object
+    ///
+    /// The component is looked up as with [`require_component`](Self::require_component)
+    /// and then passed to `cast`, which is usually just `|c| c` to let the compiler
+    /// coerce `Arc<C>` into `Arc<dyn Trait>`.
+    ///
+    /// # Arguments
+    ///
+    /// * `cast` - Converts the concrete component into the trait object
+    ///
+    /// # Errors
+    ///
+    /// Returns `ContextError::ComponentNotFound` naming `C` if no such component is
+    /// registered
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use verdure_context::ApplicationContext;
+    /// use std::sync::Arc;
+    ///
+    /// trait Greeter: Send + Sync {
+    ///     fn greet(&self) -> String;
+    /// }
+    ///
+    /// struct EnglishGreeter;
+    ///
+    /// impl Greeter for EnglishGreeter {
+    ///     fn greet(&self) -> String {
+    ///         "Hello".to_string()
+    ///     }
+    /// }
+    ///
+    /// let context = ApplicationContext::new();
+    /// context.register_component(Arc::new(EnglishGreeter));
+    ///
+    /// let greeter: Arc<dyn Greeter> = context
+    ///     .get_component_as::<EnglishGreeter, dyn Greeter>(|greeter| greeter)
+    ///     .unwrap();
+    /// assert_eq!(greeter.greet(), "Hello");
+    /// ```
+    pub fn get_component_as<C, T>(&self, cast: fn(Arc<C>) -> Arc<T>) -> ContextResult<Arc<T>>
+    where
+        C: 'static + Send + Sync,
+        T: ?Sized,
+    {
+        self.require_component::<C>().map(cast)
+    }
+
     /// Registers a pre-created component instance with the context container
     pub fn register_component(&self, instance: ComponentInstance) {
         self.container.register_component(instance)
@@ -543,10 +1384,18 @@ impl ApplicationContext {
 
     /// Publishes an event
     ///
+    /// Only regular listeners are notified; use
+    /// [`publish_event_with_context`](Self::publish_event_with_context) to also reach
+    /// context-aware listeners.
+    ///
     /// # Arguments
     ///
     /// * `event` - The event to publish
     ///
+    /// # Returns
+    ///
+    /// The number of listeners that handled the event
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -569,10 +1418,58 @@ impl ApplicationContext {
     ///     message: "Hello, World!".to_string(),
     /// };
     ///
-    /// context.publish_event(&event);
+    /// // Nobody is listening yet
+    /// assert_eq!(context.publish_event(&event), 0);
     /// ```
-    pub fn publish_event<T: Event + 'static>(&self, event: &T) {
-        self.event_publisher.publish(event);
+    pub fn publish_event<T: Event + 'static>(&self, event: &T) -> usize {
+        self.event_publisher.publish(event)
+    }
+
+    /// Publishes an event to both regular and context-aware listeners
+    ///
+    /// Context-aware listeners registered with
+    /// [`subscribe_to_context_events`](Self::subscribe_to_context_events) receive a
+    /// reference to this context along with the event.
+    ///
+    /// # Arguments
+    ///
+    /// * `event` - The event to publish
+    ///
+    /// # Returns
+    ///
+    /// The number of listeners (regular and context-aware) that handled the event
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use verdure_context::{ApplicationContext, ContextAwareEventListener, Event};
+    /// use std::any::Any;
+    ///
+    /// #[derive(Debug, Clone)]
+    /// struct OrderPlaced;
+    ///
+    /// impl Event for OrderPlaced {
+    ///     fn name(&self) -> &'static str { "OrderPlaced" }
+    ///     fn as_any(&self) -> &dyn Any { self }
+    ///     fn into_any(self: Box<Self>) -> Box<dyn Any> { self }
+    /// }
+    ///
+    /// struct OrderAudit;
+    ///
+    /// impl ContextAwareEventListener<OrderPlaced> for OrderAudit {
+    ///     fn on_context_event(&self, _event: &OrderPlaced, context: &ApplicationContext) {
+    ///         println!("order placed in {}", context.environment());
+    ///     }
+    /// }
+    ///
+    /// let context = ApplicationContext::new();
+    /// context.subscribe_to_context_events(OrderAudit);
+    ///
+    /// assert_eq!(context.publish_event(&OrderPlaced), 0);
+    /// assert_eq!(context.publish_event_with_context(&OrderPlaced), 1);
+    /// ```
+    pub fn publish_event_with_context<T: Event + 'static>(&self, event: &T) -> usize {
+        self.event_publisher.publish_with_context(event, self)
     }
 
     /// Subscribes to events with context access
@@ -650,8 +1547,77 @@ impl ApplicationContext {
         self.event_publisher.subscribe(listener);
     }
 
+    /// Subscribes a listener to every event published through this context
+    ///
+    /// The listener receives each event as `&dyn Event`, including the context
+    /// lifecycle events, regardless of its concrete type.
+    ///
+    /// # Arguments
+    ///
+    /// * `listener` - The closure invoked for each published event
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use verdure_context::{ApplicationContext, Event};
+    ///
+    /// let context = ApplicationContext::new();
+    /// context.subscribe_to_all_events(|event: &dyn Event| {
+    ///     println!("[audit] {}", event.name());
+    /// });
+    /// ```
+    pub fn subscribe_to_all_events<F>(&self, listener: F)
+    where
+        F: Fn(&dyn Event) + Send + Sync + 'static,
+    {
+        self.event_publisher.subscribe_all(listener);
+    }
+
+    /// Registers a callback invoked when a configuration key matching `pattern` changes
+    ///
+    /// The pattern is matched against the whole key, with `*` standing for any sequence
+    /// of characters and `?` for one character, so `"feature.*"` matches every key below
+    /// `feature`. The callback receives the key, its previous value (if any) and its new
+    /// value for each `ConfigurationChangedEvent`, e.g. from
+    /// [`set_config`](Self::set_config).
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - The key, or key pattern, to watch
+    /// * `callback` - The closure invoked with `(key, old_value, new_value)`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use verdure_context::ApplicationContext;
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// let context = ApplicationContext::new();
+    /// let toggled = Arc::new(Mutex::new(Vec::new()));
+    /// let sink = toggled.clone();
+    /// context.on_config_change("feature.*", move |key, _old, new| {
+    ///     sink.lock().unwrap().push(format!("{}={}", key, new));
+    /// });
+    ///
+    /// context.set_config("feature.search", "true");
+    /// context.set_config("server.port", "8080");
+    /// assert_eq!(*toggled.lock().unwrap(), vec!["feature.search=true"]);
+    /// ```
+    pub fn on_config_change<F>(&self, pattern: impl Into<String>, callback: F)
+    where
+        F: Fn(&str, Option<&str>, &str) + Send + Sync + 'static,
+    {
+        self.event_publisher
+            .subscribe(ConfigKeyListener::new(pattern, callback));
+    }
+
     /// Gets environment information
     ///
+    /// The environment is read from the `verdure.environment` configuration key, so it
+    /// can be set like any other value, e.g. in a configuration file, with
+    /// `with_property` or through the `VERDURE_ENVIRONMENT` variable when environment
+    /// variables are a source. It is `"default"` if the key is not set.
+    ///
     /// # Returns
     ///
     /// A string representing the current environment
@@ -661,11 +1627,123 @@ impl ApplicationContext {
     /// ```rust
     /// use verdure_context::ApplicationContext;
     ///
-    /// let context = ApplicationContext::new();
-    /// println!("Environment: {}", context.environment());
+    /// let context = ApplicationContext::builder()
+    ///     .with_property("verdure.environment", "prod")
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(context.environment(), "prod");
+    /// assert!(context.is_production());
     /// ```
     pub fn environment(&self) -> String {
-        "default".to_string()
+        self.config_manager
+            .get_string(ENVIRONMENT_KEY)
+            .unwrap_or_else(|_| "default".to_string())
+    }
+
+    /// Gets the current environment classified as an [`Environment`]
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use verdure_context::{ApplicationContext, Environment};
+    ///
+    /// let context = ApplicationContext::new();
+    /// assert_eq!(
+    ///     context.environment_type(),
+    ///     Environment::Other("default".to_string())
+    /// );
+    /// ```
+    pub fn environment_type(&self) -> Environment {
+        Environment::from_name(&self.environment())
+    }
+
+    /// Returns `true` if the current environment is production (`prod` / `production`)
+    pub fn is_production(&self) -> bool {
+        self.environment_type().is_production()
+    }
+
+    /// Returns `true` if the current environment is development (`dev` / `development`)
+    pub fn is_development(&self) -> bool {
+        self.environment_type().is_development()
+    }
+
+    /// Returns `true` if the current environment is test (`test`)
+    pub fn is_test(&self) -> bool {
+        self.environment_type().is_test()
+    }
+}
+
+/// Weak handle to an `ApplicationContext`
+///
+/// `ApplicationContextRef` is registered as a component when the context is
+/// initialized, so any component can declare `#[autowired] ctx: Arc<ApplicationContextRef>`
+/// to read configuration, look up components, or publish events without being a
+/// context-aware listener.
+///
+/// The handle holds only weak references, so storing it inside components does not
+/// create a reference cycle with the container. Once the context is dropped, its
+/// methods return `None` or `false`.
+///
+/// # Examples
+///
+/// ```rust
+/// use verdure_context::{ApplicationContext, ApplicationContextRef};
+/// use std::sync::Arc;
+///
+/// let context = ApplicationContext::builder()
+///     .with_property("app.name", "MyApp")
+///     .build()
+///     .unwrap();
+/// context.initialize().unwrap();
+///
+/// let handle: Arc<ApplicationContextRef> = context.get_component().unwrap();
+/// assert_eq!(handle.get_config("app.name"), Some("MyApp".to_string()));
+/// ```
+#[derive(Clone)]
+pub struct ApplicationContextRef {
+    config_manager: Weak<ConfigManager>,
+    event_publisher: Weak<EventPublisher>,
+    container: Weak<ComponentContainer>,
+}
+
+impl ApplicationContextRef {
+    /// Returns `true` while the referenced context is still alive
+    pub fn is_alive(&self) -> bool {
+        self.event_publisher.strong_count() > 0
+    }
+
+    /// Gets the configuration manager of the context
+    pub fn config_manager(&self) -> Option<Arc<ConfigManager>> {
+        self.config_manager.upgrade()
+    }
+
+    /// Gets a configuration value as a string
+    ///
+    /// # Returns
+    ///
+    /// `None` if the key is not set or the context has been dropped
+    pub fn get_config(&self, key: &str) -> Option<String> {
+        self.config_manager()?.get_string(key).ok()
+    }
+
+    /// Gets a component from the context container
+    pub fn get_component<T: 'static + Send + Sync>(&self) -> Option<Arc<T>> {
+        self.container.upgrade()?.get_component()
+    }
+
+    /// Publishes an event to the context's event listeners
+    ///
+    /// # Returns
+    ///
+    /// `false` if the context has been dropped and the event was not published
+    pub fn publish_event<T: Event + 'static>(&self, event: &T) -> bool {
+        match self.event_publisher.upgrade() {
+            Some(publisher) => {
+                publisher.publish(event);
+                true
+            }
+            None => false,
+        }
     }
 }
 
@@ -675,9 +1753,47 @@ impl Default for ApplicationContext {
     }
 }
 
+/// Lists the files matching a pattern with wildcards in its file name, sorted by name
+fn config_files_matching(pattern: &Path) -> Vec<PathBuf> {
+    let Some(name_pattern) = pattern.file_name().and_then(|name| name.to_str()) else {
+        return Vec::new();
+    };
+    let dir = match pattern.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => Path::new("."),
+    };
+
+    let mut files: Vec<PathBuf> = std::fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.is_file()
+                && path
+                    .file_name()
+                    .and_then(|name| name.to_str())
+                    .is_some_and(|name| matches_file_pattern(name_pattern, name))
+        })
+        .collect();
+    files.sort();
+    files
+}
+
+/// Matches a file name against a wildcard pattern, see [`wildcard::matches`]
+///
+/// Hidden files only match a pattern that starts with `.`.
+fn matches_file_pattern(pattern: &str, name: &str) -> bool {
+    if name.starts_with('.') && !pattern.starts_with('.') {
+        return false;
+    }
+    wildcard::matches(pattern, name)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_env::EnvVars;
     use std::collections::HashMap;
 
     #[test]
@@ -685,6 +1801,27 @@ mod tests {
         let context = ApplicationContext::new();
         // Context created successfully
         assert_eq!(context.environment(), "default");
+        assert_eq!(
+            context.environment_type(),
+            Environment::Other("default".to_string())
+        );
+        assert!(!context.is_production());
+        assert!(!context.is_development());
+        assert!(!context.is_test());
+    }
+
+    #[test]
+    fn test_environment_from_configuration() {
+        let context = ApplicationContext::builder()
+            .with_property("verdure.environment", "local,dev")
+            .build()
+            .unwrap();
+        assert_eq!(context.environment(), "local,dev");
+        assert!(context.is_development());
+
+        context.set_config("verdure.environment", "test");
+        assert!(context.is_test());
+        assert!(!context.is_development());
     }
 
     #[test]
@@ -697,6 +1834,63 @@ mod tests {
         assert_eq!(context.get_config("app.name"), "TestApp");
     }
 
+    #[test]
+    fn test_builder_with_properties() {
+        let mut defaults = HashMap::new();
+        defaults.insert("app.name".to_string(), "Default".to_string());
+        defaults.insert("app.port".to_string(), "8080".to_string());
+
+        let context = ApplicationContext::builder()
+            .with_properties(defaults)
+            .with_properties([("app.name", "Override")])
+            .with_property_if(true, "feature.enabled", "true")
+            .with_property_if(false, "feature.disabled", "true")
+            .build()
+            .unwrap();
+
+        assert_eq!(context.get_config("app.name"), "Override");
+        assert_eq!(context.get_config("app.port"), "8080");
+        assert_eq!(context.get_config("feature.enabled"), "true");
+        assert_eq!(context.get_config("feature.disabled"), "");
+    }
+
+    #[test]
+    fn test_builder_required_config_file() {
+        let missing = std::env::temp_dir().join(format!(
+            "verdure_missing_{}.yml",
+            std::process::id()
+        ));
+
+        // Best-effort files may be missing
+        assert!(ApplicationContext::builder()
+            .with_config_file(&missing)
+            .build()
+            .is_ok());
+
+        match ApplicationContext::builder()
+            .with_config_file_required(&missing)
+            .build()
+        {
+            Err(ContextError::ConfigurationFileError { path, source, .. }) => {
+                assert_eq!(path, Some(missing.to_string_lossy().to_string()));
+                assert!(source.is_some());
+            }
+            other => panic!("expected ConfigurationFileError, got {:?}", other.err()),
+        }
+
+        let present = std::env::temp_dir().join(format!(
+            "verdure_required_{}.yml",
+            std::process::id()
+        ));
+        std::fs::write(&present, "app:\n  name: Required\n").unwrap();
+        let context = ApplicationContext::builder()
+            .with_config_file_required(&present)
+            .build()
+            .unwrap();
+        assert_eq!(context.get_config("app.name"), "Required");
+        std::fs::remove_file(&present).unwrap();
+    }
+
     #[test]
     fn test_configuration_management() {
         let context = ApplicationContext::new();
@@ -722,7 +1916,7 @@ mod tests {
         assert_eq!(port, 3000);
 
         let debug: bool = context.get_config_as("debug.enabled").unwrap();
-        assert_eq!(debug, true);
+        assert!(debug);
     }
 
     #[test]
@@ -736,6 +1930,76 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_get_config_opt() {
+        let context = ApplicationContext::new();
+        context.set_config("server.port", "3000");
+        context.set_config("server.timeout", "forever");
+        context.set_config("server.name", "");
+
+        assert_eq!(context.get_config_opt::<u16>("server.port").unwrap(), Some(3000));
+        assert_eq!(context.get_config_opt::<u16>("server.missing").unwrap(), None);
+        assert_eq!(
+            context.get_config_opt::<String>("server.name").unwrap(),
+            Some(String::new())
+        );
+
+        let err = context.get_config_opt::<u64>("server.timeout").unwrap_err();
+        assert!(matches!(err, ContextError::InvalidConfiguration { .. }));
+    }
+
+    #[test]
+    fn test_get_config_map_across_sources() {
+        let context = ApplicationContextBuilder::new()
+            .with_inline_yaml(
+                "database:\n  host: file-host\n  port: 5432\n  pool:\n    size: 4\n\
+                 databases:\n  other: ignored\n",
+            )
+            .with_property("database.host", "property-host")
+            .build()
+            .unwrap();
+        context.set_config("database.pool.size", "16");
+
+        let database = context.get_config_map("database");
+        assert_eq!(database.len(), 3);
+        assert_eq!(database["host"], "property-host");
+        assert_eq!(database["port"], "5432");
+        assert_eq!(database["pool.size"], "16");
+        assert!(context.get_config_map("missing").is_empty());
+    }
+
+    #[test]
+    fn test_get_config_json() {
+        #[derive(Debug, PartialEq, serde::Deserialize)]
+        struct Upstream {
+            host: String,
+            port: u16,
+            tags: Vec<String>,
+        }
+
+        let context = ApplicationContext::new();
+        context.set_config(
+            "proxy.upstream",
+            r#"{"host": "backend.local", "port": 9000, "tags": ["primary"]}"#,
+        );
+        context.set_config("proxy.broken", "{\"host\": ");
+
+        let upstream: Upstream = context.get_config_json("proxy.upstream").unwrap();
+        assert_eq!(
+            upstream,
+            Upstream {
+                host: "backend.local".to_string(),
+                port: 9000,
+                tags: vec!["primary".to_string()],
+            }
+        );
+
+        let err = context.get_config_json::<Upstream>("proxy.broken").unwrap_err();
+        assert!(matches!(err, ContextError::InvalidConfiguration { .. }));
+        let err = context.get_config_json::<Upstream>("proxy.missing").unwrap_err();
+        assert!(matches!(err, ContextError::ConfigurationNotFound { .. }));
+    }
+
     #[test]
     fn test_runtime_configuration() {
         let context = ApplicationContext::new();
@@ -751,6 +2015,91 @@ mod tests {
         assert_eq!(context.environment(), "default");
     }
 
+    #[test]
+    fn test_context_ref_component() {
+        use crate::event::ConfigurationChangedEvent;
+        use std::sync::Mutex;
+
+        struct KeyListener(Arc<Mutex<Vec<String>>>);
+        impl EventListener<ConfigurationChangedEvent> for KeyListener {
+            fn on_event(&self, event: &ConfigurationChangedEvent) {
+                self.0.lock().unwrap().push(event.key.clone());
+            }
+        }
+
+        let context = ApplicationContext::builder()
+            .with_property("app.name", "RefApp")
+            .build()
+            .unwrap();
+        let keys = Arc::new(Mutex::new(Vec::new()));
+        context.subscribe_to_events(KeyListener(keys.clone()));
+        context.initialize().unwrap();
+
+        let handle: Arc<ApplicationContextRef> = context.get_component().unwrap();
+        assert!(handle.is_alive());
+        assert_eq!(handle.get_config("app.name"), Some("RefApp".to_string()));
+        assert!(handle.get_component::<ConfigManager>().is_some());
+
+        let event = ConfigurationChangedEvent {
+            key: "from.ref".to_string(),
+            old_value: None,
+            new_value: "value".to_string(),
+            timestamp: std::time::SystemTime::now(),
+        };
+        assert!(handle.publish_event(&event));
+        assert_eq!(*keys.lock().unwrap(), vec!["from.ref".to_string()]);
+
+        let handle = (*handle).clone();
+        drop(context);
+        assert!(!handle.is_alive());
+        assert_eq!(handle.get_config("app.name"), None);
+        assert!(!handle.publish_event(&event));
+    }
+
+    #[test]
+    fn test_logger() {
+        use std::sync::Mutex;
+
+        let messages = Arc::new(Mutex::new(Vec::new()));
+        let sink = messages.clone();
+
+        let context = ApplicationContext::new();
+        context.set_logger(move |level, message| {
+            sink.lock().unwrap().push((level, message.to_string()));
+        });
+        context.initialize().unwrap();
+        context.set_config("logged.key", "value");
+
+        let messages = messages.lock().unwrap();
+        let has = |level: LogLevel, prefix: &str| {
+            messages
+                .iter()
+                .any(|(l, m)| *l == level && m.starts_with(prefix))
+        };
+        assert!(has(LogLevel::Info, "Application context initialization started"));
+        assert!(has(LogLevel::Info, "Container initialization completed"));
+        assert!(has(LogLevel::Info, "Application context initialization completed"));
+        assert!(has(LogLevel::Debug, "Configuration 'logged.key' changed"));
+    }
+
+    #[test]
+    fn test_global_container() {
+        let context = ApplicationContext::builder()
+            .use_global_container()
+            .build()
+            .unwrap();
+        assert!(Arc::ptr_eq(
+            &context.container(),
+            &ComponentContainer::global()
+        ));
+
+        let private = ApplicationContext::new();
+        assert!(!Arc::ptr_eq(
+            &private.container(),
+            &ComponentContainer::global()
+        ));
+    }
+
     #[test]
     fn test_container_integration() {
         let context = ApplicationContext::new();
@@ -760,6 +2109,16 @@ mod tests {
         assert!(container.get_component::<String>().is_none());
     }
 
+    #[test]
+    fn test_component_count() {
+        let context = ApplicationContext::new();
+        assert_eq!(context.component_count(), 0);
+
+        context.register_component(Arc::new("value".to_string()));
+        assert_eq!(context.component_count(), 1);
+        assert_eq!(context.component_count(), context.container().component_count());
+    }
+
     // Test event system integration
     use std::any::Any;
 
@@ -869,6 +2228,10 @@ mod tests {
         context.set_config("runtime.key", "runtime.value");
         context.set_config("initial.key", "updated.value");
 
+        // Setting unchanged values should not fire any events
+        context.set_config("runtime.key", "runtime.value");
+        context.set_config("initial.key", "updated.value");
+
         // Verify initializing events were fired
         let initializing_events = initializing_events.lock().unwrap();
         assert_eq!(initializing_events.len(), 1);
@@ -961,4 +2324,472 @@ mod tests {
         assert_eq!(context_data[0], "test.value"); // Successfully accessed config
         assert_eq!(context_data[1], "default"); // Successfully accessed environment
     }
+
+    #[test]
+    fn test_publish_returns_notified_listener_count() {
+        use crate::event::{ContextAwareEventListener, EventListener};
+
+        #[derive(Debug, Clone)]
+        struct PingEvent;
+
+        impl Event for PingEvent {
+            fn name(&self) -> &'static str {
+                "PingEvent"
+            }
+            fn as_any(&self) -> &dyn Any {
+                self
+            }
+            fn into_any(self: Box<Self>) -> Box<dyn Any> {
+                self
+            }
+        }
+
+        struct PingListener;
+        impl EventListener<PingEvent> for PingListener {
+            fn on_event(&self, _event: &PingEvent) {}
+        }
+
+        struct ContextPingListener;
+        impl ContextAwareEventListener<PingEvent> for ContextPingListener {
+            fn on_context_event(&self, _event: &PingEvent, _context: &ApplicationContext) {}
+        }
+
+        let context = ApplicationContext::new();
+        assert_eq!(context.publish_event(&PingEvent), 0);
+
+        context.subscribe_to_events(PingListener);
+        context.subscribe_to_events(PingListener);
+        context.subscribe_to_context_events(ContextPingListener);
+
+        assert_eq!(context.publish_event(&PingEvent), 2);
+        assert_eq!(context.publish_event_with_context(&PingEvent), 3);
+    }
+
+    #[test]
+    fn test_subscribe_to_all_events_receives_lifecycle_events() {
+        use std::sync::{Arc, Mutex};
+
+        let names = Arc::new(Mutex::new(Vec::new()));
+        let recorded = names.clone();
+
+        let context = ApplicationContext::builder()
+            .with_property("app.name", "Audited")
+            .build()
+            .unwrap();
+        context.subscribe_to_all_events(move |event: &dyn Event| {
+            recorded.lock().unwrap().push(event.name());
+        });
+
+        context.initialize().unwrap();
+        context.set_config("app.name", "Renamed");
+
+        let names = names.lock().unwrap();
+        assert_eq!(
+            *names,
+            vec![
+                "ContextInitializing",
+                "ContextInitialized",
+                "ConfigurationChanged"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_config_snapshot_is_cached_until_change() {
+        let context = ApplicationContext::builder()
+            .with_property("app.name", "First")
+            .build()
+            .unwrap();
+
+        let first = context.config_snapshot();
+        assert!(Arc::ptr_eq(&first, &context.config_snapshot()));
+
+        context.set_config("app.name", "First");
+        assert!(Arc::ptr_eq(&first, &context.config_snapshot()));
+
+        context.set_config("app.name", "Second");
+        let second = context.config_snapshot();
+        assert!(!Arc::ptr_eq(&first, &second));
+        assert_eq!(first["app.name"].as_string().unwrap(), "First");
+        assert_eq!(second["app.name"].as_string().unwrap(), "Second");
+
+        context
+            .add_config_source(ConfigSource::Properties(HashMap::from([(
+                "app.port".to_string(),
+                "8080".to_string(),
+            )])))
+            .unwrap();
+        let third = context.config_snapshot();
+        assert_eq!(third["app.port"].as_string().unwrap(), "8080");
+        assert!(!second.contains_key("app.port"));
+    }
+
+    #[test]
+    fn test_refresh_publishes_changed_keys() {
+        use std::sync::Mutex;
+
+        struct RefreshListener(Arc<Mutex<Vec<Vec<String>>>>);
+        impl ContextAwareEventListener<ContextRefreshedEvent> for RefreshListener {
+            fn on_context_event(
+                &self,
+                event: &ContextRefreshedEvent,
+                context: &ApplicationContext,
+            ) {
+                assert_eq!(context.get_config("app.name"), "Reloaded");
+                self.0.lock().unwrap().push(event.changed_keys.clone());
+            }
+        }
+
+        let path = std::env::temp_dir().join(format!(
+            "verdure_refresh_{}.toml",
+            std::process::id()
+        ));
+        std::fs::write(&path, "[app]\nname = \"Initial\"\nmode = \"dev\"\n").unwrap();
+        let context = ApplicationContext::builder()
+            .with_toml_config_file(&path)
+            .build()
+            .unwrap();
+        assert_eq!(context.get_config("app.name"), "Initial");
+        let snapshot = context.config_snapshot();
+
+        let received = Arc::new(Mutex::new(Vec::new()));
+        context.subscribe_to_context_events(RefreshListener(received.clone()));
+
+        std::fs::write(
+            &path,
+            "[app]\nname = \"Reloaded\"\nmode = \"dev\"\nport = 8080\n",
+        )
+        .unwrap();
+        let changed = context.refresh();
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(changed.contains(&"app.name".to_string()));
+        assert!(changed.contains(&"app.port".to_string()));
+        assert!(!changed.contains(&"app.mode".to_string()));
+        assert_eq!(*received.lock().unwrap(), vec![changed]);
+        assert_eq!(snapshot["app.name"].as_string().unwrap(), "Initial");
+        assert_eq!(
+            context.config_snapshot()["app.name"].as_string().unwrap(),
+            "Reloaded"
+        );
+    }
+
+    #[test]
+    fn test_reload_config_file_publishes_changes() {
+        use std::sync::Mutex;
+
+        let path = std::env::temp_dir().join(format!(
+            "verdure_reload_file_{}.toml",
+            std::process::id()
+        ));
+        std::fs::write(&path, "[app]\nname = \"Initial\"\nmode = \"dev\"\n").unwrap();
+        let context = ApplicationContext::builder()
+            .with_toml_config_file(&path)
+            .build()
+            .unwrap();
+        let path = path.to_str().unwrap().to_string();
+        assert_eq!(context.get_config("app.name"), "Initial");
+
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let sink = received.clone();
+        context.on_config_change("app.*", move |key, old_value, new_value| {
+            sink.lock().unwrap().push((
+                key.to_string(),
+                old_value.map(str::to_string),
+                new_value.to_string(),
+            ));
+        });
+
+        std::fs::write(&path, "[app]\nname = \"Reloaded\"\nport = 8080\n").unwrap();
+        let changed = context.reload_config_file(&path).unwrap();
+        assert_eq!(changed, vec!["app.mode", "app.name", "app.port"]);
+        assert_eq!(
+            *received.lock().unwrap(),
+            vec![
+                ("app.mode".to_string(), Some("dev".to_string()), String::new()),
+                (
+                    "app.name".to_string(),
+                    Some("Initial".to_string()),
+                    "Reloaded".to_string()
+                ),
+                ("app.port".to_string(), None, "8080".to_string()),
+            ]
+        );
+        assert_eq!(context.get_config("app.name"), "Reloaded");
+        assert!(context.reload_config_file(&path).unwrap().is_empty());
+
+        // A broken file keeps the configuration that was last read successfully
+        std::fs::write(&path, "[app\nname = ").unwrap();
+        assert!(context.reload_config_file(&path).is_err());
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(context.get_config("app.name"), "Reloaded");
+
+        assert!(matches!(
+            context.reload_config_file("unregistered.toml"),
+            Err(ContextError::ConfigurationFileError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_context_listener_can_subscribe_during_publish() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        struct CountingListener(Arc<AtomicUsize>);
+        impl EventListener<ContextInitializedEvent> for CountingListener {
+            fn on_event(&self, _event: &ContextInitializedEvent) {
+                self.0.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+
+        struct StartupListener(Arc<AtomicUsize>);
+        impl ContextAwareEventListener<ContextInitializedEvent> for StartupListener {
+            fn on_context_event(
+                &self,
+                _event: &ContextInitializedEvent,
+                context: &ApplicationContext,
+            ) {
+                context.subscribe_to_events(CountingListener(self.0.clone()));
+                context.subscribe_to_context_events(StartupListener(self.0.clone()));
+            }
+        }
+
+        let context = ApplicationContext::new();
+        let count = Arc::new(AtomicUsize::new(0));
+        context.subscribe_to_context_events(StartupListener(count.clone()));
+
+        context.initialize().unwrap();
+        assert_eq!(count.load(Ordering::SeqCst), 0);
+
+        // The listeners added during the first dispatch receive the next event
+        let event = ContextInitializedEvent {
+            config_sources_count: 0,
+            timestamp: std::time::SystemTime::now(),
+            duration: std::time::Duration::ZERO,
+        };
+        assert_eq!(context.publish_event_with_context(&event), 3);
+        assert_eq!(count.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_on_config_change_matches_pattern() {
+        use std::sync::Mutex;
+
+        let context = ApplicationContext::new();
+        let changes = Arc::new(Mutex::new(Vec::new()));
+        let sink = changes.clone();
+        context.on_config_change("feature.*", move |key, old, new| {
+            sink.lock()
+                .unwrap()
+                .push((key.to_string(), old.map(str::to_string), new.to_string()));
+        });
+
+        context.set_config("feature.dark_mode", "false");
+        context.set_config("server.port", "8080");
+        context.set_config("feature.dark_mode", "true");
+
+        assert_eq!(
+            *changes.lock().unwrap(),
+            vec![
+                ("feature.dark_mode".to_string(), None, "false".to_string()),
+                (
+                    "feature.dark_mode".to_string(),
+                    Some("false".to_string()),
+                    "true".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_with_defaults_in_finds_first_conventional_file() {
+        let dir = std::env::temp_dir().join(format!("verdure_defaults_{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("config")).unwrap();
+
+        let context = ApplicationContextBuilder::new()
+            .with_defaults_in(&dir)
+            .build()
+            .unwrap();
+        assert_eq!(context.get_config("app.name"), "");
+
+        std::fs::write(dir.join("config/application.yml"), "app:\n  name: Nested\n").unwrap();
+        std::fs::write(dir.join("application.toml"), "[app]\nname = \"Root\"\n").unwrap();
+        let context = ApplicationContextBuilder::new()
+            .with_defaults_in(&dir)
+            .build()
+            .unwrap();
+        assert_eq!(context.get_config("app.name"), "Root");
+
+        std::fs::remove_file(dir.join("application.toml")).unwrap();
+        let context = ApplicationContextBuilder::new()
+            .with_defaults_in(&dir)
+            .build()
+            .unwrap();
+        assert_eq!(context.get_config("app.name"), "Nested");
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_with_config_files_glob_loads_in_name_order() {
+        let dir = std::env::temp_dir().join(format!("verdure_glob_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("10-override.yml"), "app:\n  name: Override\n").unwrap();
+        std::fs::write(dir.join("00-base.yml"), "app:\n  name: Base\n  port: 8080\n").unwrap();
+        std::fs::write(dir.join("99-ignored.toml"), "[app]\nname = \"Toml\"\n").unwrap();
+        std::fs::write(dir.join(".hidden.yml"), "app:\n  name: Hidden\n").unwrap();
+
+        let context = ApplicationContextBuilder::new()
+            .with_config_files_glob(dir.join("*.yml"))
+            .build()
+            .unwrap();
+        assert_eq!(context.get_config("app.name"), "Override");
+        assert_eq!(context.get_config("app.port"), "8080");
+        assert_eq!(context.config_manager().sources_count(), 2);
+
+        // Expanded by build, in the position the pattern was added
+        let builder = ApplicationContextBuilder::new()
+            .with_inline_toml("[app]\nname = \"Inline\"\nmode = \"dev\"\n")
+            .with_config_files_glob(dir.join("*.yaml"))
+            .with_inline_toml("[app]\nmode = \"prod\"\n");
+        std::fs::write(dir.join("late.yaml"), "app:\n  name: Late\n  mode: test\n").unwrap();
+        let context = builder.build().unwrap();
+        assert_eq!(context.get_config("app.name"), "Late");
+        assert_eq!(context.get_config("app.mode"), "prod");
+        assert_eq!(context.config_manager().sources_count(), 3);
+
+        let context = ApplicationContextBuilder::new()
+            .with_config_files_glob(dir.join("*.json"))
+            .with_config_files_glob(dir.join("missing/*.yml"))
+            .build()
+            .unwrap();
+        assert_eq!(context.config_manager().sources_count(), 0);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_matches_file_pattern() {
+        assert!(matches_file_pattern("*.yml", "app.yml"));
+        assert!(matches_file_pattern("??-*.toml", "10-db.toml"));
+        assert!(matches_file_pattern("a*b*c", "abbbc"));
+        assert!(matches_file_pattern("*", "x"));
+        assert!(!matches_file_pattern("*.yml", "app.yaml"));
+        assert!(!matches_file_pattern("??-*.toml", "1-db.toml"));
+        assert!(!matches_file_pattern("*.yml", ".hidden.yml"));
+        assert!(matches_file_pattern(".*.yml", ".hidden.yml"));
+    }
+
+    #[test]
+    fn test_get_component_as_trait_object() {
+        trait Greeter: Send + Sync {
+            fn greet(&self) -> &'static str;
+        }
+
+        struct FrenchGreeter;
+        impl Greeter for FrenchGreeter {
+            fn greet(&self) -> &'static str {
+                "Bonjour"
+            }
+        }
+
+        let context = ApplicationContext::new();
+        let missing = context.get_component_as::<FrenchGreeter, dyn Greeter>(|greeter| greeter);
+        match missing {
+            Err(ContextError::ComponentNotFound { type_name }) => {
+                assert!(type_name.contains("FrenchGreeter"))
+            }
+            _ => panic!("expected a missing component error"),
+        }
+
+        let registered = Arc::new(FrenchGreeter);
+        context.register_component(registered.clone());
+        let greeter = context
+            .get_component_as::<FrenchGreeter, dyn Greeter>(|greeter| greeter)
+            .unwrap();
+        assert_eq!(greeter.greet(), "Bonjour");
+        assert!(std::ptr::eq(
+            Arc::as_ptr(&greeter) as *const (),
+            Arc::as_ptr(&registered) as *const ()
+        ));
+    }
+
+    #[test]
+    fn test_initialized_event_carries_duration() {
+        use std::sync::Mutex;
+        use std::time::{Duration, Instant};
+
+        struct SlowStartListener;
+        impl EventListener<ContextInitializingEvent> for SlowStartListener {
+            fn on_event(&self, _event: &ContextInitializingEvent) {
+                std::thread::sleep(Duration::from_millis(20));
+            }
+        }
+
+        struct DurationListener(Arc<Mutex<Option<Duration>>>);
+        impl EventListener<ContextInitializedEvent> for DurationListener {
+            fn on_event(&self, event: &ContextInitializedEvent) {
+                *self.0.lock().unwrap() = Some(event.duration);
+            }
+        }
+
+        let context = ApplicationContext::new();
+        let duration = Arc::new(Mutex::new(None));
+        context.subscribe_to_events(SlowStartListener);
+        context.subscribe_to_events(DurationListener(duration.clone()));
+
+        let started = Instant::now();
+        context.initialize().unwrap();
+        let elapsed = started.elapsed();
+
+        let duration = duration.lock().unwrap().unwrap();
+        assert!(duration >= Duration::from_millis(20));
+        assert!(duration <= elapsed);
+    }
+
+    #[test]
+    fn test_builder_with_environment() {
+        let _env = EnvVars::set(&[
+            ("VERDURE_BUILDER_ENV_PORT", "9090"),
+            ("VERDURE_BUILDER_ENV_NAME", "FromEnv"),
+        ]);
+        let defaults = "[verdure.builder.env]\nport = 8080\nname = \"FromFile\"\n";
+
+        let context = ApplicationContextBuilder::new()
+            .with_inline_toml(defaults)
+            .build()
+            .unwrap();
+        assert_eq!(context.get_config("verdure.builder.env.port"), "8080");
+
+        let context = ApplicationContextBuilder::new()
+            .with_environment(true)
+            .with_inline_toml(defaults)
+            .with_property("verdure.builder.env.name", "FromProperty")
+            .build()
+            .unwrap();
+        assert_eq!(context.get_config("verdure.builder.env.port"), "9090");
+        assert_eq!(context.get_config("verdure.builder.env.name"), "FromProperty");
+        assert_eq!(
+            context.config_manager().source_of("verdure.builder.env.port").unwrap(),
+            "environment variables"
+        );
+    }
+
+    #[test]
+    fn test_inline_defaults_are_overridden() {
+        let context = ApplicationContextBuilder::new()
+            .with_inline_yaml("app:\n  name: Default\n  port: 8080\n")
+            .with_property("app.name", "Custom")
+            .build()
+            .unwrap();
+        assert_eq!(context.get_config("app.name"), "Custom");
+        assert_eq!(context.get_config("app.port"), "8080");
+
+        let result = ApplicationContextBuilder::new()
+            .with_inline_toml("[app\nname = ")
+            .build();
+        assert!(matches!(
+            result,
+            Err(ContextError::ConfigurationFileError { .. })
+        ));
+    }
 }
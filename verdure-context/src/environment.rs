@@ -0,0 +1,138 @@
+//! Environment classification
+//!
+//! This module provides the `Environment` enum, a typed view over the environment
+//! string reported by the application context. It recognizes the conventional
+//! names for production, development, and test environments.
+
+use std::fmt;
+
+/// Configuration key holding the environment name, see
+/// [`ApplicationContext::environment`](crate::ApplicationContext::environment)
+pub const ENVIRONMENT_KEY: &str = "verdure.environment";
+
+/// Well-known application environments
+///
+/// `Environment` classifies an environment string such as `"prod"` or a comma-joined
+/// list such as `"dev,local"`. Names are matched case-insensitively:
+///
+/// - `prod` / `production` -> [`Environment::Production`]
+/// - `dev` / `development` -> [`Environment::Development`]
+/// - `test` -> [`Environment::Test`]
+///
+/// When several names are present, production takes precedence over test, and test
+/// over development. Anything unrecognized is kept as [`Environment::Other`].
+///
+/// # Examples
+///
+/// ```rust
+/// use verdure_context::Environment;
+///
+/// assert_eq!(Environment::from_name("production"), Environment::Production);
+/// assert_eq!(Environment::from_name("local,DEV"), Environment::Development);
+/// assert_eq!(
+///     Environment::from_name("default"),
+///     Environment::Other("default".to_string())
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Environment {
+    /// Production environment (`prod`, `production`)
+    Production,
+    /// Development environment (`dev`, `development`)
+    Development,
+    /// Test environment (`test`)
+    Test,
+    /// Any other environment, holding the original name
+    Other(String),
+}
+
+impl Environment {
+    /// Classifies an environment name or a comma-separated list of names
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The environment name, e.g. the value of `ApplicationContext::environment()`
+    pub fn from_name(name: &str) -> Self {
+        let names: Vec<String> = name
+            .split(',')
+            .map(|n| n.trim().to_ascii_lowercase())
+            .collect();
+        let has = |candidates: &[&str]| names.iter().any(|n| candidates.contains(&n.as_str()));
+
+        if has(&["prod", "production"]) {
+            Environment::Production
+        } else if has(&["test"]) {
+            Environment::Test
+        } else if has(&["dev", "development"]) {
+            Environment::Development
+        } else {
+            Environment::Other(name.to_string())
+        }
+    }
+
+    /// Returns `true` for [`Environment::Production`]
+    pub fn is_production(&self) -> bool {
+        matches!(self, Environment::Production)
+    }
+
+    /// Returns `true` for [`Environment::Development`]
+    pub fn is_development(&self) -> bool {
+        matches!(self, Environment::Development)
+    }
+
+    /// Returns `true` for [`Environment::Test`]
+    pub fn is_test(&self) -> bool {
+        matches!(self, Environment::Test)
+    }
+}
+
+impl fmt::Display for Environment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Environment::Production => write!(f, "production"),
+            Environment::Development => write!(f, "development"),
+            Environment::Test => write!(f, "test"),
+            Environment::Other(name) => write!(f, "{}", name),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_conventional_names() {
+        assert_eq!(Environment::from_name("prod"), Environment::Production);
+        assert_eq!(
+            Environment::from_name("Production"),
+            Environment::Production
+        );
+        assert_eq!(Environment::from_name("dev"), Environment::Development);
+        assert_eq!(
+            Environment::from_name("development"),
+            Environment::Development
+        );
+        assert_eq!(Environment::from_name("TEST"), Environment::Test);
+    }
+
+    #[test]
+    fn test_comma_joined_names() {
+        assert_eq!(Environment::from_name("dev, test"), Environment::Test);
+        assert_eq!(Environment::from_name("test,prod"), Environment::Production);
+        assert_eq!(
+            Environment::from_name("local,dev"),
+            Environment::Development
+        );
+    }
+
+    #[test]
+    fn test_other_environment() {
+        let env = Environment::from_name("staging");
+        assert_eq!(env, Environment::Other("staging".to_string()));
+        assert!(!env.is_production());
+        assert!(!env.is_development());
+        assert!(!env.is_test());
+        assert_eq!(env.to_string(), "staging");
+    }
+}
@@ -5,6 +5,8 @@
 //! management, and environment handling.
 
 use std::fmt;
+use std::sync::Arc;
+use verdure_core::error::container::{ContainerError, ContainerErrorKind};
 
 /// Context operation errors
 ///
@@ -41,10 +43,25 @@ pub enum ContextError {
         reason: String,
     },
 
-    /// Configuration file I/O error
+    /// The IoC container failed to initialize
+    ///
+    /// `kind` tells apart, for example, a circular dependency from a missing component,
+    /// while `source` holds the original [`ContainerError`].
+    ContainerInitializationFailed {
+        /// The kind of the container error
+        kind: ContainerErrorKind,
+        /// The container error
+        source: ErrorSource,
+    },
+
+    /// Configuration file I/O or parse error
     ConfigurationFileError {
         /// Error message
         message: String,
+        /// Path of the file, if the error relates to a specific file
+        path: Option<String>,
+        /// The underlying I/O or parse error, if any
+        source: Option<ErrorSource>,
     },
 
     /// Serialization/deserialization error
@@ -60,6 +77,12 @@ pub enum ContextError {
         /// Binding error reason
         reason: String,
     },
+
+    /// Required component not found in the container
+    ComponentNotFound {
+        /// Type name of the missing component
+        type_name: String,
+    },
 }
 
 impl fmt::Display for ContextError {
@@ -74,7 +97,10 @@ impl fmt::Display for ContextError {
             ContextError::InitializationFailed { reason } => {
                 write!(f, "Context initialization failed: {}", reason)
             }
-            ContextError::ConfigurationFileError { message } => {
+            ContextError::ContainerInitializationFailed { source, .. } => {
+                write!(f, "IoC container initialization failed: {}", source)
+            }
+            ContextError::ConfigurationFileError { message, .. } => {
                 write!(f, "Configuration file error: {}", message)
             }
             ContextError::SerializationError { message } => {
@@ -83,11 +109,56 @@ impl fmt::Display for ContextError {
             ContextError::PropertyBindingError { property, reason } => {
                 write!(f, "Property binding error for '{}': {}", property, reason)
             }
+            ContextError::ComponentNotFound { type_name } => {
+                write!(f, "Component not found: {}", type_name)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ContextError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ContextError::ConfigurationFileError {
+                source: Some(source),
+                ..
+            } => Some(source.get()),
+            ContextError::ContainerInitializationFailed { source, .. } => Some(source.get()),
+            _ => None,
         }
     }
 }
 
-impl std::error::Error for ContextError {}
+/// The underlying cause of a [`ContextError`]
+///
+/// Wraps the original error in an `Arc` so that `ContextError` stays `Clone`.
+/// Two sources compare equal when their messages are equal.
+#[derive(Debug, Clone)]
+pub struct ErrorSource(Arc<dyn std::error::Error + Send + Sync>);
+
+impl ErrorSource {
+    /// Wraps an error
+    pub fn new(error: impl std::error::Error + Send + Sync + 'static) -> Self {
+        Self(Arc::new(error))
+    }
+
+    /// Returns the wrapped error
+    pub fn get(&self) -> &(dyn std::error::Error + Send + Sync + 'static) {
+        self.0.as_ref()
+    }
+}
+
+impl fmt::Display for ErrorSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl PartialEq for ErrorSource {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.to_string() == other.0.to_string()
+    }
+}
 
 impl ContextError {
     /// Creates a configuration not found error
@@ -120,6 +191,52 @@ impl ContextError {
         }
     }
 
+    /// Combines several invalid configuration errors into a single one
+    ///
+    /// The first error is returned with the keys of the remaining errors appended to
+    /// its reason, so all misconfigured keys are reported at once.
+    ///
+    /// # Returns
+    ///
+    /// `None` if `errors` is empty
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use verdure_context::ContextError;
+    ///
+    /// let error = ContextError::merge_invalid_configurations(vec![
+    ///     ContextError::invalid_configuration("server.port", "not a number"),
+    ///     ContextError::invalid_configuration("server.workers", "not a number"),
+    /// ])
+    /// .unwrap();
+    /// assert_eq!(
+    ///     error.to_string(),
+    ///     "Invalid configuration for key 'server.port': not a number \
+    ///      (also invalid: server.workers)"
+    /// );
+    /// ```
+    pub fn merge_invalid_configurations(errors: Vec<ContextError>) -> Option<Self> {
+        let mut errors = errors.into_iter();
+        let first = errors.next()?;
+        let others: Vec<String> = errors
+            .map(|error| match error {
+                Self::InvalidConfiguration { key, .. } => key,
+                other => other.to_string(),
+            })
+            .collect();
+        if others.is_empty() {
+            return Some(first);
+        }
+        match first {
+            Self::InvalidConfiguration { key, reason } => Some(Self::InvalidConfiguration {
+                key,
+                reason: format!("{} (also invalid: {})", reason, others.join(", ")),
+            }),
+            other => Some(other),
+        }
+    }
+
     /// Creates an initialization failed error
     ///
     /// # Arguments
@@ -131,6 +248,38 @@ impl ContextError {
         }
     }
 
+    /// Creates an error for a container that failed to initialize
+    ///
+    /// # Arguments
+    ///
+    /// * `error` - The error returned by the container
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use verdure_context::ContextError;
+    /// use verdure_core::error::container::{ContainerError, ContainerErrorKind};
+    /// use std::error::Error;
+    ///
+    /// let error = ContextError::container_initialization_failed(
+    ///     ContainerError::circular_dependency("A -> B -> A"),
+    /// );
+    /// assert!(matches!(
+    ///     error,
+    ///     ContextError::ContainerInitializationFailed {
+    ///         kind: ContainerErrorKind::CircularDependency,
+    ///         ..
+    ///     }
+    /// ));
+    /// assert!(error.source().unwrap().is::<ContainerError>());
+    /// ```
+    pub fn container_initialization_failed(error: ContainerError) -> Self {
+        Self::ContainerInitializationFailed {
+            kind: error.kind,
+            source: ErrorSource::new(error),
+        }
+    }
+
     /// Creates a configuration file error
     ///
     /// # Arguments
@@ -139,6 +288,88 @@ impl ContextError {
     pub fn configuration_file_error(message: impl Into<String>) -> Self {
         Self::ConfigurationFileError {
             message: message.into(),
+            path: None,
+            source: None,
+        }
+    }
+
+    /// Creates a configuration file error for a file that could not be read
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path of the file
+    /// * `error` - The I/O error returned while reading it
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use verdure_context::ContextError;
+    /// use std::error::Error;
+    ///
+    /// let io_error = std::io::Error::new(std::io::ErrorKind::NotFound, "not found");
+    /// let error = ContextError::file_read_error("app.toml", io_error);
+    /// assert!(error.to_string().contains("app.toml"));
+    /// assert!(error.source().is_some());
+    /// ```
+    pub fn file_read_error(path: impl Into<String>, error: std::io::Error) -> Self {
+        let path = path.into();
+        Self::ConfigurationFileError {
+            message: format!("failed to read '{}': {}", path, error),
+            path: Some(path),
+            source: Some(ErrorSource::new(error)),
+        }
+    }
+
+    /// Creates a configuration file error for a file whose content is not valid UTF-8
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path of the file
+    /// * `error` - The UTF-8 decoding error
+    pub fn file_encoding_error(path: impl Into<String>, error: std::str::Utf8Error) -> Self {
+        let path = path.into();
+        Self::ConfigurationFileError {
+            message: format!(
+                "failed to read '{}': content is not valid UTF-8 ({})",
+                path, error
+            ),
+            path: Some(path),
+            source: Some(ErrorSource::new(error)),
+        }
+    }
+
+    /// Creates a configuration file error for a file that could not be parsed
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path of the file
+    /// * `error` - The parser error
+    pub fn file_parse_error(
+        path: impl Into<String>,
+        error: impl std::error::Error + Send + Sync + 'static,
+    ) -> Self {
+        let path = path.into();
+        Self::ConfigurationFileError {
+            message: format!("failed to parse '{}': {}", path, error),
+            path: Some(path),
+            source: Some(ErrorSource::new(error)),
+        }
+    }
+
+    /// Creates a configuration file error for inline content that could not be parsed
+    ///
+    /// # Arguments
+    ///
+    /// * `format` - Name of the content's format
+    /// * `error` - The parser error
+    pub fn inline_parse_error(
+        format: &str,
+        error: impl std::error::Error + Send + Sync + 'static,
+    ) -> Self {
+        Self::ConfigurationFileError {
+            message: format!("failed to parse inline {}: {}", format, error),
+            path: None,
+            source: Some(ErrorSource::new(error)),
         }
     }
 
@@ -165,6 +396,48 @@ impl ContextError {
             reason: reason.into(),
         }
     }
+
+    /// Creates a component not found error
+    ///
+    /// # Arguments
+    ///
+    /// * `type_name` - Type name of the missing component
+    pub fn component_not_found(type_name: impl Into<String>) -> Self {
+        Self::ComponentNotFound {
+            type_name: type_name.into(),
+        }
+    }
+}
+
+impl From<std::io::Error> for ContextError {
+    /// Converts an I/O error into a `ConfigurationFileError` without a path
+    ///
+    /// Prefer [`ContextError::file_read_error`] when the path of the file is known.
+    fn from(error: std::io::Error) -> Self {
+        Self::ConfigurationFileError {
+            message: error.to_string(),
+            path: None,
+            source: Some(ErrorSource::new(error)),
+        }
+    }
+}
+
+impl From<toml::de::Error> for ContextError {
+    fn from(error: toml::de::Error) -> Self {
+        Self::serialization_error(format!("invalid TOML: {}", error))
+    }
+}
+
+impl From<serde_yaml::Error> for ContextError {
+    fn from(error: serde_yaml::Error) -> Self {
+        Self::serialization_error(format!("invalid YAML: {}", error))
+    }
+}
+
+impl From<serde_json::Error> for ContextError {
+    fn from(error: serde_json::Error) -> Self {
+        Self::serialization_error(format!("invalid JSON: {}", error))
+    }
 }
 
 /// Result type for context operations
@@ -194,6 +467,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_merge_invalid_configurations() {
+        assert!(ContextError::merge_invalid_configurations(Vec::new()).is_none());
+
+        let single =
+            ContextError::merge_invalid_configurations(vec![ContextError::invalid_configuration(
+                "port",
+                "not a number",
+            )]);
+        assert_eq!(
+            single,
+            Some(ContextError::invalid_configuration("port", "not a number"))
+        );
+
+        let merged = ContextError::merge_invalid_configurations(vec![
+            ContextError::invalid_configuration("port", "not a number"),
+            ContextError::invalid_configuration("host", "empty"),
+            ContextError::invalid_configuration("level", "unknown"),
+        ]);
+        assert_eq!(
+            merged,
+            Some(ContextError::invalid_configuration(
+                "port",
+                "not a number (also invalid: host, level)"
+            ))
+        );
+    }
+
     #[test]
     fn test_initialization_failed_error() {
         let error = ContextError::initialization_failed("missing required config");
@@ -214,6 +515,29 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_file_read_error_keeps_source() {
+        use std::error::Error;
+
+        let io_error = std::io::Error::new(std::io::ErrorKind::NotFound, "no such file");
+        let error = ContextError::file_read_error("config/app.yml", io_error);
+
+        match &error {
+            ContextError::ConfigurationFileError { path, source, .. } => {
+                assert_eq!(path.as_deref(), Some("config/app.yml"));
+                assert!(source.is_some());
+            }
+            other => panic!("expected ConfigurationFileError, got {:?}", other),
+        }
+        assert_eq!(
+            error.to_string(),
+            "Configuration file error: failed to read 'config/app.yml': no such file"
+        );
+        let source = error.source().unwrap();
+        assert!(source.downcast_ref::<std::io::Error>().is_some());
+        assert_eq!(error.clone(), error);
+    }
+
     #[test]
     fn test_serialization_error() {
         let error = ContextError::serialization_error("invalid TOML format");
@@ -233,4 +557,75 @@ mod tests {
             "Property binding error for 'database.port': type mismatch"
         );
     }
+
+    #[test]
+    fn test_component_not_found_error() {
+        let error = ContextError::component_not_found("app::UserService");
+        assert!(matches!(error, ContextError::ComponentNotFound { .. }));
+        assert_eq!(error.to_string(), "Component not found: app::UserService");
+    }
+
+    #[test]
+    fn test_from_io_error() {
+        use std::error::Error;
+
+        let io_error = std::io::Error::new(std::io::ErrorKind::PermissionDenied, "denied");
+        let error = ContextError::from(io_error);
+        assert!(matches!(
+            error,
+            ContextError::ConfigurationFileError { path: None, .. }
+        ));
+        assert_eq!(error.to_string(), "Configuration file error: denied");
+        assert!(error.source().is_some());
+    }
+
+    #[test]
+    fn test_from_parser_errors() {
+        fn parse_toml(content: &str) -> ContextResult<toml::Value> {
+            Ok(toml::from_str(content)?)
+        }
+        fn parse_yaml(content: &str) -> ContextResult<serde_yaml::Value> {
+            Ok(serde_yaml::from_str(content)?)
+        }
+        fn parse_json(content: &str) -> ContextResult<serde_json::Value> {
+            Ok(serde_json::from_str(content)?)
+        }
+
+        let error = parse_toml("name = ").unwrap_err();
+        assert!(matches!(error, ContextError::SerializationError { .. }));
+        assert!(error.to_string().contains("invalid TOML"));
+
+        let error = parse_yaml("a: [1, 2").unwrap_err();
+        assert!(matches!(error, ContextError::SerializationError { .. }));
+        assert!(error.to_string().contains("invalid YAML"));
+
+        let error = parse_json("{\"a\": }").unwrap_err();
+        assert!(matches!(error, ContextError::SerializationError { .. }));
+        assert!(error.to_string().contains("invalid JSON"));
+    }
+
+    #[test]
+    fn test_container_initialization_failed_keeps_source() {
+        use std::error::Error;
+
+        let error = ContextError::container_initialization_failed(ContainerError::not_found(
+            "Bean definition not found",
+        ));
+        assert!(matches!(
+            error,
+            ContextError::ContainerInitializationFailed {
+                kind: ContainerErrorKind::NotFound,
+                ..
+            }
+        ));
+        assert_eq!(
+            error.to_string(),
+            "IoC container initialization failed: Component not found: Bean definition not found"
+        );
+
+        let source = error.source().unwrap();
+        let container_error = source.downcast_ref::<ContainerError>().unwrap();
+        assert_eq!(container_error.kind, ContainerErrorKind::NotFound);
+        assert_eq!(error.clone(), error);
+    }
 }
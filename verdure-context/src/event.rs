@@ -4,12 +4,14 @@
 //! It supports application-wide event publishing and subscription, enabling decoupled
 //! communication between different parts of the application.
 
+use crate::wildcard;
 use dashmap::DashMap;
+use parking_lot::RwLock;
 use std::any::{Any, TypeId};
 use std::collections::HashMap;
 use std::sync::Arc;
 
-/// Built-in context lifecycle events
+// Built-in context lifecycle events
 
 /// Event fired when the application context starts initialization
 ///
@@ -47,6 +49,9 @@ pub struct ContextInitializedEvent {
     pub config_sources_count: usize,
     /// Initialization timestamp
     pub timestamp: std::time::SystemTime,
+    /// Time taken by the initialization, measured with a monotonic clock from the start
+    /// of `ApplicationContext::initialize`
+    pub duration: std::time::Duration,
 }
 
 impl Event for ContextInitializedEvent {
@@ -93,10 +98,39 @@ impl Event for ConfigurationChangedEvent {
     }
 }
 
+/// Event fired when the configuration is reloaded as a whole
+///
+/// Unlike [`ConfigurationChangedEvent`], which describes a single key, this event is
+/// published once per [`ApplicationContext::refresh`](crate::ApplicationContext::refresh)
+/// with every key whose effective value changed, so listeners can react to a
+/// coherent batch of changes.
+#[derive(Debug, Clone)]
+pub struct ContextRefreshedEvent {
+    /// Keys that were added, removed or changed by the reload, sorted
+    pub changed_keys: Vec<String>,
+    /// Refresh timestamp
+    pub timestamp: std::time::SystemTime,
+}
+
+impl Event for ContextRefreshedEvent {
+    fn name(&self) -> &'static str {
+        "ContextRefreshed"
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
 /// Event trait that all events must implement
 ///
 /// This trait allows events to be stored and transmitted in a type-safe manner
-/// while supporting downcasting to the concrete event type.
+/// while supporting downcasting to the concrete event type. With the `verdure`
+/// crate, `#[derive(Event)]` generates this implementation.
 ///
 /// # Examples
 ///
@@ -334,6 +368,70 @@ impl<T: Event + 'static, L: EventListener<T>> AnyEventListener for TypedEventLis
     }
 }
 
+/// Static definition of an application context event listener
+///
+/// Definitions are registered with the `context_event_listener!` macro and subscribed
+/// to the event publisher of every `ApplicationContext` when it is created, so library
+/// crates can ship listeners without the application wiring them.
+///
+/// # Examples
+///
+/// ```rust
+/// use verdure_context::event::ContextListenerDefinition;
+/// use verdure_context::{ContextInitializedEvent, EventListener, EventPublisher};
+///
+/// struct StartupLogger;
+///
+/// impl EventListener<ContextInitializedEvent> for StartupLogger {
+///     fn on_event(&self, _event: &ContextInitializedEvent) {
+///         println!("Context initialized");
+///     }
+/// }
+///
+/// let definition = ContextListenerDefinition {
+///     name: "startup_logger",
+///     subscribe: |publisher: &EventPublisher| publisher.subscribe(StartupLogger),
+/// };
+/// ```
+pub struct ContextListenerDefinition {
+    /// Unique name identifying this listener
+    pub name: &'static str,
+    /// Function subscribing the listener to a context's event publisher
+    pub subscribe: fn(&EventPublisher),
+}
+
+inventory::collect!(ContextListenerDefinition);
+
+/// Listener invoking a callback when a configuration key matching a pattern changes
+///
+/// Created by [`ApplicationContext::on_config_change`].
+///
+/// [`ApplicationContext::on_config_change`]: crate::ApplicationContext::on_config_change
+pub(crate) struct ConfigKeyListener<F> {
+    pattern: String,
+    callback: F,
+}
+
+impl<F> ConfigKeyListener<F> {
+    pub(crate) fn new(pattern: impl Into<String>, callback: F) -> Self {
+        Self {
+            pattern: pattern.into(),
+            callback,
+        }
+    }
+}
+
+impl<F> EventListener<ConfigurationChangedEvent> for ConfigKeyListener<F>
+where
+    F: Fn(&str, Option<&str>, &str) + Send + Sync,
+{
+    fn on_event(&self, event: &ConfigurationChangedEvent) {
+        if wildcard::matches(&self.pattern, &event.key) {
+            (self.callback)(&event.key, event.old_value.as_deref(), &event.new_value);
+        }
+    }
+}
+
 /// Event publisher for broadcasting events
 ///
 /// `EventPublisher` manages event listeners and provides functionality to publish
@@ -386,8 +484,13 @@ pub struct EventPublisher {
     listeners: DashMap<TypeId, Vec<Arc<dyn AnyEventListener>>>,
     /// Context-aware event listeners organized by event type
     context_aware_listeners: DashMap<TypeId, Vec<Arc<dyn AnyContextAwareEventListener>>>,
+    /// Listeners receiving every published event regardless of its type
+    global_listeners: RwLock<Vec<GlobalEventListener>>,
 }
 
+/// A type-agnostic listener registered with [`EventPublisher::subscribe_all`]
+type GlobalEventListener = Arc<dyn Fn(&dyn Event) + Send + Sync>;
+
 impl EventPublisher {
     /// Creates a new event publisher
     ///
@@ -402,6 +505,7 @@ impl EventPublisher {
         Self {
             listeners: DashMap::new(),
             context_aware_listeners: DashMap::new(),
+            global_listeners: RwLock::new(Vec::new()),
         }
     }
 
@@ -443,7 +547,7 @@ impl EventPublisher {
 
         self.context_aware_listeners
             .entry(type_id)
-            .or_insert_with(Vec::new)
+            .or_default()
             .push(typed_listener);
     }
     ///
@@ -483,10 +587,49 @@ impl EventPublisher {
 
         self.listeners
             .entry(type_id)
-            .or_insert_with(Vec::new)
+            .or_default()
             .push(typed_listener);
     }
 
+    /// Subscribes a listener to every published event
+    ///
+    /// The listener receives events of any type as `&dyn Event`, after the typed
+    /// listeners for the event have run. This is useful for cross-cutting concerns such
+    /// as audit logging.
+    ///
+    /// # Arguments
+    ///
+    /// * `listener` - The closure invoked for each published event
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use verdure_context::{EventPublisher, Event};
+    /// use std::any::Any;
+    ///
+    /// #[derive(Debug, Clone)]
+    /// struct AuditedEvent;
+    ///
+    /// impl Event for AuditedEvent {
+    ///     fn name(&self) -> &'static str { "AuditedEvent" }
+    ///     fn as_any(&self) -> &dyn Any { self }
+    ///     fn into_any(self: Box<Self>) -> Box<dyn Any> { self }
+    /// }
+    ///
+    /// let publisher = EventPublisher::new();
+    /// publisher.subscribe_all(|event: &dyn Event| {
+    ///     println!("[audit] {}", event.name());
+    /// });
+    ///
+    /// assert_eq!(publisher.publish(&AuditedEvent), 1);
+    /// ```
+    pub fn subscribe_all<F>(&self, listener: F)
+    where
+        F: Fn(&dyn Event) + Send + Sync + 'static,
+    {
+        self.global_listeners.write().push(Arc::new(listener));
+    }
+
     /// Publishes an event to all registered listeners with context access
     ///
     /// This method publishes the event to both regular listeners and context-aware listeners.
@@ -496,32 +639,44 @@ impl EventPublisher {
     ///
     /// * `event` - The event to publish
     /// * `context` - Reference to the ApplicationContext
+    ///
+    /// # Returns
+    ///
+    /// The number of listeners (regular and context-aware) that handled the event
     pub fn publish_with_context<T: Event + 'static>(
         &self,
         event: &T,
         context: &crate::context::ApplicationContext,
-    ) {
+    ) -> usize {
         let type_id = TypeId::of::<T>();
+        let mut notified = self.publish(event);
 
-        // Publish to regular listeners
-        if let Some(listeners) = self.listeners.get(&type_id) {
-            for listener in listeners.iter() {
-                listener.handle_event(event);
-            }
-        }
-
-        // Publish to context-aware listeners
-        if let Some(context_listeners) = self.context_aware_listeners.get(&type_id) {
-            for listener in context_listeners.iter() {
-                listener.handle_context_event(event, context);
+        // Publish to context-aware listeners, released from the map so that listeners can
+        // subscribe further listeners while the event is dispatched
+        let context_listeners = self
+            .context_aware_listeners
+            .get(&type_id)
+            .map(|listeners| listeners.clone())
+            .unwrap_or_default();
+        for listener in context_listeners.iter() {
+            if listener.handle_context_event(event, context) {
+                notified += 1;
             }
         }
+        notified
     }
+
+    /// Publishes an event to all registered regular listeners
     ///
     /// # Arguments
     ///
     /// * `event` - The event to publish
     ///
+    /// # Returns
+    ///
+    /// The number of listeners that handled the event, including listeners registered
+    /// with [`subscribe_all`](Self::subscribe_all)
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -544,16 +699,28 @@ impl EventPublisher {
     ///     message: "System startup complete".to_string(),
     /// };
     ///
-    /// publisher.publish(&event);
+    /// assert_eq!(publisher.publish(&event), 0);
     /// ```
-    pub fn publish<T: Event + 'static>(&self, event: &T) {
+    pub fn publish<T: Event + 'static>(&self, event: &T) -> usize {
         let type_id = TypeId::of::<T>();
 
-        if let Some(listeners) = self.listeners.get(&type_id) {
-            for listener in listeners.iter() {
-                listener.handle_event(event);
-            }
+        // Listeners are cloned out of the map before they run, so a listener may subscribe
+        // or clear listeners without deadlocking on the map's shard lock
+        let listeners = self
+            .listeners
+            .get(&type_id)
+            .map(|listeners| listeners.clone())
+            .unwrap_or_default();
+        let notified = listeners
+            .iter()
+            .filter(|listener| listener.handle_event(event))
+            .count();
+
+        let global_listeners = self.global_listeners.read().clone();
+        for listener in global_listeners.iter() {
+            listener(event);
         }
+        notified + global_listeners.len()
     }
 
     /// Gets the number of listeners for a specific event type
@@ -599,6 +766,9 @@ impl EventPublisher {
 
     /// Removes all listeners for all event types
     ///
+    /// This removes regular, context-aware and [`subscribe_all`](Self::subscribe_all)
+    /// listeners alike.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -609,6 +779,8 @@ impl EventPublisher {
     /// ```
     pub fn clear_all_listeners(&mut self) {
         self.listeners.clear();
+        self.context_aware_listeners.clear();
+        self.global_listeners.write().clear();
     }
 
     /// Gets statistics about registered listeners
@@ -696,7 +868,8 @@ mod tests {
     struct TestListener;
 
     impl EventListener<TestEvent> for TestListener {
-        fn on_event(&self, _event: &TestEvent) {
+        fn on_event(&self, event: &TestEvent) {
+            assert!(!event.message.is_empty());
             TEST_COUNTER.fetch_add(1, Ordering::SeqCst);
         }
     }
@@ -704,7 +877,8 @@ mod tests {
     struct AnotherListener;
 
     impl EventListener<AnotherEvent> for AnotherListener {
-        fn on_event(&self, _event: &AnotherEvent) {
+        fn on_event(&self, event: &AnotherEvent) {
+            assert_eq!(event.value, 42);
             TEST_COUNTER.fetch_add(10, Ordering::SeqCst);
         }
     }
@@ -752,7 +926,7 @@ mod tests {
             message: "test".to_string(),
         };
 
-        publisher.publish(&event);
+        assert_eq!(publisher.publish(&event), 2);
 
         assert_eq!(TEST_COUNTER.load(Ordering::SeqCst), 2);
         assert_eq!(publisher.listener_count::<TestEvent>(), 2);
@@ -786,7 +960,7 @@ mod tests {
         };
 
         // Should not panic
-        publisher.publish(&event);
+        assert_eq!(publisher.publish(&event), 0);
     }
 
     #[test]
@@ -804,6 +978,103 @@ mod tests {
         assert_eq!(publisher.listener_count::<AnotherEvent>(), 0);
     }
 
+    #[test]
+    fn test_subscribe_all_receives_every_event() {
+        let publisher = EventPublisher::new();
+        let names = Arc::new(parking_lot::Mutex::new(Vec::new()));
+        let recorded = names.clone();
+        publisher.subscribe_all(move |event: &dyn Event| recorded.lock().push(event.name()));
+        publisher.subscribe(TestListener);
+
+        let handled = publisher.publish(&TestEvent {
+            message: "hello".to_string(),
+        });
+        assert_eq!(handled, 2);
+        assert_eq!(publisher.publish(&AnotherEvent { value: 1 }), 1);
+        assert_eq!(*names.lock(), vec!["TestEvent", "AnotherEvent"]);
+    }
+
+    #[test]
+    fn test_clear_listeners_removes_global_listeners() {
+        let mut publisher = EventPublisher::new();
+        publisher.subscribe_all(|_event: &dyn Event| {});
+        assert_eq!(publisher.publish(&AnotherEvent { value: 1 }), 1);
+
+        publisher.clear_all_listeners();
+        assert_eq!(publisher.publish(&AnotherEvent { value: 1 }), 0);
+    }
+
+    struct CountingListener(Arc<AtomicUsize>);
+
+    impl EventListener<AnotherEvent> for CountingListener {
+        fn on_event(&self, _event: &AnotherEvent) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    struct CountingContextListener(Arc<AtomicUsize>);
+
+    impl ContextAwareEventListener<AnotherEvent> for CountingContextListener {
+        fn on_context_event(
+            &self,
+            _event: &AnotherEvent,
+            _context: &crate::context::ApplicationContext,
+        ) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    #[test]
+    fn test_clear_listeners_removes_context_aware_listeners() {
+        let context = crate::context::ApplicationContext::new();
+        let count = Arc::new(AtomicUsize::new(0));
+        let mut publisher = EventPublisher::new();
+        publisher.subscribe(CountingListener(count.clone()));
+        publisher.subscribe_context_aware(CountingContextListener(count.clone()));
+        assert_eq!(
+            publisher.publish_with_context(&AnotherEvent { value: 1 }, &context),
+            2
+        );
+
+        publisher.clear_all_listeners();
+        assert_eq!(
+            publisher.publish_with_context(&AnotherEvent { value: 1 }, &context),
+            0
+        );
+        assert_eq!(count.load(Ordering::SeqCst), 2);
+    }
+
+    struct SubscribingListener {
+        publisher: Arc<EventPublisher>,
+        count: Arc<AtomicUsize>,
+    }
+
+    impl EventListener<AnotherEvent> for SubscribingListener {
+        fn on_event(&self, _event: &AnotherEvent) {
+            self.publisher
+                .subscribe(CountingListener(self.count.clone()));
+        }
+    }
+
+    #[test]
+    fn test_listener_can_subscribe_during_publish() {
+        let publisher = Arc::new(EventPublisher::new());
+        let count = Arc::new(AtomicUsize::new(0));
+        publisher.subscribe(SubscribingListener {
+            publisher: publisher.clone(),
+            count: count.clone(),
+        });
+
+        // The listener added during dispatch only sees later events
+        assert_eq!(publisher.publish(&AnotherEvent { value: 1 }), 1);
+        assert_eq!(count.load(Ordering::SeqCst), 0);
+        assert_eq!(publisher.listener_count::<AnotherEvent>(), 2);
+
+        assert_eq!(publisher.publish(&AnotherEvent { value: 2 }), 2);
+        assert_eq!(count.load(Ordering::SeqCst), 1);
+        assert_eq!(publisher.listener_count::<AnotherEvent>(), 3);
+    }
+
     #[test]
     fn test_listener_statistics() {
         let publisher = EventPublisher::new();
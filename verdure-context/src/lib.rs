@@ -253,7 +253,7 @@
 //!
 //! **When**: Fired after the context is fully initialized, including all configuration sources, profiles, and IoC container.  
 //! **Purpose**: Ideal for application startup tasks that require a fully configured context.  
-//! **Data**: Final configuration sources count, active profiles count, and timestamp.
+//! **Data**: Final configuration sources count, active profiles count, timestamp, and the monotonic duration of the initialization.
 //!
 //! ### ProfileActivatedEvent
 //!
@@ -363,15 +363,71 @@
 
 pub mod config;
 pub mod context;
+pub mod environment;
 pub mod error;
 pub mod event;
+mod wildcard;
+
+#[cfg(test)]
+mod test_env;
 
 // Re-export main types for convenience
 pub use config::{ConfigManager, ConfigSource, ConfigValue};
-pub use context::{ApplicationContext, ApplicationContextBuilder};
-pub use error::{ContextError, ContextResult};
+pub use context::{ApplicationContext, ApplicationContextBuilder, ApplicationContextRef};
+pub use environment::Environment;
+pub use error::{ContextError, ContextResult, ErrorSource};
 pub use event::{
     AnyContextAwareEventListener, AnyEventListener, ConfigurationChangedEvent,
-    ContextAwareEventListener, ContextInitializedEvent, ContextInitializingEvent, Event,
-    EventListener, EventPublisher,
+    ContextAwareEventListener, ContextInitializedEvent, ContextInitializingEvent,
+    ContextRefreshedEvent, Event, EventListener, EventPublisher,
 };
+
+/// Macro for registering application context event listeners
+///
+/// The listener is subscribed to every `ApplicationContext` when it is created. Prefix
+/// the listener with `context_aware` for a [`ContextAwareEventListener`].
+///
+/// # Arguments
+///
+/// * `$name` - A string literal identifying the listener
+/// * `$listener` - An expression creating the listener
+///
+/// # Examples
+///
+/// ```rust
+/// use verdure_context::{
+///     context_event_listener, ApplicationContext, ContextAwareEventListener,
+///     ContextInitializedEvent,
+/// };
+///
+/// struct StartupBanner;
+///
+/// impl ContextAwareEventListener<ContextInitializedEvent> for StartupBanner {
+///     fn on_context_event(&self, _event: &ContextInitializedEvent, context: &ApplicationContext) {
+///         println!("Starting {}", context.get_config("app.name"));
+///     }
+/// }
+///
+/// context_event_listener!("startup_banner", context_aware StartupBanner);
+/// ```
+#[macro_export]
+macro_rules! context_event_listener {
+    ($name:expr, context_aware $listener:expr) => {
+        inventory::submit! {
+            $crate::event::ContextListenerDefinition {
+                name: $name,
+                subscribe: |publisher: &$crate::EventPublisher| {
+                    publisher.subscribe_context_aware($listener)
+                },
+            }
+        }
+    };
+    ($name:expr, $listener:expr) => {
+        inventory::submit! {
+            $crate::event::ContextListenerDefinition {
+                name: $name,
+                subscribe: |publisher: &$crate::EventPublisher| publisher.subscribe($listener),
+            }
+        }
+    };
+}
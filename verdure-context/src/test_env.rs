@@ -0,0 +1,56 @@
+//! Environment variables for tests
+//!
+//! Tests run on parallel threads of one process, so every test that modifies the
+//! environment goes through [`EnvVars`], which serializes them and cleans up afterwards.
+
+use parking_lot::{Mutex, MutexGuard};
+
+/// Held by every test while it modifies the environment
+static ENV_LOCK: Mutex<()> = Mutex::new(());
+
+/// Environment variables set for the duration of a test
+///
+/// The variables are removed when the value is dropped, even if the test panics.
+pub(crate) struct EnvVars {
+    names: Vec<&'static str>,
+    _lock: MutexGuard<'static, ()>,
+}
+
+impl EnvVars {
+    /// Waits for other tests modifying the environment, then sets `vars`
+    pub(crate) fn set(vars: &[(&'static str, &str)]) -> Self {
+        let mut env = Self {
+            names: Vec::new(),
+            _lock: ENV_LOCK.lock(),
+        };
+        for (name, value) in vars {
+            env.set_var(name, value);
+        }
+        env
+    }
+
+    /// Sets another variable, or changes one that is already set
+    pub(crate) fn set_var(&mut self, name: &'static str, value: &str) {
+        // SAFETY: `ENV_LOCK` is held, so no other test modifies the environment, and
+        // the standard library synchronizes its own reads; nothing in this crate reads
+        // the environment through libc directly.
+        unsafe {
+            std::env::set_var(name, value);
+        }
+        if !self.names.contains(&name) {
+            self.names.push(name);
+        }
+    }
+}
+
+impl Drop for EnvVars {
+    fn drop(&mut self) {
+        for name in &self.names {
+            // SAFETY: as in `set_var`, the lock is released only after the variables are
+            // removed.
+            unsafe {
+                std::env::remove_var(name);
+            }
+        }
+    }
+}
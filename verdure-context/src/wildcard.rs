@@ -0,0 +1,55 @@
+//! Wildcard matching for configuration key and file name patterns
+
+/// Matches `text` against a pattern in which `*` stands for any sequence of characters
+/// and `?` for exactly one character
+///
+/// The whole of `text` must match. Every other character, including `.`, matches only
+/// itself.
+pub(crate) fn matches(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    let (mut p, mut t) = (0, 0);
+    // Position of the last `*` in the pattern and of the text it currently stands for
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while t < text.len() {
+        if p < pattern.len() && pattern[p] == '*' {
+            backtrack = Some((p, t));
+            p += 1;
+        } else if p < pattern.len() && (pattern[p] == '?' || pattern[p] == text[t]) {
+            p += 1;
+            t += 1;
+        } else if let Some((star, matched)) = backtrack {
+            // Let the last `*` absorb one more character of the text
+            p = star + 1;
+            t = matched + 1;
+            backtrack = Some((star, matched + 1));
+        } else {
+            return false;
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches() {
+        assert!(matches("feature.*", "feature.search"));
+        assert!(matches("feature.*", "feature.search.beta"));
+        assert!(matches("*.enabled", "cache.enabled"));
+        assert!(matches("app.name", "app.name"));
+        assert!(matches("a*b*c", "aXbYbZc"));
+        assert!(matches("*", ""));
+        assert!(matches("??-*.toml", "10-db.toml"));
+        assert!(matches("é?", "éa"));
+        assert!(!matches("feature.*", "features.search"));
+        assert!(!matches("feature.*", "feature"));
+        assert!(!matches("app.name", "app.names"));
+        assert!(!matches("*.enabled", "cache.enabled.flag"));
+        assert!(!matches("??-*.toml", "1-db.toml"));
+        assert!(!matches("?", ""));
+    }
+}
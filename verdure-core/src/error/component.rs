@@ -86,7 +86,7 @@ mod tests {
 
     #[test]
     fn test_component_error_display() {
-        let errors = vec![
+        let errors = [
             ComponentError::DependencyNotFound("TestDep".to_string()),
             ComponentError::DowncastFailed("TestComponent".to_string()),
             ComponentError::CircularDependency("A -> B -> A".to_string()),
@@ -95,7 +95,7 @@ mod tests {
             ComponentError::NotFound("ComponentX".to_string()),
         ];
 
-        let expected_messages = vec![
+        let expected_messages = [
             "Dependency 'TestDep' not found",
             "Failed to downcast dependency 'TestComponent'",
             "Circular dependency detected: A -> B -> A",
@@ -33,7 +33,7 @@ pub struct ContainerError {
 /// This enum categorizes the various types of errors that can occur during
 /// container operations, making it easier to handle different error conditions
 /// appropriately.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ContainerErrorKind {
     /// A component or dependency was not found
     NotFound,
@@ -240,7 +240,7 @@ mod tests {
 
     #[test]
     fn test_all_error_kinds() {
-        let errors = vec![
+        let errors = [
             ContainerError::not_found("not found"),
             ContainerError::circular_dependency("circular"),
             ContainerError::creation_failed("creation failed"),
@@ -249,7 +249,7 @@ mod tests {
             ContainerError::other("other error"),
         ];
 
-        let expected_kinds = vec![
+        let expected_kinds = [
             ContainerErrorKind::NotFound,
             ContainerErrorKind::CircularDependency,
             ContainerErrorKind::CreationFailed,
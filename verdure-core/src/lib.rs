@@ -1,6 +1,8 @@
 //! Verdure Core Library
 //!
 //! This crate provides the core error handling types and utilities for the Verdure IoC framework.
-//! It defines the fundamental error types used throughout the framework for consistent error handling.
+//! It defines the fundamental error types used throughout the framework for consistent error handling,
+//! as well as the dependency-free logging hook shared by the other crates.
 
 pub mod error;
+pub mod logging;
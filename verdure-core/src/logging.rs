@@ -0,0 +1,86 @@
+//! Pluggable logging hook for Verdure framework internals
+//!
+//! The framework does not depend on any logging crate. Instead, components such as the
+//! container and the application context accept a [`Logger`] callback which receives a
+//! [`LogLevel`] and a message, so framework events can be routed into any logging stack.
+
+use std::fmt;
+use std::sync::Arc;
+
+/// Severity of a framework log message
+///
+/// # Examples
+///
+/// ```rust
+/// use verdure_core::logging::LogLevel;
+///
+/// assert!(LogLevel::Warn > LogLevel::Info);
+/// assert_eq!(LogLevel::Debug.to_string(), "DEBUG");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LogLevel {
+    /// Very detailed diagnostic information
+    Trace,
+    /// Diagnostic information, such as individual components being created
+    Debug,
+    /// Lifecycle milestones, such as initialization starting and completing
+    Info,
+    /// Unexpected but recoverable situations, such as slow components
+    Warn,
+    /// Failures
+    Error,
+}
+
+impl fmt::Display for LogLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LogLevel::Trace => write!(f, "TRACE"),
+            LogLevel::Debug => write!(f, "DEBUG"),
+            LogLevel::Info => write!(f, "INFO"),
+            LogLevel::Warn => write!(f, "WARN"),
+            LogLevel::Error => write!(f, "ERROR"),
+        }
+    }
+}
+
+/// Callback that receives framework log messages
+///
+/// # Examples
+///
+/// ```rust
+/// use verdure_core::logging::{LogLevel, Logger};
+/// use std::sync::Arc;
+///
+/// let logger: Logger = Arc::new(|level: LogLevel, message: &str| {
+///     eprintln!("[{}] {}", level, message);
+/// });
+/// logger(LogLevel::Info, "container initialized");
+/// ```
+pub type Logger = Arc<dyn Fn(LogLevel, &str) + Send + Sync>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    #[test]
+    fn test_log_level_ordering() {
+        assert!(LogLevel::Trace < LogLevel::Debug);
+        assert!(LogLevel::Debug < LogLevel::Info);
+        assert!(LogLevel::Info < LogLevel::Warn);
+        assert!(LogLevel::Warn < LogLevel::Error);
+    }
+
+    #[test]
+    fn test_logger_callback() {
+        let messages = Arc::new(Mutex::new(Vec::new()));
+        let sink = messages.clone();
+        let logger: Logger = Arc::new(move |level, message| {
+            sink.lock().unwrap().push(format!("{} {}", level, message));
+        });
+
+        logger(LogLevel::Warn, "slow component");
+
+        assert_eq!(*messages.lock().unwrap(), vec!["WARN slow component"]);
+    }
+}
@@ -8,8 +8,11 @@ pub mod factory;
 
 use std::any::{Any, TypeId};
 use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
 use std::sync::Arc;
 use verdure_core::error::component::ComponentError;
+use verdure_core::error::container::ContainerError;
 
 /// Type alias for component instances stored in the container
 ///
@@ -25,12 +28,117 @@ pub type ComponentInstance = Arc<dyn Any + Send + Sync>;
 ///
 /// * `Singleton` - Only one instance of the component exists throughout the application lifecycle
 /// * `Prototype` - A new instance is created each time the component is requested
+/// * `Request` - One instance exists per [`ScopeContext`](crate::ScopeContext), dropped with it
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ComponentScope {
     /// Single shared instance across the entire application
     Singleton,
     /// New instance created on each request
     Prototype,
+    /// Single instance per scope opened with `ComponentContainer::open_scope`
+    Request,
+}
+
+impl ComponentScope {
+    /// Returns the scope called `name`, ignoring ASCII case
+    ///
+    /// This is the single mapping of scope names, used by `FromStr` and evaluated at
+    /// compile time for `#[component(scope = "...")]`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use verdure_ioc::ComponentScope;
+    ///
+    /// assert_eq!(ComponentScope::from_name("Request"), Some(ComponentScope::Request));
+    /// assert_eq!(ComponentScope::from_name("session"), None);
+    /// ```
+    pub const fn from_name(name: &str) -> Option<Self> {
+        if eq_ignore_ascii_case(name, "singleton") {
+            Some(ComponentScope::Singleton)
+        } else if eq_ignore_ascii_case(name, "prototype") {
+            Some(ComponentScope::Prototype)
+        } else if eq_ignore_ascii_case(name, "request") {
+            Some(ComponentScope::Request)
+        } else {
+            None
+        }
+    }
+}
+
+/// Compares two strings ignoring ASCII case, usable in constant evaluation
+const fn eq_ignore_ascii_case(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if !a[i].eq_ignore_ascii_case(&b[i]) {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+impl fmt::Display for ComponentScope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ComponentScope::Singleton => "singleton",
+            ComponentScope::Prototype => "prototype",
+            ComponentScope::Request => "request",
+        })
+    }
+}
+
+/// Parses a scope name, ignoring case
+///
+/// Delegates to [`ComponentScope::from_name`], the mapping also used by
+/// `#[component(scope = "...")]`, so configuration-driven scope selection accepts the
+/// same names.
+///
+/// # Examples
+///
+/// ```rust
+/// use verdure_ioc::ComponentScope;
+///
+/// let scope: ComponentScope = "Prototype".parse().unwrap();
+/// assert_eq!(scope, ComponentScope::Prototype);
+/// assert_eq!(scope.to_string(), "prototype");
+/// assert!("session".parse::<ComponentScope>().is_err());
+/// ```
+impl FromStr for ComponentScope {
+    type Err = ContainerError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ComponentScope::from_name(s).ok_or_else(|| {
+            ContainerError::configuration(format!(
+                "Unknown component scope '{}', expected singleton, prototype or request",
+                s
+            ))
+        })
+    }
+}
+
+/// Source location where a component definition was declared
+///
+/// The `#[derive(Component)]` macro records the module, file and line of the annotated
+/// type so that wiring errors can point at the crate a component came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ComponentOrigin {
+    /// Module path of the declaration, as given by `module_path!()`
+    pub module_path: &'static str,
+    /// Source file of the declaration, as given by `file!()`
+    pub file: &'static str,
+    /// Line of the declaration, as given by `line!()`
+    pub line: u32,
+}
+
+impl fmt::Display for ComponentOrigin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at {}:{}", self.module_path, self.file, self.line)
+    }
 }
 
 /// Definition structure for registering components with the container
@@ -52,13 +160,14 @@ pub enum ComponentScope {
 ///     value: i32,
 /// }
 ///
-/// let definition = ComponentDefinition {
-///     type_id: || TypeId::of::<MyService>(),
-///     type_name: "MyService",
-///     scope: || ComponentScope::Singleton,
-///     dependencies: || vec![],
-///     creator: |_deps| Ok(Arc::new(MyService { value: 42 })),
-/// };
+/// let definition = ComponentDefinition::new(
+///     || TypeId::of::<MyService>(),
+///     "MyService",
+///     || ComponentScope::Singleton,
+///     || vec![],
+///     |_deps| Ok(Arc::new(MyService { value: 42 })),
+/// );
+/// assert_eq!((definition.order)(), 0);
 /// ```
 #[derive(Debug)]
 pub struct ComponentDefinition {
@@ -73,6 +182,107 @@ pub struct ComponentDefinition {
     /// Function that creates an instance of the component given its dependencies
     pub creator:
         fn(deps: HashMap<TypeId, ComponentInstance>) -> Result<ComponentInstance, ComponentError>,
+    /// Function that returns the component's initialization order
+    ///
+    /// Independent components are created in ascending order; dependencies are always
+    /// created before the components that need them, regardless of their order.
+    pub order: fn() -> i32,
+    /// Function that returns whether the component is the primary implementation
+    ///
+    /// When several implementations are registered for the same trait object, the one
+    /// that is this component's instance is injected without a qualifier. Set with
+    /// `#[component(primary)]`.
+    pub primary: fn() -> bool,
+    /// Where the component was declared, if known
+    pub origin: Option<ComponentOrigin>,
+}
+
+impl ComponentDefinition {
+    /// Creates a definition with order `0`, not primary and without an origin
+    ///
+    /// Use [`with_order`](Self::with_order), [`with_primary`](Self::with_primary) and
+    /// [`with_origin`](Self::with_origin) to set the remaining fields. The constructor is
+    /// `const`, so it can be used in statics and `inventory::submit!`.
+    pub const fn new(
+        type_id: fn() -> TypeId,
+        type_name: &'static str,
+        scope: fn() -> ComponentScope,
+        dependencies: fn() -> Vec<TypeId>,
+        creator: fn(
+            deps: HashMap<TypeId, ComponentInstance>,
+        ) -> Result<ComponentInstance, ComponentError>,
+    ) -> Self {
+        Self {
+            type_id,
+            type_name,
+            scope,
+            dependencies,
+            creator,
+            order: default_order,
+            primary: not_primary,
+            origin: None,
+        }
+    }
+
+    /// Sets the function returning the component's initialization order
+    pub const fn with_order(mut self, order: fn() -> i32) -> Self {
+        self.order = order;
+        self
+    }
+
+    /// Sets the function returning whether the component is the primary implementation
+    pub const fn with_primary(mut self, primary: fn() -> bool) -> Self {
+        self.primary = primary;
+        self
+    }
+
+    /// Sets where the component was declared
+    pub const fn with_origin(mut self, origin: ComponentOrigin) -> Self {
+        self.origin = Some(origin);
+        self
+    }
+
+    /// Describes the component for error messages, including its origin when known
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use verdure_ioc::{ComponentDefinition, ComponentOrigin, ComponentScope};
+    /// use std::any::TypeId;
+    /// use std::sync::Arc;
+    ///
+    /// let definition = ComponentDefinition::new(
+    ///     || TypeId::of::<u32>(),
+    ///     "Counter",
+    ///     || ComponentScope::Singleton,
+    ///     || vec![],
+    ///     |_deps| Ok(Arc::new(0u32)),
+    /// )
+    /// .with_origin(ComponentOrigin {
+    ///     module_path: "my_app::counter",
+    ///     file: "src/counter.rs",
+    ///     line: 12,
+    /// });
+    ///
+    /// assert_eq!(
+    ///     definition.describe(),
+    ///     "'Counter' (declared in my_app::counter at src/counter.rs:12)"
+    /// );
+    /// ```
+    pub fn describe(&self) -> String {
+        match &self.origin {
+            Some(origin) => format!("'{}' (declared in {})", self.type_name, origin),
+            None => format!("'{}'", self.type_name),
+        }
+    }
+}
+
+fn default_order() -> i32 {
+    0
+}
+
+fn not_primary() -> bool {
+    false
 }
 
 inventory::collect!(ComponentDefinition);
@@ -173,15 +383,27 @@ mod tests {
 
     #[test]
     fn test_component_scope() {
-        match ComponentScope::Singleton {
-            ComponentScope::Singleton => assert!(true),
-            ComponentScope::Prototype => assert!(false),
-        }
+        assert!(matches!(ComponentScope::Singleton, ComponentScope::Singleton));
+        assert!(matches!(ComponentScope::Prototype, ComponentScope::Prototype));
+        assert_ne!(ComponentScope::Singleton, ComponentScope::Prototype);
+    }
 
-        match ComponentScope::Prototype {
-            ComponentScope::Singleton => assert!(false),
-            ComponentScope::Prototype => assert!(true),
+    #[test]
+    fn test_component_scope_from_str_and_display() {
+        for scope in [
+            ComponentScope::Singleton,
+            ComponentScope::Prototype,
+            ComponentScope::Request,
+        ] {
+            assert_eq!(scope.to_string().parse::<ComponentScope>().unwrap(), scope);
         }
+        assert_eq!(
+            "SINGLETON".parse::<ComponentScope>().unwrap(),
+            ComponentScope::Singleton
+        );
+
+        let error = "Session".parse::<ComponentScope>().unwrap_err();
+        assert!(error.to_string().contains("'Session'"));
     }
 
     #[test]
@@ -189,10 +411,7 @@ mod tests {
         let component = SimpleComponent::__new(());
         assert_eq!(component.value, 42);
 
-        match SimpleComponent::__scope() {
-            ComponentScope::Singleton => assert!(true),
-            ComponentScope::Prototype => assert!(false),
-        }
+        assert_eq!(SimpleComponent::__scope(), ComponentScope::Singleton);
     }
 
     #[test]
@@ -204,10 +423,7 @@ mod tests {
         assert_eq!(component.simple.value, 100);
         assert_eq!(component.message, "Hello");
 
-        match ComponentWithDependencies::__scope() {
-            ComponentScope::Singleton => assert!(false),
-            ComponentScope::Prototype => assert!(true),
-        }
+        assert_eq!(ComponentWithDependencies::__scope(), ComponentScope::Prototype);
     }
 
     #[test]
@@ -222,13 +438,13 @@ mod tests {
             Ok(Arc::new(instance) as ComponentInstance)
         };
 
-        let definition = ComponentDefinition {
-            type_id: type_id_fn,
+        let definition = ComponentDefinition::new(
+            type_id_fn,
             type_name,
-            scope: scope_fn,
-            dependencies: dependencies_fn,
-            creator: creator_fn,
-        };
+            scope_fn,
+            dependencies_fn,
+            creator_fn,
+        );
 
         assert_eq!((definition.type_id)(), TypeId::of::<SimpleComponent>());
         assert_eq!(definition.type_name, "SimpleComponent");
@@ -276,13 +492,13 @@ mod tests {
             Ok(Arc::new(instance) as ComponentInstance)
         };
 
-        let definition = ComponentDefinition {
-            type_id: type_id_fn,
+        let definition = ComponentDefinition::new(
+            type_id_fn,
             type_name,
-            scope: scope_fn,
-            dependencies: dependencies_fn,
-            creator: creator_fn,
-        };
+            scope_fn,
+            dependencies_fn,
+            creator_fn,
+        );
 
         assert_eq!(
             (definition.type_id)(),
@@ -335,4 +551,26 @@ mod tests {
             ));
         }
     }
+
+    #[test]
+    fn test_component_definition_describe() {
+        let mut definition = ComponentDefinition::new(
+            TypeId::of::<SimpleComponent>,
+            "SimpleComponent",
+            || ComponentScope::Singleton,
+            Vec::new,
+            |_| Ok(Arc::new(SimpleComponent { value: 1 }) as ComponentInstance),
+        );
+        assert_eq!(definition.describe(), "'SimpleComponent'");
+
+        definition.origin = Some(ComponentOrigin {
+            module_path: "app::simple",
+            file: "src/simple.rs",
+            line: 7,
+        });
+        assert_eq!(
+            definition.describe(),
+            "'SimpleComponent' (declared in app::simple at src/simple.rs:7)"
+        );
+    }
 }
@@ -5,14 +5,101 @@
 //! component lifecycles, resolves dependencies, and provides the runtime infrastructure
 //! that enables Verdure's declarative programming model.
 
-use crate::event::{ContainerLifecycleEvent, LifecycleEventPublisher};
+use crate::event::{
+    ContainerLifecycleEvent, EventHistory, LifecycleEventPublisher, LifecycleEventRecord,
+};
+use crate::scope::ScopeContext;
 use crate::{ComponentDefinition, ComponentFactory, ComponentInstance, ComponentScope};
+use dashmap::mapref::entry::Entry;
 use dashmap::{DashMap, DashSet};
 use std::any::{Any, TypeId};
 use std::collections::HashMap;
-use std::sync::Arc;
-use std::time::Instant;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Condvar, Mutex, OnceLock, RwLock};
+use std::thread::ThreadId;
+use std::time::{Duration, Instant};
 use verdure_core::error::container::ContainerError;
+use verdure_core::logging::{LogLevel, Logger};
+
+/// Factory registered with [`ComponentContainer::register_lazy`], with the component's
+/// type name
+#[derive(Clone)]
+struct LazyFactory {
+    type_name: &'static str,
+    create: Arc<dyn Fn() -> ComponentInstance + Send + Sync>,
+}
+
+/// Callback decorating a component right after the container creates it
+type PostProcessor = Arc<dyn Fn(TypeId, ComponentInstance) -> ComponentInstance + Send + Sync>;
+
+/// Serializes the creation of components from their definitions across threads
+///
+/// The owning thread may lock it again, which happens when a lifecycle listener or
+/// post processor resolves further components while one is being created.
+#[derive(Default)]
+struct CreationLock {
+    /// The thread holding the lock and how many times it has locked it
+    owner: Mutex<Option<(ThreadId, usize)>>,
+    released: Condvar,
+}
+
+impl CreationLock {
+    fn lock(&self) -> CreationGuard<'_> {
+        let current = std::thread::current().id();
+        let mut owner = self.owner.lock().unwrap_or_else(|e| e.into_inner());
+        while let Some((thread, _)) = *owner
+            && thread != current
+        {
+            owner = self.released.wait(owner).unwrap_or_else(|e| e.into_inner());
+        }
+        match owner.as_mut() {
+            Some((_, depth)) => *depth += 1,
+            None => *owner = Some((current, 1)),
+        }
+        CreationGuard { lock: self }
+    }
+}
+
+struct CreationGuard<'a> {
+    lock: &'a CreationLock,
+}
+
+impl Drop for CreationGuard<'_> {
+    fn drop(&mut self) {
+        let mut owner = self.lock.owner.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some((_, depth)) = owner.as_mut() {
+            *depth -= 1;
+            if *depth == 0 {
+                *owner = None;
+                self.lock.released.notify_all();
+            }
+        }
+    }
+}
+
+/// Marks a type as being resolved until dropped, for circular dependency detection
+///
+/// Removing the mark on drop keeps the set clean on every error path, including
+/// errors propagated from nested resolutions, so a failed `initialize` can be retried.
+pub(crate) struct ResolvingGuard<'a> {
+    resolving: &'a DashSet<TypeId>,
+    type_id: TypeId,
+}
+
+impl<'a> ResolvingGuard<'a> {
+    /// Marks `type_id`, returning `None` if it is already being resolved
+    pub(crate) fn enter(resolving: &'a DashSet<TypeId>, type_id: TypeId) -> Option<Self> {
+        resolving
+            .insert(type_id)
+            .then_some(Self { resolving, type_id })
+    }
+}
+
+impl Drop for ResolvingGuard<'_> {
+    fn drop(&mut self) {
+        self.resolving.remove(&self.type_id);
+    }
+}
 
 /// Component descriptor for identifying components in the container
 ///
@@ -96,6 +183,88 @@ pub struct ComponentStats {
     pub creation_time: u64,
 }
 
+/// The implementations registered for one trait object type
+struct TraitImplementations {
+    /// The type name of the trait object, used in error messages
+    trait_name: &'static str,
+    /// The qualifiers of the registered implementations, in registration order
+    qualifiers: Vec<&'static str>,
+    /// Whether each implementation's type is a primary component, in the same order as
+    /// `qualifiers`
+    primary: Vec<bool>,
+}
+
+/// Names a component by its registered type name, or by its `TypeId` without a definition
+fn component_name(
+    definitions: &HashMap<TypeId, &'static ComponentDefinition>,
+    type_id: TypeId,
+) -> String {
+    match definitions.get(&type_id) {
+        Some(def) => def.type_name.to_string(),
+        None => format!("{:?}", type_id),
+    }
+}
+
+/// The process-wide container returned by [`ComponentContainer::global`]
+static GLOBAL_CONTAINER: OnceLock<Arc<ComponentContainer>> = OnceLock::new();
+
+/// Returns all component definitions registered via `inventory`, keyed by type
+pub(crate) fn registered_definitions() -> &'static HashMap<TypeId, &'static ComponentDefinition> {
+    static DEFINITIONS: OnceLock<HashMap<TypeId, &'static ComponentDefinition>> = OnceLock::new();
+    DEFINITIONS.get_or_init(|| {
+        inventory::iter::<ComponentDefinition>
+            .into_iter()
+            .map(|def| ((def.type_id)(), def))
+            .collect()
+    })
+}
+
+/// Returns pairs of `inventory` definitions registered for the same type
+fn duplicate_definitions() -> &'static [(&'static ComponentDefinition, &'static ComponentDefinition)]
+{
+    static DUPLICATES: OnceLock<Vec<(&'static ComponentDefinition, &'static ComponentDefinition)>> =
+        OnceLock::new();
+    DUPLICATES.get_or_init(|| find_duplicates(inventory::iter::<ComponentDefinition>))
+}
+
+fn find_duplicates<'a>(
+    definitions: impl IntoIterator<Item = &'a ComponentDefinition>,
+) -> Vec<(&'a ComponentDefinition, &'a ComponentDefinition)> {
+    let mut seen: HashMap<TypeId, &'a ComponentDefinition> = HashMap::new();
+    let mut duplicates = Vec::new();
+    for def in definitions {
+        if let Some(first) = seen.insert((def.type_id)(), def) {
+            duplicates.push((first, def));
+        }
+    }
+    duplicates
+}
+
+fn duplicate_definition_message(
+    first: &ComponentDefinition,
+    second: &ComponentDefinition,
+) -> String {
+    let location = |def: &ComponentDefinition| match &def.origin {
+        Some(origin) => origin.to_string(),
+        None => "a manual definition".to_string(),
+    };
+    format!(
+        "Component '{}' is defined more than once ({} and {})",
+        first.type_name,
+        location(first),
+        location(second)
+    )
+}
+
+/// Sorts definitions by ascending order, then by type name for a stable startup order
+fn initialization_order<'a>(
+    definitions: impl IntoIterator<Item = &'a ComponentDefinition>,
+) -> Vec<&'a ComponentDefinition> {
+    let mut ordered: Vec<_> = definitions.into_iter().collect();
+    ordered.sort_by_key(|def| ((def.order)(), def.type_name));
+    ordered
+}
+
 /// The central IoC container for the Verdure ecosystem
 ///
 /// `ComponentContainer` serves as the heart of the Verdure ecosystem's dependency injection system.
@@ -108,7 +277,8 @@ pub struct ComponentStats {
 ///
 /// # Features
 ///
-/// * **Thread-safe**: Uses concurrent data structures for safe multi-threaded access
+/// * **Thread-safe**: Uses concurrent data structures for safe multi-threaded access, and
+///   creates each singleton exactly once even when several threads request it at the same time
 /// * **Dependency Resolution**: Automatically resolves and injects component dependencies
 /// * **Circular Dependency Detection**: Prevents infinite dependency loops during resolution
 /// * **Lifecycle Events**: Publishes events during container and component lifecycle operations
@@ -145,12 +315,44 @@ pub struct ComponentStats {
 pub struct ComponentContainer {
     /// Map of component descriptors to their instances
     components: DashMap<ComponentDescriptor, ComponentInstance>,
+    /// Map of alias descriptors to the descriptors they redirect to
+    aliases: DashMap<ComponentDescriptor, ComponentDescriptor>,
+    /// Held while an alias is checked and inserted, so concurrent registrations cannot form a cycle
+    alias_lock: Mutex<()>,
+    /// Implementations registered for each trait object type, keyed by `TypeId::of::<Arc<dyn Trait>>()`
+    trait_implementations: DashMap<TypeId, TraitImplementations>,
     /// Set tracking which components are currently being initialized (for circular dependency detection)
     initializing: DashSet<TypeId>,
     /// Statistics for each component
     stats: DashMap<ComponentDescriptor, ComponentStats>,
     /// Event publisher for lifecycle events
     lifecycle_publisher: Arc<LifecycleEventPublisher>,
+    /// Whether `initialize` has already run (or is running)
+    initialized: AtomicBool,
+    /// Held for the whole of `initialize`, with the count of the last successful run
+    initialization: Mutex<Option<usize>>,
+    /// Duration and resulting component count of the last successful initialization
+    last_initialization: RwLock<Option<(Duration, usize)>>,
+    /// Creation time above which a `SlowComponentCreation` event is published
+    slow_component_threshold: Option<Duration>,
+    /// Optional sink for framework log messages
+    logger: RwLock<Option<Logger>>,
+    /// Whether component definitions registered via `inventory` are used
+    discover_components: bool,
+    /// Definitions used instead of `inventory`, see [`IsolatedContainer`](crate::IsolatedContainer)
+    explicit_definitions: Option<HashMap<TypeId, &'static ComponentDefinition>>,
+    /// Callbacks applied to every created component, in registration order
+    post_processors: RwLock<Vec<PostProcessor>>,
+    /// Held while components are created from definitions, so each singleton is built once
+    creation_lock: CreationLock,
+    /// Factories of lazily registered components that have not been requested yet
+    lazy_factories: DashMap<ComponentDescriptor, LazyFactory>,
+    /// Number of `ComponentCreated` events published, for any scope
+    creation_events: AtomicUsize,
+    /// Number of `ComponentCreated` events published for singletons created from definitions
+    singleton_creation_events: AtomicUsize,
+    /// Records of the most recent lifecycle events, if enabled
+    event_history: Option<EventHistory>,
 }
 
 impl ComponentContainer {
@@ -166,21 +368,303 @@ impl ComponentContainer {
     pub fn new() -> Self {
         Self {
             components: DashMap::new(),
+            aliases: DashMap::new(),
+            alias_lock: Mutex::new(()),
+            trait_implementations: DashMap::new(),
             initializing: DashSet::new(),
             stats: DashMap::new(),
             lifecycle_publisher: Arc::new(LifecycleEventPublisher::new()),
+            initialized: AtomicBool::new(false),
+            initialization: Mutex::new(None),
+            last_initialization: RwLock::new(None),
+            slow_component_threshold: None,
+            logger: RwLock::new(None),
+            discover_components: true,
+            explicit_definitions: None,
+            post_processors: RwLock::new(Vec::new()),
+            creation_lock: CreationLock::default(),
+            lazy_factories: DashMap::new(),
+            creation_events: AtomicUsize::new(0),
+            singleton_creation_events: AtomicUsize::new(0),
+            event_history: None,
+        }
+    }
+
+    /// Creates an empty container that ignores `inventory` component definitions
+    ///
+    /// `inventory` registrations are global to the binary, so every
+    /// `#[derive(Component)]` would otherwise be created by [`initialize`](Self::initialize).
+    /// A test container only holds what is registered on it explicitly, which lets
+    /// focused tests wire up just the mocks they need.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use verdure_ioc::{ComponentContainer, ComponentFactory};
+    /// use std::sync::Arc;
+    ///
+    /// struct MockRepository;
+    ///
+    /// let container = ComponentContainer::test();
+    /// container.register_component(Arc::new(MockRepository));
+    /// container.initialize().unwrap();
+    ///
+    /// assert_eq!(container.component_count(), 1);
+    /// assert!(container.get_component::<MockRepository>().is_some());
+    /// ```
+    pub fn test() -> Self {
+        Self {
+            discover_components: false,
+            ..Self::new()
+        }
+    }
+
+    /// Creates a container that resolves components from `definitions` only
+    ///
+    /// Fails if two definitions are given for the same type.
+    #[cfg(any(test, feature = "test-support"))]
+    pub(crate) fn with_definitions(
+        definitions: Vec<&'static ComponentDefinition>,
+    ) -> Result<Self, ContainerError> {
+        if let Some((first, second)) = find_duplicates(definitions.iter().copied()).first() {
+            return Err(ContainerError::configuration(duplicate_definition_message(
+                first, second,
+            )));
+        }
+        Ok(Self {
+            discover_components: false,
+            explicit_definitions: Some(
+                definitions
+                    .into_iter()
+                    .map(|def| ((def.type_id)(), def))
+                    .collect(),
+            ),
+            ..Self::new()
+        })
+    }
+
+    /// Returns the component definitions this container resolves components from
+    pub(crate) fn definitions(&self) -> &HashMap<TypeId, &'static ComponentDefinition> {
+        static NO_DEFINITIONS: OnceLock<HashMap<TypeId, &'static ComponentDefinition>> =
+            OnceLock::new();
+        if let Some(definitions) = &self.explicit_definitions {
+            definitions
+        } else if self.discover_components {
+            registered_definitions()
+        } else {
+            NO_DEFINITIONS.get_or_init(HashMap::new)
+        }
+    }
+
+    /// Sets the sink that receives the container's log messages
+    ///
+    /// The container logs initialization start and completion at `Info`, each created
+    /// component at `Debug`, slow components and an empty set of discovered components
+    /// at `Warn`, and initialization failures at `Error`. No messages are produced while
+    /// no logger is set.
+    ///
+    /// # Arguments
+    ///
+    /// * `logger` - Callback receiving the level and message
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use verdure_ioc::ComponentContainer;
+    /// use verdure_core::logging::LogLevel;
+    ///
+    /// let container = ComponentContainer::new();
+    /// container.set_logger(|level: LogLevel, message: &str| {
+    ///     eprintln!("[{}] {}", level, message);
+    /// });
+    /// ```
+    pub fn set_logger<F>(&self, logger: F)
+    where
+        F: Fn(LogLevel, &str) + Send + Sync + 'static,
+    {
+        self.set_shared_logger(Arc::new(logger));
+    }
+
+    /// Sets an already shared logger, e.g. one also used by an application context
+    pub fn set_shared_logger(&self, logger: Logger) {
+        *self.logger.write().unwrap_or_else(|e| e.into_inner()) = Some(logger);
+    }
+
+    /// Sends a message to the logger, building it only if a logger is set
+    fn log(&self, level: LogLevel, message: impl FnOnce() -> String) {
+        let logger = self.logger.read().unwrap_or_else(|e| e.into_inner());
+        if let Some(logger) = logger.as_ref() {
+            logger(level, &message());
+        }
+    }
+
+    /// Adds a callback that may replace each component right after it is created
+    ///
+    /// Processors see every component the container creates from a definition, before
+    /// it is stored or injected anywhere, including request-scoped and prototype
+    /// components created through a [`ScopeContext`]. Instances passed to
+    /// [`register_component`](Self::register_component) are not processed. Processors
+    /// run in the order they were added, each receiving the result of the previous one.
+    ///
+    /// The returned instance replaces the created one, so it must still hold the
+    /// component's type (for example a freshly wrapped `Arc<T>`); otherwise lookups by
+    /// type no longer find it. Processors should be added before
+    /// [`initialize`](Self::initialize), since components created earlier are unaffected.
+    ///
+    /// # Arguments
+    ///
+    /// * `processor` - Callback receiving the component's `TypeId` and the created instance
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use verdure_ioc::ComponentContainer;
+    /// use std::any::TypeId;
+    ///
+    /// let container = ComponentContainer::new();
+    /// container.add_post_processor(|type_id: TypeId, instance| {
+    ///     println!("created {:?}", type_id);
+    ///     instance
+    /// });
+    /// ```
+    pub fn add_post_processor<F>(&self, processor: F)
+    where
+        F: Fn(TypeId, ComponentInstance) -> ComponentInstance + Send + Sync + 'static,
+    {
+        self.post_processors
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .push(Arc::new(processor));
+    }
+
+    /// Passes a freshly created component through the registered post processors
+    pub(crate) fn post_process(
+        &self,
+        type_id: TypeId,
+        instance: ComponentInstance,
+    ) -> ComponentInstance {
+        // Clone the list so a processor may add further processors without deadlocking
+        let processors = self
+            .post_processors
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone();
+        processors
+            .iter()
+            .fold(instance, |instance, processor| processor(type_id, instance))
+    }
+
+    /// Sets the creation time above which a component is reported as slow
+    ///
+    /// When creating a component takes longer than `threshold`, the container publishes
+    /// a [`ContainerLifecycleEvent::SlowComponentCreation`] event naming the component,
+    /// right after its `ComponentCreated` event.
+    ///
+    /// # Arguments
+    ///
+    /// * `threshold` - The maximum expected creation time of a single component
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use verdure_ioc::ComponentContainer;
+    /// use std::time::Duration;
+    ///
+    /// let container = ComponentContainer::new()
+    ///     .with_slow_bean_threshold(Duration::from_millis(500));
+    /// ```
+    pub fn with_slow_bean_threshold(mut self, threshold: Duration) -> Self {
+        self.slow_component_threshold = Some(threshold);
+        self
+    }
+
+    /// Keeps records of the last `capacity` lifecycle events
+    ///
+    /// Records are available from [`recent_events`](Self::recent_events), which gives
+    /// a startup timeline without registering a lifecycle listener.
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - The number of records to keep; older records are dropped
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use verdure_ioc::ComponentContainer;
+    ///
+    /// let container = ComponentContainer::test().with_event_history(64);
+    /// container.initialize().unwrap();
+    ///
+    /// for record in container.recent_events() {
+    ///     println!("{} {:?} {:?}", record.event, record.component_name, record.duration);
+    /// }
+    /// ```
+    pub fn with_event_history(mut self, capacity: usize) -> Self {
+        self.event_history = Some(EventHistory::new(capacity));
+        self
+    }
+
+    /// Returns records of the most recent lifecycle events, oldest first
+    ///
+    /// Returns an empty list unless the container was created with
+    /// [`with_event_history`](Self::with_event_history).
+    pub fn recent_events(&self) -> Vec<LifecycleEventRecord> {
+        self.event_history
+            .as_ref()
+            .map(EventHistory::records)
+            .unwrap_or_default()
+    }
+
+    /// Publishes `event` to the lifecycle listeners and records it in the event history
+    fn publish_event(&self, event: &ContainerLifecycleEvent) {
+        self.lifecycle_publisher.publish(event);
+        if let Some(history) = &self.event_history {
+            history.record(event);
         }
     }
 
+    /// Returns the process-wide shared container
+    ///
+    /// Every call returns the same instance, created empty on first use. Containers
+    /// created with [`new`](Self::new) are independent of it and of each other, so a
+    /// component created in one is not visible in another. Use the global container
+    /// when plain container code and an `ApplicationContext` (via its builder's
+    /// `use_global_container` option) need to share components.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use verdure_ioc::ComponentContainer;
+    /// use std::sync::Arc;
+    ///
+    /// let a = ComponentContainer::global();
+    /// let b = ComponentContainer::global();
+    /// assert!(Arc::ptr_eq(&a, &b));
+    /// ```
+    pub fn global() -> Arc<ComponentContainer> {
+        GLOBAL_CONTAINER
+            .get_or_init(|| Arc::new(ComponentContainer::new()))
+            .clone()
+    }
+
     /// Initializes the container by discovering and creating all registered components
     ///
     /// This method scans for all components registered via the `#[derive(Component)]` macro
     /// and creates instances of them, resolving their dependencies automatically.
     /// It also publishes lifecycle events during the initialization process.
     ///
+    /// Initialization only runs once: subsequent calls are no-ops that return the same
+    /// count without publishing lifecycle events again. A call made while another thread
+    /// is initializing the container waits for it to finish. If initialization fails,
+    /// the container may be initialized again.
+    ///
+    /// Components are discovered through `inventory`, which only sees types from crates
+    /// linked into the binary. If no component is discovered, a warning is logged and
+    /// the `InitializationStarted` event reports a `component_count` of `0`.
+    ///
     /// # Returns
     ///
-    /// * `Ok(())` - If initialization completed successfully
+    /// * `Ok(count)` - The number of component definitions discovered
     /// * `Err(ContainerError)` - If there was an error during initialization (e.g., circular dependencies)
     ///
     /// # Examples
@@ -190,56 +674,240 @@ impl ComponentContainer {
     ///
     /// let container = ComponentContainer::new();
     /// match container.initialize() {
-    ///     Ok(_) => println!("Container initialized successfully"),
+    ///     Ok(0) => println!("No components discovered"),
+    ///     Ok(count) => println!("Container initialized with {} components", count),
     ///     Err(e) => eprintln!("Initialization failed: {}", e),
     /// }
     /// ```
-    pub fn initialize(&self) -> Result<(), ContainerError> {
-        let component_count = inventory::iter::<ComponentDefinition>().count();
+    pub fn initialize(&self) -> Result<usize, ContainerError> {
+        let mut initialization = self
+            .initialization
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        if let Some(count) = *initialization {
+            return Ok(count);
+        }
 
-        self.lifecycle_publisher
-            .publish(&ContainerLifecycleEvent::InitializationStarted {
-                container: self,
-                component_count,
+        self.initialized.store(true, Ordering::SeqCst);
+        let result = self.initialize_components();
+        match &result {
+            Ok(count) => *initialization = Some(*count),
+            Err(e) => {
+                self.log(LogLevel::Error, || {
+                    format!("Container initialization failed: {}", e)
+                });
+                self.initialized.store(false, Ordering::SeqCst);
+            }
+        }
+        result
+    }
+
+    /// Returns whether the container has been initialized
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use verdure_ioc::ComponentContainer;
+    ///
+    /// let container = ComponentContainer::new();
+    /// assert!(!container.is_initialized());
+    /// container.initialize().unwrap();
+    /// assert!(container.is_initialized());
+    /// ```
+    pub fn is_initialized(&self) -> bool {
+        self.initialized.load(Ordering::SeqCst)
+    }
+
+    /// Returns how long the last successful [`initialize`](Self::initialize) took
+    ///
+    /// Returns `None` if the container has not been initialized successfully yet.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use verdure_ioc::ComponentContainer;
+    ///
+    /// let container = ComponentContainer::test();
+    /// assert!(container.last_init_duration().is_none());
+    ///
+    /// container.initialize().unwrap();
+    /// println!(
+    ///     "Started {} components in {:?}",
+    ///     container.initialized_component_count().unwrap(),
+    ///     container.last_init_duration().unwrap()
+    /// );
+    /// ```
+    pub fn last_init_duration(&self) -> Option<Duration> {
+        self.last_initialization
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .map(|(duration, _)| duration)
+    }
+
+    /// Returns the number of components held right after the last successful initialization
+    ///
+    /// This is the `component_count` reported by `InitializationCompleted`; use
+    /// [`component_count`](Self::component_count) for the current number. Returns
+    /// `None` if the container has not been initialized successfully yet.
+    pub fn initialized_component_count(&self) -> Option<usize> {
+        self.last_initialization
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .map(|(_, count)| count)
+    }
+
+    fn initialize_components(&self) -> Result<usize, ContainerError> {
+        if self.discover_components {
+            for (first, second) in duplicate_definitions() {
+                self.log(LogLevel::Warn, || {
+                    format!(
+                        "{}; the last one is used",
+                        duplicate_definition_message(first, second)
+                    )
+                });
+            }
+        }
+
+        let definitions = self.definitions();
+        let component_count = definitions.len();
+
+        self.log(LogLevel::Info, || {
+            format!(
+                "Container initialization started ({} component definitions)",
+                component_count
+            )
+        });
+        if component_count == 0 && self.discover_components {
+            self.log(LogLevel::Warn, || {
+                "0 components discovered; #[derive(Component)] types are only found when \
+                 the crate defining them is linked into the binary"
+                    .to_string()
             });
+        }
+        self.publish_event(&ContainerLifecycleEvent::InitializationStarted {
+            container: self,
+            component_count,
+        });
 
         let start_time = Instant::now();
+        let creation_guard = self.creation_lock.lock();
+        let held_before: Vec<TypeId> = definitions
+            .keys()
+            .copied()
+            .filter(|type_id| {
+                let descriptor = ComponentDescriptor::new(*type_id, None);
+                self.components.contains_key(&descriptor)
+                    || self.lazy_factories.contains_key(&descriptor)
+            })
+            .collect();
+        let singleton_events_before = self.singleton_creation_events.load(Ordering::SeqCst);
 
-        let mut def_map = HashMap::new();
-        for def in inventory::iter::<ComponentDefinition> {
-            let type_id = (def.type_id)();
-            def_map.insert(type_id, def);
-        }
+        for def in initialization_order(definitions.values().copied()) {
+            // Request-scoped components are only created inside a ScopeContext
+            if (def.scope)() == ComponentScope::Request {
+                continue;
+            }
 
-        for def in inventory::iter::<ComponentDefinition> {
             let type_id = (def.type_id)();
             let descriptor = ComponentDescriptor::new(type_id, None);
 
             if !self.components.contains_key(&descriptor) {
-                self.resolve_bean(&descriptor, &def_map)?;
+                self.resolve_bean(&descriptor, definitions)?;
             } else {
                 // TODO: Duplicate registration failed
             }
         }
 
-        self.lifecycle_publisher
-            .publish(&ContainerLifecycleEvent::InitializationCompleted {
-                container: self,
-                component_count: self.components.len(),
-                duration: start_time.elapsed(),
-            });
+        // Every singleton the loop above created from its definition published exactly one
+        // event. Creation statistics are only recorded for components the container
+        // created, so instances registered manually meanwhile are not counted.
+        debug_assert_eq!(
+            self.singleton_creation_events.load(Ordering::SeqCst) - singleton_events_before,
+            definitions
+                .iter()
+                .filter(|(type_id, def)| {
+                    (def.scope)() == ComponentScope::Singleton
+                        && !held_before.contains(type_id)
+                        && self
+                            .stats
+                            .contains_key(&ComponentDescriptor::new(**type_id, None))
+                })
+                .count(),
+            "ComponentCreated events do not match the singletons created by initialize"
+        );
 
-        Ok(())
+        drop(creation_guard);
+        let duration = start_time.elapsed();
+        *self
+            .last_initialization
+            .write()
+            .unwrap_or_else(|e| e.into_inner()) = Some((duration, self.components.len()));
+        self.log(LogLevel::Info, || {
+            format!(
+                "Container initialization completed in {:?} ({} components)",
+                duration,
+                self.components.len()
+            )
+        });
+        self.publish_event(&ContainerLifecycleEvent::InitializationCompleted {
+            container: self,
+            component_count: self.components.len(),
+            duration,
+        });
+
+        Ok(component_count)
     }
 
-    /// Registers a pre-created component instance with the container
+    /// Opens a new scope for request-scoped components
     ///
-    /// This method allows manual registration of component instances that have been
-    /// created outside the container's automatic initialization process.
+    /// Components with [`ComponentScope::Request`] are created at most once per scope
+    /// and dropped together with the returned [`ScopeContext`]. Singleton and manually
+    /// registered components are shared with the container as usual.
     ///
-    /// # Arguments
+    /// # Examples
     ///
-    /// * `instance` - The component instance to register
+    /// ```rust
+    /// use verdure_ioc::{ComponentContainer, ComponentFactory};
+    /// use std::sync::Arc;
+    ///
+    /// struct Config;
+    ///
+    /// let container = ComponentContainer::new();
+    /// container.register_component(Arc::new(Config));
+    ///
+    /// let scope = container.open_scope();
+    /// let config: Option<Arc<Config>> = scope.get_component();
+    /// assert!(config.is_some());
+    /// ```
+    pub fn open_scope(&self) -> ScopeContext<'_> {
+        ScopeContext::new(self)
+    }
+
+    /// Resolves a singleton or prototype component from the registered definitions
+    ///
+    /// Concurrent calls for a singleton that does not exist yet create it exactly once:
+    /// the other callers wait and receive the same instance.
+    pub(crate) fn resolve_registered(
+        &self,
+        type_id: TypeId,
+    ) -> Result<ComponentInstance, ContainerError> {
+        let descriptor = self.resolve_alias(&ComponentDescriptor::new(type_id, None));
+        if let Some(instance) = self.components.get(&descriptor) {
+            return Ok(instance.clone());
+        }
+
+        let _guard = self.creation_lock.lock();
+        // Another thread may have created it while this one was waiting
+        if let Some(instance) = self.components.get(&descriptor) {
+            return Ok(instance.clone());
+        }
+        self.resolve_bean(&descriptor, self.definitions())
+    }
+
+    /// Returns the number of component instances held by the container
+    ///
+    /// This counts created singletons and manually registered instances; prototype
+    /// and request-scoped components are not held by the container.
     ///
     /// # Examples
     ///
@@ -247,244 +915,2051 @@ impl ComponentContainer {
     /// use verdure_ioc::ComponentContainer;
     /// use std::sync::Arc;
     ///
-    /// #[derive(Debug)]
-    /// struct ConfigService {
-    ///     config_path: String,
-    /// }
+    /// struct Config;
     ///
     /// let container = ComponentContainer::new();
-    /// let config = Arc::new(ConfigService {
-    ///     config_path: "/etc/myapp/config.toml".to_string(),
-    /// });
-    ///
-    /// container.register_component(config);
+    /// container.register_component(Arc::new(Config));
+    /// assert_eq!(container.component_count(), 1);
     /// ```
-    pub fn register_component(&self, instance: ComponentInstance) {
-        let type_id = (*instance).type_id();
-        self.register_component_by_type_id(type_id, instance);
+    pub fn component_count(&self) -> usize {
+        self.components.len()
     }
 
-    /// Registers a component instance with the container using a specific TypeId
+    /// Returns the number of `ComponentCreated` events this container has published
     ///
-    /// This method is useful when you need to register a component with a different
-    /// type identity than its concrete type.
+    /// An event is published each time a component is created from its definition,
+    /// so prototype components count once per creation, and when a component
+    /// registered with [`register_lazy`](Self::register_lazy) is first created. After a
+    /// successful [`initialize`](Self::initialize), every singleton created from a
+    /// definition is held by the container and has published exactly one event;
+    /// manually registered components publish none, and neither do request-scoped or
+    /// prototype components created by a [`ScopeContext`].
     ///
-    /// # Arguments
+    /// # Examples
     ///
-    /// * `type_id` - The TypeId to use for component registration
-    /// * `instance` - The component instance to register
-    pub fn register_component_by_type_id(&self, type_id: TypeId, instance: ComponentInstance) {
-        let descriptor = ComponentDescriptor::new(type_id, None);
-        self.components.insert(descriptor, instance);
+    /// ```rust
+    /// use verdure_ioc::{ComponentContainer, ComponentFactory};
+    /// use std::sync::Arc;
+    ///
+    /// struct Config;
+    /// struct Cache;
+    ///
+    /// let container = ComponentContainer::test();
+    /// container.register_component(Arc::new(Config));
+    /// container.register_lazy(|| Arc::new(Cache));
+    /// container.initialize().unwrap();
+    /// assert_eq!(container.creation_event_count(), 0);
+    ///
+    /// let _cache: Arc<Cache> = container.get_component().unwrap();
+    /// assert_eq!(container.creation_event_count(), 1);
+    /// ```
+    pub fn creation_event_count(&self) -> usize {
+        self.creation_events.load(Ordering::SeqCst)
     }
 
-    fn resolve_bean(
-        &self,
-        descriptor: &ComponentDescriptor,
-        def_map: &HashMap<TypeId, &ComponentDefinition>,
-    ) -> Result<ComponentInstance, ContainerError> {
-        if !self.initializing.insert(descriptor.type_id) {
-            let type_name = def_map
+    /// Returns the type names of all components registered via `inventory`, sorted
+    pub fn registered_type_names(&self) -> Vec<&'static str> {
+        let mut names: Vec<&'static str> = self
+            .definitions()
+            .values()
+            .map(|def| def.type_name)
+            .collect();
+        names.sort_unstable();
+        names
+    }
+
+    /// Checks that every declared dependency can be satisfied, without creating anything
+    ///
+    /// A dependency is satisfied if it has a `ComponentDefinition` registered via
+    /// `inventory` or if an instance (or alias) for it is already registered with this
+    /// container. This catches a missing `#[derive(Component)]` on a dependency before
+    /// [`initialize`](Self::initialize) is called.
+    ///
+    /// # Returns
+    ///
+    /// The dangling dependencies as `(depending component name, missing TypeId)` pairs,
+    /// sorted by component name. An empty list means the wiring is complete.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use verdure_ioc::ComponentContainer;
+    ///
+    /// let container = ComponentContainer::new();
+    /// for (component, missing) in container.check_wiring() {
+    ///     eprintln!("{} depends on unregistered type {:?}", component, missing);
+    /// }
+    /// ```
+    pub fn check_wiring(&self) -> Vec<(&'static str, TypeId)> {
+        let definitions = self.definitions();
+        let mut dangling: Vec<(&'static str, TypeId)> = definitions
+            .values()
+            .flat_map(|def| {
+                (def.dependencies)()
+                    .into_iter()
+                    .filter(|dep_id| {
+                        let descriptor =
+                            self.resolve_alias(&ComponentDescriptor::new(*dep_id, None));
+                        !definitions.contains_key(&descriptor.type_id)
+                            && !self.components.contains_key(&descriptor)
+                            && !matches!(self.trait_implementation(*dep_id), Ok(Some(_)))
+                    })
+                    .map(move |dep_id| (def.type_name, dep_id))
+            })
+            .collect();
+        dangling.sort_by_key(|(name, _)| *name);
+        dangling
+    }
+
+    /// Returns the dependency graph of all components registered via `inventory`
+    ///
+    /// Each entry maps a component's type name to the type names of the components it
+    /// depends on, sorted by name. Dependencies without a registered definition (for
+    /// example manually registered instances) are shown by their `TypeId`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use verdure_ioc::ComponentContainer;
+    ///
+    /// let container = ComponentContainer::new();
+    /// for (component, dependencies) in container.dependency_graph() {
+    ///     println!("{} -> {:?}", component, dependencies);
+    /// }
+    /// ```
+    pub fn dependency_graph(&self) -> Vec<(String, Vec<String>)> {
+        let definitions = self.definitions();
+        let mut graph: Vec<(String, Vec<String>)> = definitions
+            .values()
+            .map(|def| {
+                let dependencies = (def.dependencies)()
+                    .into_iter()
+                    .map(|dep_id| match definitions.get(&dep_id) {
+                        Some(dep_def) => dep_def.type_name.to_string(),
+                        None => format!("{:?}", dep_id),
+                    })
+                    .collect();
+                (def.type_name.to_string(), dependencies)
+            })
+            .collect();
+        graph.sort();
+        graph
+    }
+
+    /// Returns the statistics of the components matching a predicate
+    ///
+    /// Only components with recorded statistics are considered, i.e. components
+    /// created by the container. Components are named by their registered type name,
+    /// or by their `TypeId` if they have no definition.
+    ///
+    /// # Arguments
+    ///
+    /// * `predicate` - Filter receiving each component's descriptor and statistics
+    ///
+    /// # Returns
+    ///
+    /// The matching `(name, statistics)` pairs, sorted by name
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use verdure_ioc::ComponentContainer;
+    ///
+    /// let container = ComponentContainer::new();
+    /// container.initialize().unwrap();
+    ///
+    /// let slow = container.find_components(|_, stats| stats.creation_time > 100);
+    /// for (name, stats) in slow {
+    ///     println!("{} took {}ms to create", name, stats.creation_time);
+    /// }
+    /// ```
+    pub fn find_components<F>(&self, predicate: F) -> Vec<(String, ComponentStats)>
+    where
+        F: Fn(&ComponentDescriptor, &ComponentStats) -> bool,
+    {
+        let definitions = self.definitions();
+        let mut found: Vec<(String, ComponentStats)> = self
+            .stats
+            .iter()
+            .filter(|entry| predicate(entry.key(), entry.value()))
+            .map(|entry| {
+                let name = component_name(definitions, entry.key().type_id);
+                (name, entry.value().clone())
+            })
+            .collect();
+        found.sort_by(|a, b| a.0.cmp(&b.0));
+        found
+    }
+
+    /// Visits every component instance held by the container
+    ///
+    /// Components are visited in order of their names: the registered type name, or the
+    /// `TypeId` for components without a definition. Only instances stored in the
+    /// container are visited, i.e. singletons and manually registered components;
+    /// prototype and request-scoped components are not stored and therefore not
+    /// visited, nor are the entries made by
+    /// [`register_trait_component`](Self::register_trait_component).
+    ///
+    /// The instances are collected before `f` is called, so `f` may use the container.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - Visitor receiving each component's name and instance
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use verdure_ioc::ComponentContainer;
+    /// use std::sync::Arc;
+    ///
+    /// struct HealthController;
+    ///
+    /// let container = ComponentContainer::new();
+    /// container.register_component(Arc::new(HealthController));
+    ///
+    /// let mut controllers = 0;
+    /// container.for_each_component(|_name, instance| {
+    ///     if instance.is::<HealthController>() {
+    ///         controllers += 1;
+    ///     }
+    /// });
+    /// assert_eq!(controllers, 1);
+    /// ```
+    pub fn for_each_component<F>(&self, mut f: F)
+    where
+        F: FnMut(&str, &ComponentInstance),
+    {
+        let definitions = self.definitions();
+        let mut instances: Vec<(String, ComponentInstance)> = self
+            .components
+            .iter()
+            .filter(|entry| {
+                !self
+                    .trait_implementations
+                    .contains_key(&entry.key().type_id)
+            })
+            .map(|entry| {
+                let name = component_name(definitions, entry.key().type_id);
+                (name, entry.value().clone())
+            })
+            .collect();
+        instances.sort_by(|a, b| a.0.cmp(&b.0));
+        for (name, instance) in &instances {
+            f(name, instance);
+        }
+    }
+
+    /// Renders the [`dependency_graph`](Self::dependency_graph) in Graphviz DOT format
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use verdure_ioc::ComponentContainer;
+    ///
+    /// let dot = ComponentContainer::new().to_dot();
+    /// assert!(dot.starts_with("digraph components {"));
+    /// ```
+    pub fn to_dot(&self) -> String {
+        render_dot(&self.dependency_graph())
+    }
+
+    /// Registers a pre-created component instance with the container
+    ///
+    /// This method allows manual registration of component instances that have been
+    /// created outside the container's automatic initialization process.
+    ///
+    /// # Arguments
+    ///
+    /// * `instance` - The component instance to register
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use verdure_ioc::ComponentContainer;
+    /// use std::sync::Arc;
+    ///
+    /// #[derive(Debug)]
+    /// struct ConfigService {
+    ///     config_path: String,
+    /// }
+    ///
+    /// let container = ComponentContainer::new();
+    /// let config = Arc::new(ConfigService {
+    ///     config_path: "/etc/myapp/config.toml".to_string(),
+    /// });
+    ///
+    /// container.register_component(config);
+    /// ```
+    pub fn register_component(&self, instance: ComponentInstance) {
+        let type_id = (*instance).type_id();
+        self.register_component_by_type_id(type_id, instance);
+    }
+
+    /// Registers a component instance with the container using a specific TypeId
+    ///
+    /// This method is useful when you need to register a component with a different
+    /// type identity than its concrete type.
+    ///
+    /// # Arguments
+    ///
+    /// * `type_id` - The TypeId to use for component registration
+    /// * `instance` - The component instance to register
+    pub fn register_component_by_type_id(&self, type_id: TypeId, instance: ComponentInstance) {
+        let descriptor = ComponentDescriptor::new(type_id, None);
+        self.components.insert(descriptor, instance);
+    }
+
+    /// Registers a component instance unless one of the same type is already registered
+    ///
+    /// [`register_component`](Self::register_component) replaces an existing instance,
+    /// which can hide a component being wired up twice. This method leaves the existing
+    /// instance in place and reports the collision instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `instance` - The component instance to register
+    ///
+    /// # Errors
+    ///
+    /// Returns a `Configuration` error naming the type if an instance of `T` is already
+    /// held by the container.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use verdure_ioc::ComponentContainer;
+    /// use std::sync::Arc;
+    ///
+    /// struct Clock;
+    ///
+    /// let container = ComponentContainer::new();
+    /// assert!(container.try_register_component(Arc::new(Clock)).is_ok());
+    /// assert!(container.try_register_component(Arc::new(Clock)).is_err());
+    /// ```
+    pub fn try_register_component<T: Any + Send + Sync>(
+        &self,
+        instance: Arc<T>,
+    ) -> Result<(), ContainerError> {
+        match self.components.entry(ComponentDescriptor::for_type::<T>()) {
+            Entry::Occupied(_) => Err(ContainerError::configuration(format!(
+                "Component '{}' is already registered",
+                std::any::type_name::<T>()
+            ))),
+            Entry::Vacant(entry) => {
+                entry.insert(instance);
+                Ok(())
+            }
+        }
+    }
+
+    /// Registers a factory that creates a component the first time it is requested
+    ///
+    /// This is the runtime counterpart of [`register_component`](Self::register_component)
+    /// for components whose construction needs data that only becomes available after the
+    /// container was created, or that are expensive and often unused. The factory runs at
+    /// most once, on the first lookup of `T` or the first component depending on it, and
+    /// the instance is then held like any registered component. Registering `T` again
+    /// before that replaces the factory. Like components created from definitions, the
+    /// instance goes through the post processors and publishes a `ComponentCreated` event.
+    ///
+    /// # Arguments
+    ///
+    /// * `factory` - Creates the component
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use verdure_ioc::{ComponentContainer, ComponentFactory};
+    /// use std::sync::Arc;
+    ///
+    /// struct ReportCache {
+    ///     capacity: usize,
+    /// }
+    ///
+    /// let container = ComponentContainer::new();
+    /// container.register_lazy(|| Arc::new(ReportCache { capacity: 64 }));
+    /// assert_eq!(container.component_count(), 0);
+    ///
+    /// let cache: Arc<ReportCache> = container.get_component().unwrap();
+    /// assert_eq!(cache.capacity, 64);
+    /// assert_eq!(container.component_count(), 1);
+    /// ```
+    pub fn register_lazy<T, F>(&self, factory: F)
+    where
+        T: Any + Send + Sync,
+        F: Fn() -> Arc<T> + Send + Sync + 'static,
+    {
+        self.lazy_factories.insert(
+            ComponentDescriptor::for_type::<T>(),
+            LazyFactory {
+                type_name: std::any::type_name::<T>(),
+                create: Arc::new(move || factory() as ComponentInstance),
+            },
+        );
+    }
+
+    /// Creates a lazily registered component, returning `None` if there is no factory for it
+    fn lazy_component(&self, descriptor: &ComponentDescriptor) -> Option<ComponentInstance> {
+        if !self.lazy_factories.contains_key(descriptor) {
+            // The factory is only removed once its instance is stored
+            return self
+                .components
+                .get(descriptor)
+                .map(|instance| instance.clone());
+        }
+
+        let _guard = self.creation_lock.lock();
+        // Another thread may have created it while this one was waiting
+        if let Some(instance) = self.components.get(descriptor) {
+            return Some(instance.clone());
+        }
+        let factory = self.lazy_factories.get(descriptor)?.clone();
+
+        let start = Instant::now();
+        let instance = (factory.create)();
+        let creation_time = start.elapsed();
+        let instance = self.post_process(descriptor.type_id, instance);
+        self.components.insert(descriptor.clone(), instance.clone());
+        self.lazy_factories.remove(descriptor);
+        self.stats.insert(
+            descriptor.clone(),
+            ComponentStats {
+                created_at: Some(Instant::now()),
+                creation_time: creation_time.as_millis() as u64,
+                ..Default::default()
+            },
+        );
+
+        self.log(LogLevel::Debug, || {
+            format!(
+                "Created component '{}' in {:?}",
+                factory.type_name, creation_time
+            )
+        });
+        self.publish_event(&ContainerLifecycleEvent::ComponentCreated {
+            container: self,
+            component_name: factory.type_name,
+            component_type_id: descriptor.type_id,
+            creation_duration: creation_time,
+        });
+        self.creation_events.fetch_add(1, Ordering::SeqCst);
+        Some(instance)
+    }
+
+    /// Retrieves a component, returning an error that names the type if it is missing
+    ///
+    /// Unlike [`ComponentFactory::get_component`], which returns `None`, this method
+    /// reports a `NotFound` error containing `std::any::type_name::<T>()`, or a
+    /// `TypeCastFailed` error if the registered instance has a different type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use verdure_ioc::ComponentContainer;
+    /// use std::sync::Arc;
+    ///
+    /// struct UserService;
+    ///
+    /// let container = ComponentContainer::new();
+    /// let missing = container.get_component_required::<UserService>();
+    /// assert!(missing.err().unwrap().to_string().contains("UserService"));
+    ///
+    /// container.register_component(Arc::new(UserService));
+    /// assert!(container.get_component_required::<UserService>().is_ok());
+    /// ```
+    pub fn get_component_required<T: Any + Send + Sync>(&self) -> Result<Arc<T>, ContainerError> {
+        let type_name = std::any::type_name::<T>();
+        self.get_component_by_type_id(TypeId::of::<T>())
+            .ok_or_else(|| ContainerError::not_found(type_name))?
+            .downcast()
+            .map_err(|_| ContainerError::type_cast_failed(type_name))
+    }
+
+    /// Runs a closure on a component without cloning its `Arc`
+    ///
+    /// The component is borrowed from the container's map for the duration of the
+    /// closure, which avoids the reference count update of
+    /// [`ComponentFactory::get_component`] on hot paths.
+    ///
+    /// Part of the component map stays read-locked while the closure runs, so the
+    /// closure must not call back into this container: registering or creating a
+    /// component from it can deadlock.
+    ///
+    /// # Returns
+    ///
+    /// The closure's result, or `None` if no component of type `T` is registered
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use verdure_ioc::ComponentContainer;
+    /// use std::sync::Arc;
+    ///
+    /// struct Settings {
+    ///     timeout_ms: u64,
+    /// }
+    ///
+    /// let container = ComponentContainer::new();
+    /// container.register_component(Arc::new(Settings { timeout_ms: 250 }));
+    ///
+    /// let timeout = container.with_component(|settings: &Settings| settings.timeout_ms);
+    /// assert_eq!(timeout, Some(250));
+    /// ```
+    pub fn with_component<T, R>(&self, f: impl FnOnce(&T) -> R) -> Option<R>
+    where
+        T: Any + Send + Sync,
+    {
+        let descriptor = self.resolve_alias(&ComponentDescriptor::for_type::<T>());
+        if let Some(instance) = self.components.get(&descriptor) {
+            return instance.value().as_ref().downcast_ref::<T>().map(f);
+        }
+        // Created on first use; later calls borrow it from the map
+        let instance = self.lazy_component(&descriptor)?;
+        instance.as_ref().downcast_ref::<T>().map(f)
+    }
+
+    /// Returns the registered component of type `T`, creating and registering it if missing
+    ///
+    /// The factory runs at most once per container even when several threads race for
+    /// the same component: the slot is claimed through the component map's entry API
+    /// and concurrent callers wait for it to be filled. This is intended for manually
+    /// managed, expensive components that are not known to `inventory`.
+    ///
+    /// The factory runs while the slot is locked, so it must not access this container.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TypeCastFailed` if the instance registered for `T` (for example through
+    /// an alias) is not a `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use verdure_ioc::ComponentContainer;
+    /// use std::sync::Arc;
+    ///
+    /// struct ConnectionPool {
+    ///     size: usize,
+    /// }
+    ///
+    /// let container = ComponentContainer::new();
+    /// let pool = container
+    ///     .get_component_or_init(|| Arc::new(ConnectionPool { size: 8 }))
+    ///     .unwrap();
+    /// let again = container
+    ///     .get_component_or_init(|| Arc::new(ConnectionPool { size: 16 }))
+    ///     .unwrap();
+    ///
+    /// assert_eq!(again.size, 8);
+    /// assert!(Arc::ptr_eq(&pool, &again));
+    /// ```
+    pub fn get_component_or_init<T, F>(&self, init: F) -> Result<Arc<T>, ContainerError>
+    where
+        T: Any + Send + Sync,
+        F: FnOnce() -> Arc<T>,
+    {
+        let descriptor = self.resolve_alias(&ComponentDescriptor::for_type::<T>());
+        let instance = match self.components.entry(descriptor.clone()) {
+            Entry::Occupied(entry) => entry.get().clone(),
+            Entry::Vacant(entry) => {
+                let start = Instant::now();
+                let instance: ComponentInstance = init();
+                entry.insert(instance.clone());
+                self.stats.insert(
+                    descriptor,
+                    ComponentStats {
+                        created_at: Some(Instant::now()),
+                        creation_time: start.elapsed().as_millis() as u64,
+                        ..Default::default()
+                    },
+                );
+                instance
+            }
+        };
+        instance
+            .downcast()
+            .map_err(|_| ContainerError::type_cast_failed(std::any::type_name::<T>()))
+    }
+
+    /// Registers an alias so that lookups for one type resolve to another type's instance
+    ///
+    /// After registration, looking up `alias_type_id` returns the instance registered
+    /// under `target_type_id`. Aliases are resolved at lookup time and never duplicate
+    /// the instance: both lookups yield the same `Arc`. The target does not need to be
+    /// registered yet, and aliases may point at other aliases.
+    ///
+    /// # Arguments
+    ///
+    /// * `alias_type_id` - The TypeId that should redirect to the target
+    /// * `target_type_id` - The TypeId of the component the alias refers to
+    ///
+    /// # Errors
+    ///
+    /// Returns a configuration error if the alias refers to itself, either directly
+    /// or through a chain of existing aliases.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use verdure_ioc::{ComponentContainer, ComponentFactory};
+    /// use std::any::TypeId;
+    /// use std::sync::Arc;
+    ///
+    /// struct PostgresRepository;
+    /// struct Repository;
+    ///
+    /// let container = ComponentContainer::new();
+    /// container.register_component(Arc::new(PostgresRepository));
+    /// container
+    ///     .register_alias(TypeId::of::<Repository>(), TypeId::of::<PostgresRepository>())
+    ///     .unwrap();
+    ///
+    /// let by_alias = container.get_component_by_type_id(TypeId::of::<Repository>()).unwrap();
+    /// let by_target = container
+    ///     .get_component_by_type_id(TypeId::of::<PostgresRepository>())
+    ///     .unwrap();
+    /// assert!(Arc::ptr_eq(&by_alias, &by_target));
+    /// ```
+    pub fn register_alias(
+        &self,
+        alias_type_id: TypeId,
+        target_type_id: TypeId,
+    ) -> Result<(), ContainerError> {
+        let alias = ComponentDescriptor::new(alias_type_id, None);
+        let target = ComponentDescriptor::new(target_type_id, None);
+
+        let _registering = self.alias_lock.lock().unwrap_or_else(|e| e.into_inner());
+        if self.resolve_alias(&target) == alias {
+            return Err(ContainerError::configuration(format!(
+                "Alias {:?} would refer to itself",
+                alias_type_id
+            )));
+        }
+
+        self.aliases.insert(alias, target);
+        Ok(())
+    }
+
+    /// Registers an implementation of a trait object type under a qualifier
+    ///
+    /// The implementation is stored as `Arc<Arc<T>>` under `TypeId::of::<Arc<T>>()`,
+    /// which is how `#[autowired]` fields of type `Arc<dyn Trait>` are looked up. When a
+    /// trait has exactly one implementation, autowiring and
+    /// [`get_trait_component`](Self::get_trait_component) resolve to it; with several
+    /// implementations only [`get_qualified_trait_component`](Self::get_qualified_trait_component)
+    /// can tell them apart. The concrete type of the implementation is not known, so it
+    /// is never treated as primary; use
+    /// [`register_trait_component_of`](Self::register_trait_component_of) for that.
+    ///
+    /// # Arguments
+    ///
+    /// * `qualifier` - The name distinguishing this implementation from others of the same trait
+    /// * `implementation` - The implementation as a trait object
+    ///
+    /// # Errors
+    ///
+    /// Returns a configuration error if an implementation with the same qualifier is
+    /// already registered for the trait.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use verdure_ioc::ComponentContainer;
+    /// use std::sync::Arc;
+    ///
+    /// trait UserRepository: Send + Sync {
+    ///     fn name(&self) -> &str;
+    /// }
+    ///
+    /// struct PostgresRepository;
+    ///
+    /// impl UserRepository for PostgresRepository {
+    ///     fn name(&self) -> &str {
+    ///         "postgres"
+    ///     }
+    /// }
+    ///
+    /// let container = ComponentContainer::new();
+    /// container
+    ///     .register_trait_component::<dyn UserRepository>("postgres", Arc::new(PostgresRepository))
+    ///     .unwrap();
+    ///
+    /// let repository = container.get_trait_component::<dyn UserRepository>().unwrap();
+    /// assert_eq!(repository.name(), "postgres");
+    /// ```
+    pub fn register_trait_component<T>(
+        &self,
+        qualifier: &'static str,
+        implementation: Arc<T>,
+    ) -> Result<(), ContainerError>
+    where
+        T: ?Sized + Send + Sync + 'static,
+    {
+        self.insert_trait_component(qualifier, implementation, false)
+    }
+
+    /// Registers an implementation of the concrete component type `C` as an
+    /// implementation of the trait object type `T`
+    ///
+    /// Works like [`register_trait_component`](Self::register_trait_component), but
+    /// records `C`, so an implementation whose type is declared with
+    /// `#[component(primary)]` is preferred when the trait has several implementations.
+    /// `as_trait` converts the implementation to the trait object, usually `|c| c`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a configuration error if an implementation with the same qualifier is
+    /// already registered for the trait.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use verdure_ioc::ComponentContainer;
+    /// use std::sync::Arc;
+    ///
+    /// trait UserRepository: Send + Sync {
+    ///     fn name(&self) -> &str;
+    /// }
+    ///
+    /// struct PostgresRepository;
+    ///
+    /// impl UserRepository for PostgresRepository {
+    ///     fn name(&self) -> &str {
+    ///         "postgres"
+    ///     }
+    /// }
+    ///
+    /// let container = ComponentContainer::new();
+    /// container
+    ///     .register_trait_component_of::<dyn UserRepository, _>(
+    ///         "postgres",
+    ///         Arc::new(PostgresRepository),
+    ///         |c| c,
+    ///     )
+    ///     .unwrap();
+    ///
+    /// let repository = container.get_trait_component::<dyn UserRepository>().unwrap();
+    /// assert_eq!(repository.name(), "postgres");
+    /// ```
+    pub fn register_trait_component_of<T, C>(
+        &self,
+        qualifier: &'static str,
+        implementation: Arc<C>,
+        as_trait: impl FnOnce(Arc<C>) -> Arc<T>,
+    ) -> Result<(), ContainerError>
+    where
+        T: ?Sized + Send + Sync + 'static,
+        C: Send + Sync + 'static,
+    {
+        let primary = self
+            .definitions()
+            .get(&TypeId::of::<C>())
+            .is_some_and(|def| (def.primary)());
+        self.insert_trait_component(qualifier, as_trait(implementation), primary)
+    }
+
+    fn insert_trait_component<T>(
+        &self,
+        qualifier: &'static str,
+        implementation: Arc<T>,
+        primary: bool,
+    ) -> Result<(), ContainerError>
+    where
+        T: ?Sized + Send + Sync + 'static,
+    {
+        let type_id = TypeId::of::<Arc<T>>();
+        let mut implementations = self
+            .trait_implementations
+            .entry(type_id)
+            .or_insert_with(|| TraitImplementations {
+                trait_name: std::any::type_name::<T>(),
+                qualifiers: Vec::new(),
+                primary: Vec::new(),
+            });
+        if implementations.qualifiers.contains(&qualifier) {
+            return Err(ContainerError::configuration(format!(
+                "Implementation '{}' of {} is already registered",
+                qualifier,
+                std::any::type_name::<T>()
+            )));
+        }
+        implementations.qualifiers.push(qualifier);
+        implementations.primary.push(primary);
+        self.components.insert(
+            ComponentDescriptor::new(type_id, Some(qualifier)),
+            Arc::new(implementation),
+        );
+        Ok(())
+    }
+
+    /// Retrieves the only implementation registered for a trait object type
+    ///
+    /// If several implementations are registered, the one registered with
+    /// [`register_trait_component_of`](Self::register_trait_component_of) for a type
+    /// declared with `#[component(primary)]` is returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns `NotFound` if no implementation is registered, or a configuration error
+    /// naming the candidates if there is more than one and not exactly one of them is
+    /// primary.
+    pub fn get_trait_component<T>(&self) -> Result<Arc<T>, ContainerError>
+    where
+        T: ?Sized + Send + Sync + 'static,
+    {
+        let type_name = std::any::type_name::<T>();
+        self.trait_implementation(TypeId::of::<Arc<T>>())?
+            .ok_or_else(|| ContainerError::not_found(type_name))?
+            .downcast::<Arc<T>>()
+            .map(|implementation| implementation.as_ref().clone())
+            .map_err(|_| ContainerError::type_cast_failed(type_name))
+    }
+
+    /// Retrieves the implementation of a trait object type registered under `qualifier`
+    ///
+    /// # Errors
+    ///
+    /// Returns `NotFound` if no implementation is registered under that qualifier.
+    pub fn get_qualified_trait_component<T>(
+        &self,
+        qualifier: &'static str,
+    ) -> Result<Arc<T>, ContainerError>
+    where
+        T: ?Sized + Send + Sync + 'static,
+    {
+        let type_name = std::any::type_name::<T>();
+        let descriptor = ComponentDescriptor::new(TypeId::of::<Arc<T>>(), Some(qualifier));
+        self.components
+            .get(&descriptor)
+            .ok_or_else(|| ContainerError::not_found(format!("{} ('{}')", type_name, qualifier)))?
+            .clone()
+            .downcast::<Arc<T>>()
+            .map(|implementation| implementation.as_ref().clone())
+            .map_err(|_| ContainerError::type_cast_failed(type_name))
+    }
+
+    /// Returns the single, or else the primary, implementation registered for a trait
+    /// object `TypeId`
+    ///
+    /// Returns `Ok(None)` if no implementation is registered for `type_id`.
+    fn trait_implementation(
+        &self,
+        type_id: TypeId,
+    ) -> Result<Option<ComponentInstance>, ContainerError> {
+        let Some(implementations) = self.trait_implementations.get(&type_id) else {
+            return Ok(None);
+        };
+        match implementations.qualifiers.as_slice() {
+            [] => Ok(None),
+            [qualifier] => Ok(self
+                .components
+                .get(&ComponentDescriptor::new(type_id, Some(qualifier)))
+                .map(|instance| instance.clone())),
+            qualifiers => {
+                let primaries: Vec<&'static str> = qualifiers
+                    .iter()
+                    .zip(&implementations.primary)
+                    .filter(|(_, primary)| **primary)
+                    .map(|(qualifier, _)| *qualifier)
+                    .collect();
+                match primaries.as_slice() {
+                    [qualifier] => Ok(self
+                        .components
+                        .get(&ComponentDescriptor::new(type_id, Some(qualifier)))
+                        .map(|instance| instance.clone())),
+                    [] => Err(ContainerError::configuration(format!(
+                        "{} has {} implementations ({}); use a qualified lookup or mark one \
+                         as primary",
+                        implementations.trait_name,
+                        qualifiers.len(),
+                        qualifiers.join(", ")
+                    ))),
+                    primaries => Err(ContainerError::configuration(format!(
+                        "{} has {} primary implementations ({}); only one may be primary",
+                        implementations.trait_name,
+                        primaries.len(),
+                        primaries.join(", ")
+                    ))),
+                }
+            }
+        }
+    }
+
+    /// Follows alias entries until reaching a descriptor that is not an alias
+    fn resolve_alias(&self, descriptor: &ComponentDescriptor) -> ComponentDescriptor {
+        let mut current = descriptor.clone();
+        while let Some(target) = self.aliases.get(&current) {
+            current = target.clone();
+        }
+        current
+    }
+
+    fn resolve_bean(
+        &self,
+        descriptor: &ComponentDescriptor,
+        def_map: &HashMap<TypeId, &ComponentDefinition>,
+    ) -> Result<ComponentInstance, ContainerError> {
+        let Some(_resolving) = ResolvingGuard::enter(&self.initializing, descriptor.type_id) else {
+            let description = def_map
                 .get(&descriptor.type_id)
-                .map_or("Unknown", |d| d.type_name);
-            return Err(ContainerError::circular_dependency(format!(
-                "{}",
-                type_name
-            )));
+                .map_or_else(|| "Unknown".to_string(), |d| d.describe());
+            return Err(ContainerError::circular_dependency(description));
+        };
+
+        let def = match def_map.get(&descriptor.type_id) {
+            Some(d) => *d,
+            None => {
+                return Err(ContainerError::not_found(format!(
+                    "Bean definition not found for type ID {:?}",
+                    descriptor.type_id
+                )));
+            }
+        };
+
+        let dependencies = (def.dependencies)();
+        let mut deps_map = HashMap::new();
+        for dep_id in dependencies {
+            let dep_descriptor = self.resolve_alias(&ComponentDescriptor::new(dep_id, None));
+
+            // exist in components
+            if let Some(instance) = self.components.get(&dep_descriptor) {
+                deps_map.insert(dep_id, instance.clone());
+                continue;
+            }
+            if let Some(instance) = self.lazy_component(&dep_descriptor) {
+                deps_map.insert(dep_id, instance);
+                continue;
+            }
+
+            if let Some(instance) = self.trait_implementation(dep_id)? {
+                deps_map.insert(dep_id, instance);
+                continue;
+            }
+
+            if let Some(dep_def) = def_map.get(&dep_descriptor.type_id) {
+                if (dep_def.scope)() == ComponentScope::Request {
+                    return Err(ContainerError::configuration(format!(
+                        "Request-scoped component '{}' cannot be injected into '{}' outside of a scope",
+                        dep_def.type_name, def.type_name
+                    )));
+                }
+                let dep_instance = self.resolve_bean(&dep_descriptor, def_map)?;
+                deps_map.insert(dep_id, dep_instance);
+            } else {
+                return Err(ContainerError::not_found(format!(
+                    "Dependency of {} not found for type ID {:?}; if it derives Component, \
+                     make sure the crate defining it is linked into the binary",
+                    def.describe(),
+                    dep_id
+                )));
+            }
+        }
+
+        let start = Instant::now();
+        let instance = match (def.creator)(deps_map) {
+            Ok(i) => i,
+            Err(e) => {
+                return Err(ContainerError::creation_failed(format!(
+                    "Failed to create bean {}: '{}'",
+                    def.describe(),
+                    e
+                )));
+            }
+        };
+        let creation_time = start.elapsed();
+        let instance = self.post_process(descriptor.type_id, instance);
+
+        self.log(LogLevel::Debug, || {
+            format!(
+                "Created component '{}' in {:?}",
+                def.type_name, creation_time
+            )
+        });
+        self.publish_event(&ContainerLifecycleEvent::ComponentCreated {
+            container: self,
+            component_name: def.type_name,
+            component_type_id: descriptor.type_id,
+            creation_duration: creation_time,
+        });
+        self.creation_events.fetch_add(1, Ordering::SeqCst);
+        if (def.scope)() == ComponentScope::Singleton {
+            self.singleton_creation_events
+                .fetch_add(1, Ordering::SeqCst);
+        }
+
+        if let Some(threshold) = self.slow_component_threshold
+            && creation_time > threshold
+        {
+            self.log(LogLevel::Warn, || {
+                format!(
+                    "Component '{}' took {:?} to create (threshold {:?})",
+                    def.type_name, creation_time, threshold
+                )
+            });
+            self.publish_event(&ContainerLifecycleEvent::SlowComponentCreation {
+                container: self,
+                component_name: def.type_name,
+                component_type_id: descriptor.type_id,
+                creation_duration: creation_time,
+                threshold,
+            });
+        }
+
+        if (def.scope)() == ComponentScope::Singleton {
+            self.components.insert(descriptor.clone(), instance.clone());
+        }
+
+        self.stats.insert(
+            descriptor.clone(),
+            ComponentStats {
+                created_at: Some(Instant::now()),
+                last_accessed: Some(Instant::now()),
+                access_count: 1,
+                creation_time: creation_time.as_millis() as u64,
+            },
+        );
+
+        Ok(instance)
+    }
+}
+
+impl Default for ComponentContainer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn render_dot(graph: &[(String, Vec<String>)]) -> String {
+    let mut dot = String::from("digraph components {\n");
+    for (component, dependencies) in graph {
+        if dependencies.is_empty() {
+            dot.push_str(&format!("    {:?};\n", component));
+        }
+        for dependency in dependencies {
+            dot.push_str(&format!("    {:?} -> {:?};\n", component, dependency));
+        }
+    }
+    dot.push_str("}\n");
+    dot
+}
+
+impl ComponentFactory for ComponentContainer {
+    fn get_component_by_type_id(&self, type_id: TypeId) -> Option<Arc<dyn Any + Send + Sync>> {
+        let descriptor = self.resolve_alias(&ComponentDescriptor::new(type_id, None));
+        if let Some(instance) = self.components.get(&descriptor) {
+            return Some(instance.clone());
+        }
+        self.lazy_component(&descriptor)
+            .or_else(|| self.trait_implementation(type_id).ok().flatten())
+    }
+
+    fn get_component<T: Any + Send + Sync>(&self) -> Option<Arc<T>> {
+        let component_any = self.get_component_by_type_id(TypeId::of::<T>())?;
+        component_any.downcast().ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ComponentInitializer;
+    use std::sync::atomic::{AtomicU32, AtomicUsize, Ordering};
+
+    #[derive(Debug)]
+    struct TestComponent {
+        value: u32,
+    }
+
+    impl TestComponent {
+        fn new(value: u32) -> Self {
+            Self { value }
+        }
+    }
+
+    #[derive(Debug)]
+    struct TestComponentWithDeps {
+        dependency: Arc<TestComponent>,
+        value: String,
+    }
+
+    static CREATION_COUNTER: AtomicU32 = AtomicU32::new(0);
+
+    impl ComponentInitializer for TestComponent {
+        type Dependencies = ();
+
+        fn __new(_deps: Self::Dependencies) -> Self {
+            CREATION_COUNTER.fetch_add(1, Ordering::SeqCst);
+            TestComponent::new(42)
+        }
+
+        fn __scope() -> crate::ComponentScope {
+            crate::ComponentScope::Singleton
+        }
+    }
+
+    impl ComponentInitializer for TestComponentWithDeps {
+        type Dependencies = (Arc<TestComponent>,);
+
+        fn __new(deps: Self::Dependencies) -> Self {
+            let (dependency,) = deps;
+            TestComponentWithDeps {
+                dependency,
+                value: "test".to_string(),
+            }
+        }
+
+        fn __scope() -> crate::ComponentScope {
+            crate::ComponentScope::Singleton
+        }
+    }
+
+    #[test]
+    fn test_component_descriptor() {
+        let desc1 = ComponentDescriptor::for_type::<TestComponent>();
+        let desc2 = ComponentDescriptor::new(TypeId::of::<TestComponent>(), None);
+        assert_eq!(desc1, desc2);
+
+        let desc_with_qualifier = ComponentDescriptor::with_qualifier::<TestComponent>("test");
+        assert_ne!(desc1, desc_with_qualifier);
+        assert_eq!(desc_with_qualifier.qualifier, Some("test"));
+    }
+
+    #[test]
+    fn test_container_creation() {
+        let container = ComponentContainer::new();
+        assert!(container.components.is_empty());
+        assert!(container.initializing.is_empty());
+        assert!(container.stats.is_empty());
+    }
+
+    #[test]
+    fn test_manual_component_registration() {
+        let container = ComponentContainer::new();
+        let test_component = Arc::new(TestComponent::new(100));
+
+        container.register_component(test_component.clone());
+
+        let retrieved: Option<Arc<TestComponent>> = container.get_component();
+        assert!(retrieved.is_some());
+        assert_eq!(retrieved.unwrap().value, 100);
+    }
+
+    #[test]
+    fn test_try_register_component_rejects_duplicates() {
+        let container = ComponentContainer::new();
+        container
+            .try_register_component(Arc::new(TestComponent::new(1)))
+            .unwrap();
+
+        let err = container
+            .try_register_component(Arc::new(TestComponent::new(2)))
+            .unwrap_err();
+        assert_eq!(
+            err.kind,
+            verdure_core::error::container::ContainerErrorKind::Configuration
+        );
+        assert!(err.message.contains("TestComponent"));
+        assert_eq!(container.get_component::<TestComponent>().unwrap().value, 1);
+
+        // register_component keeps replacing the instance
+        container.register_component(Arc::new(TestComponent::new(3)));
+        assert_eq!(container.get_component::<TestComponent>().unwrap().value, 3);
+    }
+
+    #[test]
+    fn test_register_component_by_type_id() {
+        let container = ComponentContainer::new();
+        let test_component = Arc::new(TestComponent::new(200));
+        let type_id = TypeId::of::<TestComponent>();
+
+        container.register_component_by_type_id(type_id, test_component);
+
+        let retrieved = container.get_component_by_type_id(type_id);
+        assert!(retrieved.is_some());
+
+        let downcast_component: Result<Arc<TestComponent>, _> = retrieved.unwrap().downcast();
+        assert!(downcast_component.is_ok());
+        assert_eq!(downcast_component.unwrap().value, 200);
+    }
+
+    #[test]
+    fn test_component_with_deps_initializer() {
+        let dependency = Arc::new(TestComponent::new(7));
+        let component = TestComponentWithDeps::__new((dependency.clone(),));
+
+        assert!(Arc::ptr_eq(&component.dependency, &dependency));
+        assert_eq!(component.value, "test");
+    }
+
+    #[test]
+    fn test_register_alias() {
+        struct Alias;
+        struct ChainedAlias;
+
+        let container = ComponentContainer::new();
+        let test_component = Arc::new(TestComponent::new(300));
+        container.register_component(test_component.clone());
+
+        container
+            .register_alias(TypeId::of::<Alias>(), TypeId::of::<TestComponent>())
+            .unwrap();
+        container
+            .register_alias(TypeId::of::<ChainedAlias>(), TypeId::of::<Alias>())
+            .unwrap();
+
+        for alias_id in [TypeId::of::<Alias>(), TypeId::of::<ChainedAlias>()] {
+            let retrieved = container.get_component_by_type_id(alias_id).unwrap();
+            let retrieved: Arc<TestComponent> = retrieved.downcast().unwrap();
+            assert!(Arc::ptr_eq(&retrieved, &test_component));
+        }
+        assert_eq!(container.components.len(), 1);
+    }
+
+    #[test]
+    fn test_register_alias_rejects_cycles() {
+        struct Alias;
+
+        let container = ComponentContainer::new();
+        let self_alias = container.register_alias(TypeId::of::<Alias>(), TypeId::of::<Alias>());
+        assert!(self_alias.is_err());
+
+        container
+            .register_alias(TypeId::of::<Alias>(), TypeId::of::<TestComponent>())
+            .unwrap();
+        let cycle = container.register_alias(TypeId::of::<TestComponent>(), TypeId::of::<Alias>());
+        assert_eq!(
+            cycle.unwrap_err().kind,
+            verdure_core::error::container::ContainerErrorKind::Configuration
+        );
+    }
+
+    #[test]
+    fn test_concurrent_aliases_cannot_form_a_cycle() {
+        struct Left;
+        struct Right;
+
+        for _ in 0..100 {
+            let container = ComponentContainer::new();
+            let results = std::thread::scope(|threads| {
+                let left = threads.spawn(|| {
+                    container.register_alias(TypeId::of::<Left>(), TypeId::of::<Right>())
+                });
+                let right = threads.spawn(|| {
+                    container.register_alias(TypeId::of::<Right>(), TypeId::of::<Left>())
+                });
+                [left.join().unwrap(), right.join().unwrap()]
+            });
+            assert_eq!(results.iter().filter(|result| result.is_ok()).count(), 1);
+        }
+    }
+
+    static WATCHED_CONTAINER: AtomicUsize = AtomicUsize::new(0);
+    static WATCHED_STARTS: AtomicUsize = AtomicUsize::new(0);
+
+    fn count_watched_starts(event: &ContainerLifecycleEvent) {
+        if let ContainerLifecycleEvent::InitializationStarted { container, .. } = event
+            && std::ptr::eq(
+                *container,
+                WATCHED_CONTAINER.load(Ordering::SeqCst) as *const ComponentContainer,
+            )
+        {
+            WATCHED_STARTS.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    crate::lifecycle_listener!("container_idempotency_listener", count_watched_starts);
+
+    #[test]
+    fn test_initialize_is_idempotent() {
+        let container = ComponentContainer::new();
+        WATCHED_CONTAINER.store(&container as *const _ as usize, Ordering::SeqCst);
+
+        assert!(!container.is_initialized());
+        container.initialize().unwrap();
+        container.initialize().unwrap();
+
+        assert!(container.is_initialized());
+        assert_eq!(WATCHED_STARTS.load(Ordering::SeqCst), 1);
+    }
+
+    struct SlowToCreate;
+
+    static SLOW_TO_CREATE: ComponentDefinition = ComponentDefinition::new(
+        TypeId::of::<SlowToCreate>,
+        "SlowToCreate",
+        || ComponentScope::Singleton,
+        Vec::new,
+        |_| {
+            std::thread::sleep(Duration::from_millis(20));
+            Ok(Arc::new(SlowToCreate))
+        },
+    );
+
+    #[test]
+    fn test_concurrent_initialize_waits_for_the_first_call() {
+        let container = ComponentContainer::with_definitions(vec![&SLOW_TO_CREATE]).unwrap();
+
+        std::thread::scope(|threads| {
+            for _ in 0..4 {
+                threads.spawn(|| {
+                    assert_eq!(container.initialize().unwrap(), 1);
+                    // Only set once the first call has finished
+                    assert!(container.initialized_component_count().is_some());
+                });
+            }
+        });
+        assert_eq!(container.creation_event_count(), 1);
+    }
+
+    static SLOW_WATCHED_CONTAINER: AtomicUsize = AtomicUsize::new(0);
+    static SLOW_EVENTS: AtomicUsize = AtomicUsize::new(0);
+
+    fn count_slow_components(event: &ContainerLifecycleEvent) {
+        if let ContainerLifecycleEvent::SlowComponentCreation {
+            container,
+            component_name,
+            ..
+        } = event
+            && std::ptr::eq(
+                *container,
+                SLOW_WATCHED_CONTAINER.load(Ordering::SeqCst) as *const ComponentContainer,
+            )
+        {
+            assert_eq!(*component_name, "SlowComponent");
+            SLOW_EVENTS.fetch_add(1, Ordering::SeqCst);
         }
+    }
+
+    crate::lifecycle_listener!("container_slow_component_listener", count_slow_components);
+
+    #[test]
+    fn test_slow_component_event() {
+        let slow = ComponentDefinition::new(
+            TypeId::of::<TestComponent>,
+            "SlowComponent",
+            || ComponentScope::Singleton,
+            Vec::new,
+            |_| {
+                std::thread::sleep(Duration::from_millis(20));
+                Ok(Arc::new(TestComponent::new(1)))
+            },
+        );
+        let mut def_map = HashMap::new();
+        def_map.insert(TypeId::of::<TestComponent>(), &slow);
+
+        let container =
+            ComponentContainer::new().with_slow_bean_threshold(Duration::from_millis(5));
+        SLOW_WATCHED_CONTAINER.store(&container as *const _ as usize, Ordering::SeqCst);
+
+        container
+            .resolve_bean(&ComponentDescriptor::for_type::<TestComponent>(), &def_map)
+            .unwrap();
+
+        assert_eq!(SLOW_EVENTS.load(Ordering::SeqCst), 1);
+    }
 
-        let def = match def_map.get(&descriptor.type_id) {
-            Some(d) => *d,
-            None => {
-                self.initializing.remove(&descriptor.type_id);
-                return Err(ContainerError::not_found(format!(
-                    "Bean definition not found for type ID {:?}",
-                    descriptor.type_id
-                )));
-            }
-        };
+    #[test]
+    fn test_post_processors_decorate_created_components() {
+        let definition = ComponentDefinition::new(
+            TypeId::of::<TestComponent>,
+            "TestComponent",
+            || ComponentScope::Singleton,
+            Vec::new,
+            |_| Ok(Arc::new(TestComponent::new(1))),
+        );
+        let mut def_map = HashMap::new();
+        def_map.insert(TypeId::of::<TestComponent>(), &definition);
 
-        let dependencies = (def.dependencies)();
-        let mut deps_map = HashMap::new();
-        for dep_id in dependencies {
-            let dep_descriptor = ComponentDescriptor::new(dep_id, None);
+        let container = ComponentContainer::new();
+        let seen = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let recorded = seen.clone();
+        container.add_post_processor(move |type_id, instance| {
+            recorded.lock().unwrap().push(type_id);
+            instance
+        });
+        container.add_post_processor(|_, instance| match instance.downcast::<TestComponent>() {
+            Ok(component) => Arc::new(TestComponent::new(component.value * 10)),
+            Err(instance) => instance,
+        });
 
-            // exist in components
-            if let Some(instance) = self.components.get(&dep_descriptor) {
-                deps_map.insert(dep_id, instance.clone());
-                continue;
-            }
+        container
+            .resolve_bean(&ComponentDescriptor::for_type::<TestComponent>(), &def_map)
+            .unwrap();
 
-            if let Some(_dep_def) = def_map.get(&dep_id) {
-                let dep_instance = self.resolve_bean(&dep_descriptor, def_map)?;
-                deps_map.insert(dep_id, dep_instance);
-            } else {
-                self.initializing.remove(&descriptor.type_id);
-                return Err(ContainerError::not_found(format!(
-                    "Dependency not found for type ID {:?}",
-                    dep_id
-                )));
-            }
-        }
+        let component: Arc<TestComponent> = container.get_component().unwrap();
+        assert_eq!(component.value, 10);
+        assert_eq!(*seen.lock().unwrap(), vec![TypeId::of::<TestComponent>()]);
 
-        let start = Instant::now();
-        let instance = match (def.creator)(deps_map) {
-            Ok(i) => i,
-            Err(e) => {
-                self.initializing.remove(&descriptor.type_id);
-                return Err(ContainerError::creation_failed(format!(
-                    "Failed to create bean '{}': '{}'",
-                    def.type_name, e
-                )));
-            }
-        };
-        let creation_time = start.elapsed();
+        // Manually registered instances are not processed
+        container.register_component(Arc::new(TestComponentWithDeps {
+            dependency: component,
+            value: "manual".to_string(),
+        }));
+        assert_eq!(seen.lock().unwrap().len(), 1);
+    }
 
-        self.lifecycle_publisher
-            .publish(&ContainerLifecycleEvent::ComponentCreated {
-                container: self,
-                component_name: def.type_name,
-                component_type_id: descriptor.type_id,
-                creation_duration: creation_time,
-            });
+    #[test]
+    fn test_initialization_order() {
+        fn definition(type_name: &'static str, order: fn() -> i32) -> ComponentDefinition {
+            ComponentDefinition::new(
+                TypeId::of::<TestComponent>,
+                type_name,
+                || ComponentScope::Singleton,
+                Vec::new,
+                |_| Ok(Arc::new(TestComponent::new(1))),
+            )
+            .with_order(order)
+        }
 
-        self.initializing.remove(&descriptor.type_id);
+        let metrics = definition("Metrics", || 10);
+        let logging = definition("Logging", || -10);
+        let cache = definition("Cache", || 0);
+        let audit = definition("Audit", || 0);
 
-        match (def.scope)() {
-            ComponentScope::Singleton => {
-                self.components.insert(descriptor.clone(), instance.clone());
-            }
-            _ => {}
-        };
+        let names: Vec<_> = initialization_order([&metrics, &logging, &cache, &audit])
+            .into_iter()
+            .map(|def| def.type_name)
+            .collect();
+        assert_eq!(names, vec!["Logging", "Audit", "Cache", "Metrics"]);
+    }
 
-        self.stats.insert(
-            descriptor.clone(),
-            ComponentStats {
-                created_at: Some(Instant::now()),
-                last_accessed: Some(Instant::now()),
-                access_count: 1,
-                creation_time: creation_time.as_millis() as u64,
-            },
+    #[test]
+    fn test_duplicate_definitions_name_both_origins() {
+        let derived = ComponentDefinition::new(
+            TypeId::of::<TestComponent>,
+            "TestComponent",
+            || ComponentScope::Singleton,
+            Vec::new,
+            |_| Ok(Arc::new(TestComponent::new(1))),
+        )
+        .with_origin(crate::ComponentOrigin {
+            module_path: "app::components",
+            file: "src/components.rs",
+            line: 10,
+        });
+        let manual = ComponentDefinition::new(
+            TypeId::of::<TestComponent>,
+            "TestComponent",
+            || ComponentScope::Singleton,
+            Vec::new,
+            |_| Ok(Arc::new(TestComponent::new(2))),
         );
 
-        Ok(instance)
+        let duplicates = find_duplicates([&derived, &manual]);
+        assert_eq!(duplicates.len(), 1);
+
+        let message = duplicate_definition_message(duplicates[0].0, duplicates[0].1);
+        assert!(message.contains("'TestComponent' is defined more than once"));
+        assert!(message.contains("app::components at src/components.rs:10"));
+        assert!(message.contains("a manual definition"));
+
+        assert!(find_duplicates([&derived]).is_empty());
     }
-}
 
-impl ComponentFactory for ComponentContainer {
-    fn get_component_by_type_id(&self, type_id: TypeId) -> Option<Arc<dyn Any + Send + Sync>> {
-        Some(
-            self.components
-                .get(&ComponentDescriptor::new(type_id, None))?
-                .clone(),
+    #[test]
+    fn test_creation_error_names_component_origin() {
+        let failing = ComponentDefinition::new(
+            TypeId::of::<TestComponent>,
+            "FailingComponent",
+            || ComponentScope::Singleton,
+            Vec::new,
+            |_| {
+                Err(
+                    verdure_core::error::component::ComponentError::CreationError(
+                        "boom".to_string(),
+                    ),
+                )
+            },
         )
+        .with_origin(crate::ComponentOrigin {
+            module_path: "app::failing",
+            file: "src/failing.rs",
+            line: 3,
+        });
+        let mut def_map = HashMap::new();
+        def_map.insert(TypeId::of::<TestComponent>(), &failing);
+
+        let container = ComponentContainer::new();
+        let message = container
+            .resolve_bean(&ComponentDescriptor::for_type::<TestComponent>(), &def_map)
+            .err()
+            .unwrap()
+            .to_string();
+        assert!(
+            message.contains("'FailingComponent' (declared in app::failing at src/failing.rs:3)")
+        );
     }
 
-    fn get_component<T: Any + Send + Sync>(&self) -> Option<Arc<T>> {
-        let component_any = self.get_component_by_type_id(TypeId::of::<T>())?;
-        component_any.downcast().ok()
+    #[test]
+    fn test_singleton_cannot_depend_on_request_scope() {
+        let request = ComponentDefinition::new(
+            TypeId::of::<TestComponent>,
+            "RequestComponent",
+            || ComponentScope::Request,
+            Vec::new,
+            |_| Ok(Arc::new(TestComponent::new(1))),
+        );
+        let singleton = ComponentDefinition::new(
+            TypeId::of::<TestComponentWithDeps>,
+            "SingletonComponent",
+            || ComponentScope::Singleton,
+            || vec![TypeId::of::<TestComponent>()],
+            |_| {
+                Err(
+                    verdure_core::error::component::ComponentError::CreationError(
+                        "unreachable".to_string(),
+                    ),
+                )
+            },
+        );
+        let mut def_map = HashMap::new();
+        def_map.insert(TypeId::of::<TestComponent>(), &request);
+        def_map.insert(TypeId::of::<TestComponentWithDeps>(), &singleton);
+
+        let container = ComponentContainer::new();
+        let result = container.resolve_bean(
+            &ComponentDescriptor::for_type::<TestComponentWithDeps>(),
+            &def_map,
+        );
+
+        let message = result.err().unwrap().to_string();
+        assert!(message.contains("RequestComponent"));
+        assert!(message.contains("SingletonComponent"));
+        assert!(container.initializing.is_empty());
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::ComponentInitializer;
-    use std::sync::atomic::{AtomicU32, Ordering};
+    struct DependsOnFlaky;
 
-    #[derive(Debug)]
-    struct TestComponent {
-        value: u32,
+    struct FlakyDependency;
+
+    static FLAKY_ATTEMPTS: AtomicUsize = AtomicUsize::new(0);
+
+    static DEPENDS_ON_FLAKY: ComponentDefinition = ComponentDefinition::new(
+        TypeId::of::<DependsOnFlaky>,
+        "DependsOnFlaky",
+        || ComponentScope::Singleton,
+        || vec![TypeId::of::<FlakyDependency>()],
+        |_| Ok(Arc::new(DependsOnFlaky)),
+    );
+
+    static FLAKY_DEPENDENCY: ComponentDefinition = ComponentDefinition::new(
+        TypeId::of::<FlakyDependency>,
+        "FlakyDependency",
+        || ComponentScope::Singleton,
+        Vec::new,
+        |_| {
+            if FLAKY_ATTEMPTS.fetch_add(1, Ordering::SeqCst) == 0 {
+                return Err(
+                    verdure_core::error::component::ComponentError::CreationError(
+                        "not ready yet".to_string(),
+                    ),
+                );
+            }
+            Ok(Arc::new(FlakyDependency))
+        },
+    );
+
+    #[test]
+    fn test_initialize_can_be_retried_after_nested_failure() {
+        let container =
+            ComponentContainer::with_definitions(vec![&DEPENDS_ON_FLAKY, &FLAKY_DEPENDENCY])
+                .unwrap();
+
+        // DependsOnFlaky is resolved first and fails while resolving its dependency
+        let error = container.initialize().err().unwrap();
+        assert!(error.to_string().contains("not ready yet"));
+        assert!(container.initializing.is_empty());
+
+        assert_eq!(container.initialize().unwrap(), 2);
+        assert!(container.get_component::<DependsOnFlaky>().is_some());
+        assert!(container.get_component::<FlakyDependency>().is_some());
     }
 
-    impl TestComponent {
-        fn new(value: u32) -> Self {
-            Self { value }
-        }
+    #[test]
+    fn test_logger_receives_lifecycle_messages() {
+        use std::sync::Mutex;
+
+        let messages = Arc::new(Mutex::new(Vec::new()));
+        let sink = messages.clone();
+
+        let container = ComponentContainer::new();
+        container.set_logger(move |level, message| {
+            sink.lock().unwrap().push((level, message.to_string()));
+        });
+        container.initialize().unwrap();
+
+        let messages = messages.lock().unwrap();
+        assert!(messages.iter().any(|(level, message)| {
+            *level == LogLevel::Info && message.starts_with("Container initialization started")
+        }));
+        assert!(messages.iter().any(|(level, message)| {
+            *level == LogLevel::Info && message.starts_with("Container initialization completed")
+        }));
     }
 
-    #[derive(Debug)]
-    struct TestComponentWithDeps {
-        dependency: Arc<TestComponent>,
-        value: String,
+    #[test]
+    fn test_get_component_required() {
+        use verdure_core::error::container::ContainerErrorKind;
+
+        let container = ComponentContainer::new();
+        let err = container
+            .get_component_required::<TestComponent>()
+            .unwrap_err();
+        assert_eq!(err.kind, ContainerErrorKind::NotFound);
+        assert!(err.message.contains("TestComponent"));
+
+        container.register_component(Arc::new(TestComponent::new(5)));
+        let component = container.get_component_required::<TestComponent>().unwrap();
+        assert_eq!(component.value, 5);
+
+        container.register_component_by_type_id(
+            TypeId::of::<TestComponentWithDeps>(),
+            Arc::new(TestComponent::new(6)),
+        );
+        let err = container
+            .get_component_required::<TestComponentWithDeps>()
+            .unwrap_err();
+        assert_eq!(err.kind, ContainerErrorKind::TypeCastFailed);
     }
 
-    static CREATION_COUNTER: AtomicU32 = AtomicU32::new(0);
+    #[test]
+    fn test_get_component_or_init_constructs_once() {
+        static CONSTRUCTIONS: AtomicU32 = AtomicU32::new(0);
 
-    impl ComponentInitializer for TestComponent {
-        type Dependencies = ();
+        let container = Arc::new(ComponentContainer::new());
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                let container = container.clone();
+                std::thread::spawn(move || {
+                    container
+                        .get_component_or_init(|| {
+                            CONSTRUCTIONS.fetch_add(1, Ordering::SeqCst);
+                            std::thread::sleep(Duration::from_millis(10));
+                            Arc::new(TestComponent::new(7))
+                        })
+                        .unwrap()
+                })
+            })
+            .collect();
 
-        fn __new(_deps: Self::Dependencies) -> Self {
-            CREATION_COUNTER.fetch_add(1, Ordering::SeqCst);
-            TestComponent::new(42)
-        }
+        let instances: Vec<Arc<TestComponent>> =
+            handles.into_iter().map(|h| h.join().unwrap()).collect();
 
-        fn __scope() -> crate::ComponentScope {
-            crate::ComponentScope::Singleton
-        }
+        assert_eq!(CONSTRUCTIONS.load(Ordering::SeqCst), 1);
+        assert!(instances.iter().all(|i| Arc::ptr_eq(i, &instances[0])));
+        assert_eq!(container.get_component::<TestComponent>().unwrap().value, 7);
     }
 
-    impl ComponentInitializer for TestComponentWithDeps {
-        type Dependencies = (Arc<TestComponent>,);
+    #[test]
+    fn test_register_lazy_creates_on_first_use() {
+        static LAZY_CONSTRUCTIONS: AtomicU32 = AtomicU32::new(0);
 
-        fn __new(deps: Self::Dependencies) -> Self {
-            let (dependency,) = deps;
-            TestComponentWithDeps {
-                dependency,
-                value: "test".to_string(),
+        let container = Arc::new(ComponentContainer::new());
+        container.register_lazy(|| {
+            LAZY_CONSTRUCTIONS.fetch_add(1, Ordering::SeqCst);
+            std::thread::sleep(Duration::from_millis(10));
+            Arc::new(TestComponent::new(9))
+        });
+        container.add_post_processor(|_, instance| {
+            let value = instance.downcast_ref::<TestComponent>().unwrap().value;
+            Arc::new(TestComponent::new(value + 1))
+        });
+        assert_eq!(LAZY_CONSTRUCTIONS.load(Ordering::SeqCst), 0);
+        assert_eq!(container.component_count(), 0);
+
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                let container = container.clone();
+                std::thread::spawn(move || container.get_component::<TestComponent>().unwrap())
+            })
+            .collect();
+        let instances: Vec<Arc<TestComponent>> =
+            handles.into_iter().map(|h| h.join().unwrap()).collect();
+
+        assert_eq!(LAZY_CONSTRUCTIONS.load(Ordering::SeqCst), 1);
+        assert!(instances.iter().all(|i| Arc::ptr_eq(i, &instances[0])));
+        assert_eq!(instances[0].value, 10);
+        assert_eq!(container.component_count(), 1);
+        assert_eq!(container.creation_event_count(), 1);
+    }
+
+    #[test]
+    fn test_register_lazy_satisfies_dependencies() {
+        let definition = ComponentDefinition::new(
+            TypeId::of::<TestComponentWithDeps>,
+            "TestComponentWithDeps",
+            || ComponentScope::Singleton,
+            || vec![TypeId::of::<TestComponent>()],
+            |deps| {
+                let dependency = deps[&TypeId::of::<TestComponent>()].clone();
+                Ok(Arc::new(TestComponentWithDeps {
+                    dependency: dependency.downcast().unwrap(),
+                    value: "lazy".to_string(),
+                }))
+            },
+        );
+        let mut def_map = HashMap::new();
+        def_map.insert(TypeId::of::<TestComponentWithDeps>(), &definition);
+
+        let container = ComponentContainer::new();
+        container.register_lazy(|| Arc::new(TestComponent::new(3)));
+        container
+            .resolve_bean(
+                &ComponentDescriptor::for_type::<TestComponentWithDeps>(),
+                &def_map,
+            )
+            .unwrap();
+
+        let component: Arc<TestComponentWithDeps> = container.get_component().unwrap();
+        assert_eq!(component.dependency.value, 3);
+        assert!(Arc::ptr_eq(
+            &component.dependency,
+            &container.get_component::<TestComponent>().unwrap()
+        ));
+    }
+
+    struct Registrar;
+
+    struct Registered(u32);
+
+    static REGISTRAR: ComponentDefinition = ComponentDefinition::new(
+        TypeId::of::<Registrar>,
+        "Registrar",
+        || ComponentScope::Singleton,
+        Vec::new,
+        |_| Ok(Arc::new(Registrar)),
+    );
+
+    static REGISTERED: ComponentDefinition = ComponentDefinition::new(
+        TypeId::of::<Registered>,
+        "Registered",
+        || ComponentScope::Singleton,
+        Vec::new,
+        |_| Ok(Arc::new(Registered(1))),
+    )
+    .with_order(|| 1);
+
+    #[test]
+    fn test_post_processor_registers_discovered_component() {
+        let container =
+            Arc::new(ComponentContainer::with_definitions(vec![&REGISTRAR, &REGISTERED]).unwrap());
+        let registrar = Arc::downgrade(&container);
+        container.add_post_processor(move |type_id, instance| {
+            if type_id == TypeId::of::<Registrar>()
+                && let Some(container) = registrar.upgrade()
+            {
+                container.register_component(Arc::new(Registered(2)));
             }
+            instance
+        });
+
+        assert_eq!(container.initialize().unwrap(), 2);
+        assert_eq!(container.creation_event_count(), 1);
+        assert_eq!(container.with_component(|r: &Registered| r.0), Some(2));
+    }
+
+    #[test]
+    fn test_creation_event_count() {
+        let prototype = ComponentDefinition::new(
+            TypeId::of::<TestComponent>,
+            "TestComponent",
+            || ComponentScope::Prototype,
+            Vec::new,
+            |_| Ok(Arc::new(TestComponent::new(1))),
+        );
+        let singleton = ComponentDefinition::new(
+            TypeId::of::<TestComponentWithDeps>,
+            "TestComponentWithDeps",
+            || ComponentScope::Singleton,
+            || vec![TypeId::of::<TestComponent>()],
+            |deps| {
+                let dependency = deps[&TypeId::of::<TestComponent>()].clone();
+                Ok(Arc::new(TestComponentWithDeps {
+                    dependency: dependency.downcast().unwrap(),
+                    value: "counted".to_string(),
+                }))
+            },
+        );
+        let mut def_map = HashMap::new();
+        def_map.insert(TypeId::of::<TestComponent>(), &prototype);
+        def_map.insert(TypeId::of::<TestComponentWithDeps>(), &singleton);
+
+        let container = ComponentContainer::new();
+        container
+            .resolve_bean(
+                &ComponentDescriptor::for_type::<TestComponentWithDeps>(),
+                &def_map,
+            )
+            .unwrap();
+        assert_eq!(container.creation_event_count(), 2);
+        assert_eq!(container.component_count(), 1);
+
+        // Every discovered singleton publishes one event and is held afterwards
+        let container = ComponentContainer::new();
+        container.initialize().unwrap();
+        let singletons = container
+            .definitions()
+            .values()
+            .filter(|def| (def.scope)() == ComponentScope::Singleton)
+            .count();
+        assert_eq!(container.creation_event_count(), singletons);
+        assert_eq!(container.component_count(), singletons);
+    }
+
+    #[test]
+    fn test_recent_events_record_startup_timeline() {
+        let container = ComponentContainer::new().with_event_history(64);
+        container.initialize().unwrap();
+
+        let events = container.recent_events();
+        assert_eq!(events.first().unwrap().event, "InitializationStarted");
+        let completed = events.last().unwrap();
+        assert_eq!(completed.event, "InitializationCompleted");
+        assert_eq!(completed.component_count, Some(container.component_count()));
+        assert!(completed.duration.is_some());
+
+        let created = events
+            .iter()
+            .filter(|record| record.event == "ComponentCreated")
+            .count();
+        assert_eq!(created, container.creation_event_count());
+        assert!(ComponentContainer::new().recent_events().is_empty());
+    }
+
+    #[test]
+    fn test_get_component_or_init_returns_registered() {
+        let container = ComponentContainer::new();
+        container.register_component(Arc::new(TestComponent::new(1)));
+
+        let component = container
+            .get_component_or_init(|| Arc::new(TestComponent::new(2)))
+            .unwrap();
+
+        assert_eq!(component.value, 1);
+    }
+
+    trait Greeter: Send + Sync {
+        fn greet(&self) -> String;
+    }
+
+    struct English;
+
+    impl Greeter for English {
+        fn greet(&self) -> String {
+            "hello".to_string()
         }
+    }
 
-        fn __scope() -> crate::ComponentScope {
-            crate::ComponentScope::Singleton
+    struct French;
+
+    impl Greeter for French {
+        fn greet(&self) -> String {
+            "bonjour".to_string()
         }
     }
 
     #[test]
-    fn test_component_descriptor() {
-        let desc1 = ComponentDescriptor::for_type::<TestComponent>();
-        let desc2 = ComponentDescriptor::new(TypeId::of::<TestComponent>(), None);
-        assert_eq!(desc1, desc2);
+    fn test_trait_component_single_implementation() {
+        let container = ComponentContainer::test();
+        assert!(container.get_trait_component::<dyn Greeter>().is_err());
 
-        let desc_with_qualifier = ComponentDescriptor::with_qualifier::<TestComponent>("test");
-        assert_ne!(desc1, desc_with_qualifier);
-        assert_eq!(desc_with_qualifier.qualifier, Some("test"));
+        container
+            .register_trait_component::<dyn Greeter>("english", Arc::new(English))
+            .unwrap();
+        let greeter = container.get_trait_component::<dyn Greeter>().unwrap();
+        assert_eq!(greeter.greet(), "hello");
+
+        let instance = container
+            .get_component_by_type_id(TypeId::of::<Arc<dyn Greeter>>())
+            .unwrap();
+        assert!(instance.downcast::<Arc<dyn Greeter>>().is_ok());
+
+        let duplicate =
+            container.register_trait_component::<dyn Greeter>("english", Arc::new(English));
+        assert!(duplicate.is_err());
     }
 
     #[test]
-    fn test_container_creation() {
-        let container = ComponentContainer::new();
-        assert!(container.components.is_empty());
-        assert!(container.initializing.is_empty());
-        assert!(container.stats.is_empty());
+    fn test_trait_component_multiple_implementations_need_qualifier() {
+        let container = ComponentContainer::test();
+        container
+            .register_trait_component::<dyn Greeter>("english", Arc::new(English))
+            .unwrap();
+        container
+            .register_trait_component::<dyn Greeter>("french", Arc::new(French))
+            .unwrap();
+
+        let message = container
+            .get_trait_component::<dyn Greeter>()
+            .err()
+            .unwrap()
+            .to_string();
+        assert!(message.contains("has 2 implementations (english, french)"));
+        assert!(
+            container
+                .get_component_by_type_id(TypeId::of::<Arc<dyn Greeter>>())
+                .is_none()
+        );
+
+        let french = container
+            .get_qualified_trait_component::<dyn Greeter>("french")
+            .unwrap();
+        assert_eq!(french.greet(), "bonjour");
+        assert!(
+            container
+                .get_qualified_trait_component::<dyn Greeter>("german")
+                .is_err()
+        );
     }
 
     #[test]
-    fn test_manual_component_registration() {
-        let container = ComponentContainer::new();
-        let test_component = Arc::new(TestComponent::new(100));
+    fn test_last_initialization_is_recorded() {
+        let container = ComponentContainer::test();
+        assert!(container.last_init_duration().is_none());
+        assert!(container.initialized_component_count().is_none());
 
-        container.register_component(test_component.clone());
+        container.register_component(Arc::new(TestComponent::new(1)));
+        container.initialize().unwrap();
+        assert!(container.last_init_duration().is_some());
+        assert_eq!(container.initialized_component_count(), Some(1));
 
-        let retrieved: Option<Arc<TestComponent>> = container.get_component();
-        assert!(retrieved.is_some());
-        assert_eq!(retrieved.unwrap().value, 100);
+        container.register_component(Arc::new(String::from("later")));
+        assert_eq!(container.initialized_component_count(), Some(1));
+        assert_eq!(container.component_count(), 2);
     }
 
     #[test]
-    fn test_register_component_by_type_id() {
+    fn test_with_component_borrows_without_cloning() {
+        let container = ComponentContainer::test();
+        assert!(
+            container
+                .with_component(|c: &TestComponent| c.value)
+                .is_none()
+        );
+
+        let component = Arc::new(TestComponent::new(7));
+        container.register_component(component.clone());
+
+        let value = container.with_component(|c: &TestComponent| {
+            assert_eq!(Arc::strong_count(&component), 2);
+            c.value
+        });
+        assert_eq!(value, Some(7));
+        assert!(container.with_component(|_: &String| ()).is_none());
+
+        container.register_lazy(|| Arc::new(String::from("lazy")));
+        assert_eq!(container.with_component(|s: &String| s.len()), Some(4));
+        assert_eq!(container.with_component(|s: &String| s.len()), Some(4));
+    }
+
+    #[test]
+    fn test_find_components() {
+        let container = ComponentContainer::test();
+        container.register_component(Arc::new(TestComponent::new(1)));
+        assert!(container.find_components(|_, _| true).is_empty());
+
+        container
+            .get_component_or_init(|| Arc::new(String::from("created")))
+            .unwrap();
+        let all = container.find_components(|_, _| true);
+        assert_eq!(all.len(), 1);
+        assert!(all[0].1.created_at.is_some());
+
+        let string_id = TypeId::of::<String>();
+        assert_eq!(
+            container
+                .find_components(|descriptor, _| descriptor.type_id == string_id)
+                .len(),
+            1
+        );
+        assert!(
+            container
+                .find_components(|_, stats| stats.creation_time > 60_000)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_for_each_component() {
+        trait Named: Send + Sync {}
+        impl Named for TestComponent {}
+
+        let container = ComponentContainer::test();
+        let component = Arc::new(TestComponent::new(7));
+        container.register_component(component.clone());
+        container.register_component(Arc::new(String::from("text")));
+        container
+            .register_trait_component::<dyn Named>("test", component)
+            .unwrap();
+
+        let mut visited = Vec::new();
+        container.for_each_component(|name, instance| {
+            // The container is not locked while visiting
+            assert!(container.component_count() > 0);
+            if let Some(component) = instance.downcast_ref::<TestComponent>() {
+                visited.push(component.value);
+            }
+            assert!(!name.is_empty());
+        });
+        assert_eq!(visited, vec![7]);
+
+        let mut count = 0;
+        container.for_each_component(|_, _| count += 1);
+        assert_eq!(count, 2);
+    }
+
+    #[test]
+    fn test_test_container_ignores_inventory() {
+        let container = ComponentContainer::test();
+        container.register_component(Arc::new(TestComponent::new(3)));
+        assert_eq!(container.initialize().unwrap(), 0);
+
+        assert_eq!(container.component_count(), 1);
+        assert!(container.registered_type_names().is_empty());
+        assert!(container.check_wiring().is_empty());
+        assert!(
+            container
+                .open_scope()
+                .resolve(TypeId::of::<String>())
+                .is_err()
+        );
+        assert!(!ComponentContainer::new().registered_type_names().is_empty());
+    }
+
+    #[test]
+    fn test_component_count() {
         let container = ComponentContainer::new();
-        let test_component = Arc::new(TestComponent::new(200));
-        let type_id = TypeId::of::<TestComponent>();
+        assert_eq!(container.component_count(), 0);
 
-        container.register_component_by_type_id(type_id, test_component);
+        container.register_component(Arc::new(TestComponent::new(1)));
+        container.register_component(Arc::new("config".to_string()));
+        assert_eq!(container.component_count(), 2);
 
-        let retrieved = container.get_component_by_type_id(type_id);
-        assert!(retrieved.is_some());
+        container.register_component(Arc::new(TestComponent::new(2)));
+        assert_eq!(container.component_count(), 2);
+    }
 
-        let downcast_component: Result<Arc<TestComponent>, _> = retrieved.unwrap().downcast();
-        assert!(downcast_component.is_ok());
-        assert_eq!(downcast_component.unwrap().value, 200);
+    #[test]
+    fn test_registered_type_names() {
+        let names = ComponentContainer::new().registered_type_names();
+        assert!(names.contains(&"RequestState"));
+        assert!(names.windows(2).all(|w| w[0] <= w[1]));
+    }
+
+    #[test]
+    fn test_render_dot() {
+        let graph = vec![
+            (
+                "UserService".to_string(),
+                vec!["Database".to_string(), "Cache".to_string()],
+            ),
+            ("Database".to_string(), vec![]),
+        ];
+
+        assert_eq!(
+            render_dot(&graph),
+            "digraph components {\n    \"UserService\" -> \"Database\";\n    \"UserService\" -> \"Cache\";\n    \"Database\";\n}\n"
+        );
     }
 
     #[test]
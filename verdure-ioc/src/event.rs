@@ -7,6 +7,8 @@
 
 use crate::container::ComponentContainer;
 use std::any::TypeId;
+use std::collections::VecDeque;
+use std::sync::Mutex;
 use std::time::Duration;
 
 /// Container lifecycle events enumeration
@@ -30,6 +32,9 @@ use std::time::Duration;
 ///         ContainerLifecycleEvent::ComponentCreated { component_name, .. } => {
 ///             println!("Created component: {}", component_name);
 ///         }
+///         ContainerLifecycleEvent::SlowComponentCreation { component_name, creation_duration, .. } => {
+///             eprintln!("Component {} took {:?} to create", component_name, creation_duration);
+///         }
 ///     }
 /// }
 /// ```
@@ -61,6 +66,126 @@ pub enum ContainerLifecycleEvent<'a> {
         /// Time taken to create this specific component
         creation_duration: Duration,
     },
+    /// Fired after `ComponentCreated` when creating a component took longer than the
+    /// container's slow component threshold
+    SlowComponentCreation {
+        /// Reference to the container
+        container: &'a ComponentContainer,
+        /// Human-readable name of the component type
+        component_name: &'static str,
+        /// TypeId of the slow component
+        component_type_id: TypeId,
+        /// Time taken to create this specific component
+        creation_duration: Duration,
+        /// The threshold that was exceeded
+        threshold: Duration,
+    },
+}
+
+impl ContainerLifecycleEvent<'_> {
+    /// Returns the name of the event variant, e.g. `"ComponentCreated"`
+    pub fn name(&self) -> &'static str {
+        match self {
+            ContainerLifecycleEvent::InitializationStarted { .. } => "InitializationStarted",
+            ContainerLifecycleEvent::InitializationCompleted { .. } => "InitializationCompleted",
+            ContainerLifecycleEvent::ComponentCreated { .. } => "ComponentCreated",
+            ContainerLifecycleEvent::SlowComponentCreation { .. } => "SlowComponentCreation",
+        }
+    }
+}
+
+/// An owned copy of a [`ContainerLifecycleEvent`], kept by the container's event history
+///
+/// Unlike the event itself, a record does not borrow the container, so it can be kept
+/// and inspected after initialization, e.g. to print a startup timeline.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LifecycleEventRecord {
+    /// Name of the event, see [`ContainerLifecycleEvent::name`]
+    pub event: &'static str,
+    /// Name of the component the event is about, for component events
+    pub component_name: Option<&'static str>,
+    /// TypeId of the component the event is about, for component events
+    pub component_type_id: Option<TypeId>,
+    /// Number of components reported by initialization events
+    pub component_count: Option<usize>,
+    /// Creation time for component events, total time for `InitializationCompleted`
+    pub duration: Option<Duration>,
+}
+
+impl From<&ContainerLifecycleEvent<'_>> for LifecycleEventRecord {
+    fn from(event: &ContainerLifecycleEvent<'_>) -> Self {
+        let mut record = LifecycleEventRecord {
+            event: event.name(),
+            component_name: None,
+            component_type_id: None,
+            component_count: None,
+            duration: None,
+        };
+        match event {
+            ContainerLifecycleEvent::InitializationStarted {
+                component_count, ..
+            } => {
+                record.component_count = Some(*component_count);
+            }
+            ContainerLifecycleEvent::InitializationCompleted {
+                component_count,
+                duration,
+                ..
+            } => {
+                record.component_count = Some(*component_count);
+                record.duration = Some(*duration);
+            }
+            ContainerLifecycleEvent::ComponentCreated {
+                component_name,
+                component_type_id,
+                creation_duration,
+                ..
+            }
+            | ContainerLifecycleEvent::SlowComponentCreation {
+                component_name,
+                component_type_id,
+                creation_duration,
+                ..
+            } => {
+                record.component_name = Some(*component_name);
+                record.component_type_id = Some(*component_type_id);
+                record.duration = Some(*creation_duration);
+            }
+        }
+        record
+    }
+}
+
+/// The most recent lifecycle events of a container, oldest first
+pub(crate) struct EventHistory {
+    capacity: usize,
+    records: Mutex<VecDeque<LifecycleEventRecord>>,
+}
+
+impl EventHistory {
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            records: Mutex::new(VecDeque::with_capacity(capacity)),
+        }
+    }
+
+    /// Appends a record of `event`, dropping the oldest one if the history is full
+    pub(crate) fn record(&self, event: &ContainerLifecycleEvent) {
+        if self.capacity == 0 {
+            return;
+        }
+        let mut records = self.records.lock().unwrap_or_else(|e| e.into_inner());
+        if records.len() == self.capacity {
+            records.pop_front();
+        }
+        records.push_back(event.into());
+    }
+
+    pub(crate) fn records(&self) -> Vec<LifecycleEventRecord> {
+        let records = self.records.lock().unwrap_or_else(|e| e.into_inner());
+        records.iter().cloned().collect()
+    }
 }
 
 /// Trait for implementing lifecycle event listeners
@@ -185,6 +310,12 @@ impl LifecycleEventPublisher {
     }
 }
 
+impl Default for LifecycleEventPublisher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -209,9 +340,49 @@ mod tests {
             ContainerLifecycleEvent::ComponentCreated { .. } => {
                 LAST_EVENT_TYPE.store(3, Ordering::SeqCst);
             }
+            ContainerLifecycleEvent::SlowComponentCreation { .. } => {
+                LAST_EVENT_TYPE.store(4, Ordering::SeqCst);
+            }
         }
     }
 
+    #[test]
+    fn test_event_history_keeps_most_recent_records() {
+        let container = ComponentContainer::new();
+        let history = EventHistory::new(2);
+        history.record(&ContainerLifecycleEvent::InitializationStarted {
+            container: &container,
+            component_count: 1,
+        });
+        history.record(&ContainerLifecycleEvent::ComponentCreated {
+            container: &container,
+            component_name: "Service",
+            component_type_id: TypeId::of::<u32>(),
+            creation_duration: Duration::from_millis(3),
+        });
+        history.record(&ContainerLifecycleEvent::InitializationCompleted {
+            container: &container,
+            component_count: 1,
+            duration: Duration::from_millis(5),
+        });
+
+        let records = history.records();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].event, "ComponentCreated");
+        assert_eq!(records[0].component_name, Some("Service"));
+        assert_eq!(records[0].component_type_id, Some(TypeId::of::<u32>()));
+        assert_eq!(records[0].duration, Some(Duration::from_millis(3)));
+        assert_eq!(records[1].event, "InitializationCompleted");
+        assert_eq!(records[1].component_count, Some(1));
+
+        let disabled = EventHistory::new(0);
+        disabled.record(&ContainerLifecycleEvent::InitializationStarted {
+            container: &container,
+            component_count: 1,
+        });
+        assert!(disabled.records().is_empty());
+    }
+
     #[test]
     fn test_lifecycle_listener_definition() {
         let definition = LifecycleListenerDefinition {
@@ -238,10 +409,11 @@ mod tests {
     #[test]
     fn test_lifecycle_event_publisher_creation() {
         let publisher = LifecycleEventPublisher::new();
-        // The listeners vec will be populated from inventory,
-        // but since we're in a test environment without registered listeners,
-        // it should be empty or contain only test listeners
-        assert!(publisher.listeners.len() >= 0);
+        // The listeners vec is populated from inventory
+        assert_eq!(
+            publisher.listeners.len(),
+            inventory::iter::<LifecycleListenerDefinition>().count()
+        );
     }
 
     #[test]
@@ -336,6 +508,7 @@ mod tests {
     #[test]
     fn test_lifecycle_listener_trait() {
         let listener = MockLifecycleListener::new("mock_listener");
+        assert_eq!(listener.name, "mock_listener");
         assert_eq!(listener.get_event_count(), 0);
 
         let container = ComponentContainer::new();
@@ -394,6 +567,13 @@ mod tests {
                 component_type_id: std::any::TypeId::of::<String>(),
                 creation_duration: Duration::from_millis(5),
             },
+            ContainerLifecycleEvent::SlowComponentCreation {
+                container: &container,
+                component_name: "Test",
+                component_type_id: std::any::TypeId::of::<String>(),
+                creation_duration: Duration::from_millis(600),
+                threshold: Duration::from_millis(500),
+            },
         ];
 
         let mut event_types = Vec::new();
@@ -409,9 +589,12 @@ mod tests {
                 ContainerLifecycleEvent::ComponentCreated { .. } => {
                     event_types.push("created");
                 }
+                ContainerLifecycleEvent::SlowComponentCreation { .. } => {
+                    event_types.push("slow");
+                }
             }
         }
 
-        assert_eq!(event_types, vec!["started", "completed", "created"]);
+        assert_eq!(event_types, vec!["started", "completed", "created", "slow"]);
     }
 }
@@ -54,16 +54,24 @@
 mod component;
 mod container;
 mod event;
+mod scope;
+#[cfg(any(test, feature = "test-support"))]
+mod test_support;
 
 pub use component::{
-    ComponentDefinition, ComponentInitializer, ComponentInstance, ComponentScope,
-    factory::ComponentFactory,
+    ComponentDefinition, ComponentInitializer, ComponentInstance, ComponentOrigin,
+    ComponentScope, factory::ComponentFactory,
 };
 
-pub use container::ComponentContainer;
+pub use container::{ComponentContainer, ComponentDescriptor, ComponentStats};
+
+pub use scope::ScopeContext;
+
+#[cfg(any(test, feature = "test-support"))]
+pub use test_support::IsolatedContainer;
 
 pub use event::{
-    ContainerLifecycleEvent, LifecycleEventPublisher, LifecycleListener,
+    ContainerLifecycleEvent, LifecycleEventPublisher, LifecycleEventRecord, LifecycleListener,
     LifecycleListenerDefinition,
 };
 
@@ -0,0 +1,255 @@
+//! Request scope support
+//!
+//! This module provides [`ScopeContext`], a short-lived handle opened with
+//! [`ComponentContainer::open_scope`]. Components declared with
+//! [`ComponentScope::Request`] are created at most once per scope and dropped together
+//! with it, while singletons keep being served by the underlying container.
+
+use crate::container::ResolvingGuard;
+use crate::{ComponentContainer, ComponentFactory, ComponentInstance, ComponentScope};
+use dashmap::{DashMap, DashSet};
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::sync::Arc;
+use verdure_core::error::container::ContainerError;
+
+/// A scope holding request-scoped component instances
+///
+/// Components are resolved in the following order:
+///
+/// 1. Request-scoped instances already created in this scope
+/// 2. Components held by the container (singletons and manual registrations)
+/// 3. Registered definitions - singletons are created by the container, request-scoped
+///    and prototype components are created in this scope with their dependencies
+///    resolved through the scope
+///
+/// # Examples
+///
+/// ```rust
+/// use verdure_ioc::{ComponentContainer, ComponentFactory};
+/// use std::sync::Arc;
+///
+/// struct Config {
+///     name: String,
+/// }
+///
+/// let container = ComponentContainer::new();
+/// container.register_component(Arc::new(Config { name: "app".to_string() }));
+///
+/// let scope = container.open_scope();
+/// let config: Arc<Config> = scope.get_component().unwrap();
+/// assert_eq!(config.name, "app");
+/// ```
+pub struct ScopeContext<'a> {
+    container: &'a ComponentContainer,
+    instances: DashMap<TypeId, ComponentInstance>,
+    resolving: DashSet<TypeId>,
+}
+
+impl<'a> ScopeContext<'a> {
+    pub(crate) fn new(container: &'a ComponentContainer) -> Self {
+        Self {
+            container,
+            instances: DashMap::new(),
+            resolving: DashSet::new(),
+        }
+    }
+
+    /// Returns the container this scope was opened from
+    pub fn container(&self) -> &ComponentContainer {
+        self.container
+    }
+
+    /// Returns the number of request-scoped instances created in this scope
+    pub fn instance_count(&self) -> usize {
+        self.instances.len()
+    }
+
+    /// Resolves a component by type, creating it if necessary
+    ///
+    /// # Arguments
+    ///
+    /// * `type_id` - The TypeId of the component to resolve
+    ///
+    /// # Returns
+    ///
+    /// The component instance, or a `ContainerError` if it is unknown, has a circular
+    /// dependency or fails to be created
+    pub fn resolve(&self, type_id: TypeId) -> Result<ComponentInstance, ContainerError> {
+        if let Some(instance) = self.instances.get(&type_id) {
+            return Ok(instance.clone());
+        }
+        if let Some(instance) = self.container.get_component_by_type_id(type_id) {
+            return Ok(instance);
+        }
+
+        let def = match self.container.definitions().get(&type_id) {
+            Some(def) => *def,
+            None => {
+                return Err(ContainerError::not_found(format!(
+                    "Bean definition not found for type ID {:?}",
+                    type_id
+                )));
+            }
+        };
+
+        let scope = (def.scope)();
+        if scope == ComponentScope::Singleton {
+            return self.container.resolve_registered(type_id);
+        }
+
+        let Some(resolving) = ResolvingGuard::enter(&self.resolving, type_id) else {
+            return Err(ContainerError::circular_dependency(def.describe()));
+        };
+
+        let mut deps_map = HashMap::new();
+        for dep_id in (def.dependencies)() {
+            deps_map.insert(dep_id, self.resolve(dep_id)?);
+        }
+
+        let result = (def.creator)(deps_map);
+        drop(resolving);
+        let instance = result.map_err(|e| {
+            ContainerError::creation_failed(format!(
+                "Failed to create bean {}: '{}'",
+                def.describe(),
+                e
+            ))
+        })?;
+        let instance = self.container.post_process(type_id, instance);
+
+        if scope == ComponentScope::Request {
+            self.instances.insert(type_id, instance.clone());
+        }
+        Ok(instance)
+    }
+}
+
+impl ComponentFactory for ScopeContext<'_> {
+    fn get_component_by_type_id(&self, type_id: TypeId) -> Option<Arc<dyn Any + Send + Sync>> {
+        self.resolve(type_id).ok()
+    }
+
+    fn get_component<T: Any + Send + Sync>(&self) -> Option<Arc<T>> {
+        let component_any = self.get_component_by_type_id(TypeId::of::<T>())?;
+        component_any.downcast().ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ComponentDefinition;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    struct SharedConfig {
+        name: &'static str,
+    }
+
+    struct RequestState {
+        id: u32,
+    }
+
+    struct SharedPool;
+
+    static REQUEST_STATE_COUNTER: AtomicU32 = AtomicU32::new(0);
+
+    inventory::submit! {
+        ComponentDefinition::new(
+            TypeId::of::<RequestState>,
+            "RequestState",
+            || ComponentScope::Request,
+            Vec::new,
+            |_deps| {
+                let id = REQUEST_STATE_COUNTER.fetch_add(1, Ordering::SeqCst);
+                Ok(Arc::new(RequestState { id }))
+            },
+        )
+    }
+
+    inventory::submit! {
+        ComponentDefinition::new(
+            TypeId::of::<SharedPool>,
+            "SharedPool",
+            || ComponentScope::Singleton,
+            Vec::new,
+            |_deps| {
+                std::thread::sleep(std::time::Duration::from_millis(5));
+                Ok(Arc::new(SharedPool))
+            },
+        )
+    }
+
+    #[test]
+    fn test_request_scope_caches_per_scope() {
+        let container = ComponentContainer::new();
+
+        let first_scope = container.open_scope();
+        let a: Arc<RequestState> = first_scope.get_component().unwrap();
+        let b: Arc<RequestState> = first_scope.get_component().unwrap();
+        assert!(Arc::ptr_eq(&a, &b));
+        assert_eq!(first_scope.instance_count(), 1);
+
+        let second_scope = container.open_scope();
+        let c: Arc<RequestState> = second_scope.get_component().unwrap();
+        assert!(!Arc::ptr_eq(&a, &c));
+        assert_ne!(a.id, c.id);
+
+        // Request-scoped instances never end up in the container itself
+        assert!(container.get_component::<RequestState>().is_none());
+    }
+
+    #[test]
+    fn test_request_scope_drops_instances() {
+        let container = ComponentContainer::new();
+        let scope = container.open_scope();
+        let state: Arc<RequestState> = scope.get_component().unwrap();
+        assert_eq!(Arc::strong_count(&state), 2);
+
+        drop(scope);
+        assert_eq!(Arc::strong_count(&state), 1);
+    }
+
+    #[test]
+    fn test_scope_falls_back_to_container() {
+        let container = ComponentContainer::new();
+        container.register_component(Arc::new(SharedConfig { name: "shared" }));
+
+        let scope = container.open_scope();
+        let config: Arc<SharedConfig> = scope.get_component().unwrap();
+        assert_eq!(config.name, "shared");
+        assert_eq!(scope.instance_count(), 0);
+    }
+
+    #[test]
+    fn test_concurrent_scopes_create_singleton_once() {
+        let container = ComponentContainer::new();
+        let creations = Arc::new(AtomicU32::new(0));
+        let counter = creations.clone();
+        container.add_post_processor(move |type_id, instance| {
+            if type_id == TypeId::of::<SharedPool>() {
+                counter.fetch_add(1, Ordering::SeqCst);
+            }
+            instance
+        });
+
+        let pools: Vec<Arc<SharedPool>> = std::thread::scope(|threads| {
+            let handles: Vec<_> = (0..16)
+                .map(|_| threads.spawn(|| container.open_scope().get_component().unwrap()))
+                .collect();
+            handles.into_iter().map(|h| h.join().unwrap()).collect()
+        });
+
+        assert_eq!(creations.load(Ordering::SeqCst), 1);
+        assert!(pools.iter().all(|pool| Arc::ptr_eq(pool, &pools[0])));
+    }
+
+    #[test]
+    fn test_scope_unknown_component() {
+        let container = ComponentContainer::new();
+        let scope = container.open_scope();
+
+        assert!(scope.get_component::<SharedConfig>().is_none());
+        assert!(scope.resolve(TypeId::of::<SharedConfig>()).is_err());
+    }
+}
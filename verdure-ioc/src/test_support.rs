@@ -0,0 +1,230 @@
+//! Isolated containers for tests
+//!
+//! This module is available with the `test-support` feature. `inventory` registrations
+//! are global to a binary, so a container created with [`ComponentContainer::new`] sees
+//! every `#[derive(Component)]` linked into the test binary, including those of other
+//! tests. [`IsolatedContainer`] builds a container from an explicit set of definitions
+//! and mocks instead.
+
+use crate::{ComponentContainer, ComponentDefinition};
+use std::any::Any;
+use std::sync::Arc;
+use verdure_core::error::container::ContainerError;
+
+/// A registration applied to the container when it is built
+type Registration = Box<dyn FnOnce(&ComponentContainer) -> Result<(), ContainerError>>;
+
+/// Builder for a container that only knows the definitions and mocks given to it
+///
+/// Mocks are registered before the container is initialized, so definitions depending
+/// on a mocked type receive the mock, and a definition of a mocked type is not created.
+///
+/// # Examples
+///
+/// ```rust
+/// use verdure_ioc::{ComponentFactory, IsolatedContainer};
+/// use std::sync::Arc;
+///
+/// struct MockClock;
+///
+/// // Only the components declared in this module, plus a mock
+/// let container = IsolatedContainer::from_inventory(|def| {
+///     def.origin
+///         .is_some_and(|origin| origin.module_path.starts_with(module_path!()))
+/// })
+/// .with_mock(Arc::new(MockClock))
+/// .initialize()
+/// .unwrap();
+///
+/// assert!(container.get_component::<MockClock>().is_some());
+/// ```
+pub struct IsolatedContainer {
+    definitions: Vec<&'static ComponentDefinition>,
+    registrations: Vec<Registration>,
+}
+
+impl IsolatedContainer {
+    /// Creates a builder without definitions or mocks
+    pub fn new() -> Self {
+        Self {
+            definitions: Vec::new(),
+            registrations: Vec::new(),
+        }
+    }
+
+    /// Creates a builder with the `inventory` definitions accepted by `filter`
+    ///
+    /// # Arguments
+    ///
+    /// * `filter` - Returns whether a discovered definition is used
+    pub fn from_inventory<F>(filter: F) -> Self
+    where
+        F: Fn(&ComponentDefinition) -> bool,
+    {
+        Self::new().with_definitions(
+            inventory::iter::<ComponentDefinition>
+                .into_iter()
+                .filter(|def| filter(def)),
+        )
+    }
+
+    /// Adds a component definition
+    pub fn with_definition(mut self, definition: &'static ComponentDefinition) -> Self {
+        self.definitions.push(definition);
+        self
+    }
+
+    /// Adds several component definitions
+    pub fn with_definitions(
+        mut self,
+        definitions: impl IntoIterator<Item = &'static ComponentDefinition>,
+    ) -> Self {
+        self.definitions.extend(definitions);
+        self
+    }
+
+    /// Registers `mock` as the component of type `T`
+    pub fn with_mock<T: Any + Send + Sync>(mut self, mock: Arc<T>) -> Self {
+        self.registrations.push(Box::new(move |container| {
+            container.register_component(mock);
+            Ok(())
+        }));
+        self
+    }
+
+    /// Registers `mock` as an implementation of the trait object type `T`
+    ///
+    /// See [`ComponentContainer::register_trait_component`].
+    pub fn with_trait_mock<T>(mut self, qualifier: &'static str, mock: Arc<T>) -> Self
+    where
+        T: ?Sized + Send + Sync + 'static,
+    {
+        self.registrations.push(Box::new(move |container| {
+            container.register_trait_component(qualifier, mock)
+        }));
+        self
+    }
+
+    /// Creates the container and registers the mocks, without initializing it
+    ///
+    /// # Errors
+    ///
+    /// Returns a `Configuration` error if two definitions are given for the same type
+    /// or two trait mocks share a qualifier
+    pub fn build(self) -> Result<ComponentContainer, ContainerError> {
+        let container = ComponentContainer::with_definitions(self.definitions)?;
+        for registration in self.registrations {
+            registration(&container)?;
+        }
+        Ok(container)
+    }
+
+    /// Creates the container, registers the mocks and initializes it
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`build`](Self::build) and
+    /// [`ComponentContainer::initialize`]
+    pub fn initialize(self) -> Result<ComponentContainer, ContainerError> {
+        let container = self.build()?;
+        container.initialize()?;
+        Ok(container)
+    }
+}
+
+impl Default for IsolatedContainer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ComponentFactory, ComponentScope};
+    use std::any::TypeId;
+
+    struct Repository {
+        name: &'static str,
+    }
+
+    struct Service {
+        repository: Arc<Repository>,
+    }
+
+    trait Notifier: Send + Sync {
+        fn channel(&self) -> &str;
+    }
+
+    struct MockNotifier;
+
+    impl Notifier for MockNotifier {
+        fn channel(&self) -> &str {
+            "mock"
+        }
+    }
+
+    static SERVICE: ComponentDefinition = ComponentDefinition::new(
+        TypeId::of::<Service>,
+        "Service",
+        || ComponentScope::Singleton,
+        || vec![TypeId::of::<Repository>()],
+        |deps| {
+            let repository = deps[&TypeId::of::<Repository>()].clone();
+            Ok(Arc::new(Service {
+                repository: repository.downcast().unwrap(),
+            }))
+        },
+    );
+
+    #[test]
+    fn test_isolated_container_uses_given_definitions_and_mocks() {
+        let container = IsolatedContainer::new()
+            .with_definition(&SERVICE)
+            .with_mock(Arc::new(Repository { name: "mock" }))
+            .with_trait_mock::<dyn Notifier>("mock", Arc::new(MockNotifier))
+            .initialize()
+            .unwrap();
+
+        let service: Arc<Service> = container.get_component().unwrap();
+        assert_eq!(service.repository.name, "mock");
+        assert_eq!(
+            container
+                .get_trait_component::<dyn Notifier>()
+                .unwrap()
+                .channel(),
+            "mock"
+        );
+        // Components discovered through inventory are neither known nor created
+        assert_eq!(container.registered_type_names(), vec!["Service"]);
+        assert_eq!(container.component_count(), 3);
+    }
+
+    #[test]
+    fn test_isolated_container_reports_missing_and_duplicate_definitions() {
+        let missing = IsolatedContainer::new()
+            .with_definition(&SERVICE)
+            .initialize();
+        assert!(missing.is_err());
+
+        let duplicate = IsolatedContainer::new()
+            .with_definitions([&SERVICE, &SERVICE])
+            .build();
+        assert!(
+            duplicate
+                .err()
+                .unwrap()
+                .to_string()
+                .contains("defined more than once")
+        );
+    }
+
+    #[test]
+    fn test_isolated_container_filters_inventory() {
+        let container = IsolatedContainer::from_inventory(|def| def.type_name == "SharedPool")
+            .build()
+            .unwrap();
+        assert_eq!(container.initialize().unwrap(), 1);
+        assert_eq!(container.component_count(), 1);
+    }
+}
@@ -1,42 +1,58 @@
 use proc_macro2::{Ident, TokenStream};
-use quote::quote;
+use quote::{quote, quote_spanned};
 use syn::{
-    Attribute, Data, DeriveInput, Error, Field, Fields, FieldsNamed, GenericArgument,
-    PathArguments, Type,
+    Attribute, Data, DeriveInput, Error, Field, Fields, GenericArgument, LitBool, LitInt, LitStr,
+    PathArguments, Token, Type,
 };
 
 pub(crate) fn impl_component_derive(ast: &DeriveInput) -> TokenStream {
     let struct_name = &ast.ident;
 
-    let fields = match validate_struct_input(&ast) {
+    let fields = match validate_struct_input(ast) {
         Ok(fields) => fields,
         Err(err) => return err.to_compile_error(),
     };
 
     let (attr_fields, non_attr_fields) = partition_fields(fields);
+    let is_unit = matches!(fields, Fields::Unit);
 
-    match process_fields(&attr_fields, &non_attr_fields, struct_name, &ast.attrs) {
-        Ok(expanded) => TokenStream::from(expanded),
+    match process_fields(
+        &attr_fields,
+        &non_attr_fields,
+        struct_name,
+        &ast.attrs,
+        is_unit,
+    ) {
+        Ok(expanded) => expanded,
         Err(err) => err.to_compile_error(),
     }
 }
 
 fn process_fields(
-    attr_fields: &Vec<Field>,
-    non_attr_fields: &Vec<Field>,
+    attr_fields: &[Field],
+    non_attr_fields: &[Field],
     struct_name: &Ident,
-    attrs: &Vec<Attribute>,
+    attrs: &[Attribute],
+    is_unit: bool,
 ) -> Result<TokenStream, Error> {
-    let dependency_inner_types = match extract_dependency_types(attr_fields) {
-        Ok(types) => types,
-        Err(err) => return Err(err),
-    };
+    let dependency_inner_types = extract_dependency_types(attr_fields)?;
 
     let autowired_names: Vec<_> = attr_fields
         .iter()
         .map(|f| f.ident.as_ref().unwrap())
         .collect();
 
+    let dependency_key_types: Vec<_> = dependency_inner_types
+        .iter()
+        .map(dependency_key_type)
+        .collect();
+
+    let dependency_lookups: Vec<_> = autowired_names
+        .iter()
+        .zip(&dependency_inner_types)
+        .map(|(name, inner_type)| dependency_lookup(name, inner_type))
+        .collect();
+
     let non_autowired_initializers: Vec<_> = non_attr_fields
         .iter()
         .map(|f| {
@@ -49,8 +65,37 @@ fn process_fields(
         })
         .collect();
 
-    let scope =
-        find_scope_attribute(attrs).unwrap_or(quote! { ::verdure::ComponentScope::Singleton });
+    let ComponentAttributes {
+        scope,
+        order,
+        primary,
+        register,
+    } = parse_component_attributes(attrs)?;
+    let scope = scope.unwrap_or(quote! { ::verdure::ComponentScope::Singleton });
+
+    let construct = if is_unit {
+        quote! { Self }
+    } else {
+        quote! {
+            Self {
+                #( #autowired_names, )*
+                #( #non_autowired_initializers, )*
+            }
+        }
+    };
+
+    let registration = if register {
+        component_registration(
+            struct_name,
+            &autowired_names,
+            &dependency_key_types,
+            &dependency_lookups,
+            order,
+            primary,
+        )
+    } else {
+        TokenStream::new()
+    };
 
     let expanded = quote! {
         impl ::verdure::ComponentInitializer for #struct_name {
@@ -58,10 +103,7 @@ fn process_fields(
             fn __new(deps: Self::Dependencies) -> Self {
                 let ( #( #autowired_names, )* ) = deps;
 
-                Self {
-                     #( #autowired_names, )*
-                    #( #non_autowired_initializers, )*
-                }
+                #construct
             }
 
             fn __scope() -> ::verdure::ComponentScope {
@@ -69,28 +111,33 @@ fn process_fields(
             }
         }
 
+        #registration
+    };
+
+    Ok(expanded)
+}
 
+/// Generates the `inventory` registration of the component's definition
+fn component_registration(
+    struct_name: &Ident,
+    autowired_names: &[&Ident],
+    dependency_key_types: &[TokenStream],
+    dependency_lookups: &[TokenStream],
+    order: i32,
+    primary: bool,
+) -> TokenStream {
+    quote! {
         inventory::submit! {
             ::verdure::ComponentDefinition {
                 type_id: || std::any::TypeId::of::<#struct_name>(),
                 type_name: stringify!(#struct_name),
                 scope: || <#struct_name as ::verdure::ComponentInitializer>::__scope(),
                 dependencies: || vec![
-                        #( std::any::TypeId::of::<#dependency_inner_types>(), )*
+                        #( std::any::TypeId::of::<#dependency_key_types>(), )*
                     ],
                 creator: |deps: std::collections::HashMap<std::any::TypeId, ::verdure::ComponentInstance>| -> Result<::verdure::ComponentInstance, ::verdure::error::component::ComponentError> {
                     // Ok(#struct_name as ::verdure::Component::__new())
-                    #(
-                        let #autowired_names:std::sync::Arc<#dependency_inner_types> = deps.get(&std::any::TypeId::of::<#dependency_inner_types>())
-                        .ok_or_else(|| ::verdure::error::component::ComponentError::DependencyNotFound(
-                            format!("Dependency '{}' not found in provided deps", stringify!(#dependency_inner_types))
-                        ))?
-                        .clone()
-                        .downcast::<#dependency_inner_types>()
-                        .map_err(|_| ::verdure::error::component::ComponentError::DowncastFailed(
-                            format!("Failed to downcast dependency '{}'", stringify!(#dependency_inner_types))
-                        ))?;
-                    )*
+                    #( #dependency_lookups )*
                     let deps_tuple = (
                         #(
                             #autowired_names,
@@ -99,30 +146,84 @@ fn process_fields(
                     let instance = <#struct_name as ::verdure::ComponentInitializer>::__new(deps_tuple);
                     Ok(std::sync::Arc::new(instance))
                 },
+                order: || #order,
+                primary: || #primary,
+                origin: Some(::verdure::ComponentOrigin {
+                    module_path: module_path!(),
+                    file: file!(),
+                    line: line!(),
+                }),
             }
         }
-    };
+    }
+}
 
-    Ok(expanded)
+/// Options given in `#[component(...)]`
+struct ComponentAttributes {
+    scope: Option<TokenStream>,
+    order: i32,
+    primary: bool,
+    register: bool,
 }
 
-fn find_scope_attribute(attrs: &Vec<Attribute>) -> Option<TokenStream> {
+fn parse_component_attributes(attrs: &[Attribute]) -> Result<ComponentAttributes, Error> {
+    let mut scope = None;
+    let mut order = 0;
+    let mut primary = false;
+    let mut register = true;
     for attr in attrs {
-        if attr.path().is_ident("scope") {
-            if let Ok(meta) = attr.meta.require_list() {
-                let tokens = meta.tokens.clone();
-                return Some(quote! { #tokens });
-            } else {
-                return Some(quote! {
-                    ::verdure::ComponentScope::Singleton
+        if !attr.path().is_ident("component") {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("scope") {
+                let value: LitStr = meta.value()?.parse()?;
+                // Resolved by `ComponentScope::from_name` at compile time, so the names
+                // are mapped in one place; an unknown name fails the constant evaluation
+                let unknown = "scope must be one of \"Singleton\", \"Prototype\" or \"Request\"";
+                scope = Some(quote_spanned! {value.span()=>
+                    {
+                        const SCOPE: ::verdure::ComponentScope =
+                            match ::verdure::ComponentScope::from_name(#value) {
+                                Some(scope) => scope,
+                                None => panic!(#unknown),
+                            };
+                        SCOPE
+                    }
                 });
+                Ok(())
+            } else if meta.path.is_ident("order") {
+                let input = meta.value()?;
+                let negative = input.parse::<Option<Token![-]>>()?.is_some();
+                let value: LitInt = input.parse()?;
+                let magnitude = value.base10_parse::<i64>()?;
+                let signed = if negative { -magnitude } else { magnitude };
+                order = i32::try_from(signed)
+                    .map_err(|_| Error::new_spanned(&value, "order must fit in an i32"))?;
+                Ok(())
+            } else if meta.path.is_ident("primary") {
+                primary = true;
+                Ok(())
+            } else if meta.path.is_ident("register") {
+                register = meta.value()?.parse::<LitBool>()?.value;
+                Ok(())
+            } else {
+                Err(meta.error(
+                    "unsupported component attribute, expected `scope`, `order`, `primary` \
+                     or `register`",
+                ))
             }
-        }
+        })?;
     }
-    None
+    Ok(ComponentAttributes {
+        scope,
+        order,
+        primary,
+        register,
+    })
 }
 
-fn extract_dependency_types(autowired_fields: &Vec<Field>) -> Result<Vec<Type>, Error> {
+fn extract_dependency_types(autowired_fields: &[Field]) -> Result<Vec<Type>, Error> {
     let mut dependency_types = Vec::new();
 
     for field in autowired_fields {
@@ -135,21 +236,21 @@ fn extract_dependency_types(autowired_fields: &Vec<Field>) -> Result<Vec<Type>,
 }
 
 fn extract_arc_inner_type(ty: &Type) -> Result<Type, Error> {
-    if let Type::Path(type_path) = ty {
-        if let Some(segment) = type_path.path.segments.last() {
-            // 检查是否为 Arc 类型
-            if segment.ident != "Arc" {
-                return Err(Error::new_spanned(
-                    ty,
-                    "Fields with #[autowired] attribute must be of type Arc<T>",
-                ));
-            }
+    if let Type::Path(type_path) = ty
+        && let Some(segment) = type_path.path.segments.last()
+    {
+        // 检查是否为 Arc 类型
+        if segment.ident != "Arc" {
+            return Err(Error::new_spanned(
+                ty,
+                "Fields with #[autowired] attribute must be of type Arc<T>",
+            ));
+        }
 
-            if let PathArguments::AngleBracketed(args) = &segment.arguments {
-                if let Some(GenericArgument::Type(inner_ty)) = args.args.first() {
-                    return Ok(inner_ty.clone());
-                }
-            }
+        if let PathArguments::AngleBracketed(args) = &segment.arguments
+            && let Some(GenericArgument::Type(inner_ty)) = args.args.first()
+        {
+            return Ok(inner_ty.clone());
         }
     }
 
@@ -159,22 +260,54 @@ fn extract_arc_inner_type(ty: &Type) -> Result<Type, Error> {
     ))
 }
 
+/// Returns the type under which an autowired dependency is looked up in the container
+///
+/// Trait objects have no `TypeId` of their own implementation, so `Arc<dyn Trait>`
+/// dependencies are registered and looked up as `Arc<dyn Trait>` itself.
+fn dependency_key_type(inner_type: &Type) -> TokenStream {
+    if matches!(inner_type, Type::TraitObject(_)) {
+        quote! { std::sync::Arc<#inner_type> }
+    } else {
+        quote! { #inner_type }
+    }
+}
+
+fn dependency_lookup(name: &Ident, inner_type: &Type) -> TokenStream {
+    let key_type = dependency_key_type(inner_type);
+    let unwrap_trait_object = if matches!(inner_type, Type::TraitObject(_)) {
+        quote! { .as_ref().clone() }
+    } else {
+        quote! {}
+    };
+    quote! {
+        let #name: std::sync::Arc<#inner_type> = deps.get(&std::any::TypeId::of::<#key_type>())
+        .ok_or_else(|| ::verdure::error::component::ComponentError::DependencyNotFound(
+            format!("Dependency '{}' not found in provided deps", stringify!(#inner_type))
+        ))?
+        .clone()
+        .downcast::<#key_type>()
+        .map_err(|_| ::verdure::error::component::ComponentError::DowncastFailed(
+            format!("Failed to downcast dependency '{}'", stringify!(#inner_type))
+        ))?
+        #unwrap_trait_object;
+    }
+}
+
 fn is_optional_field(ty: &Type) -> bool {
-    if let Type::Path(type_path) = ty {
-        if let Some(segment) = type_path.path.segments.last() {
-            if segment.ident == "Option" {
-                return true;
-            }
-        }
+    if let Type::Path(type_path) = ty
+        && let Some(segment) = type_path.path.segments.last()
+        && segment.ident == "Option"
+    {
+        return true;
     }
     false
 }
 
-fn partition_fields(fields: &FieldsNamed) -> (Vec<Field>, Vec<Field>) {
+fn partition_fields(fields: &Fields) -> (Vec<Field>, Vec<Field>) {
     let mut attr_fields = Vec::new();
     let mut non_attr_fields = Vec::new();
 
-    for field in &fields.named {
+    for field in fields {
         if has_marco_attributes(&field.attrs) {
             attr_fields.push(field.clone());
         } else {
@@ -188,13 +321,13 @@ fn has_marco_attributes(attrs: &[Attribute]) -> bool {
     attrs.iter().any(|attr| attr.path().is_ident("autowired"))
 }
 
-fn validate_struct_input(ast: &DeriveInput) -> Result<&FieldsNamed, Error> {
+fn validate_struct_input(ast: &DeriveInput) -> Result<&Fields, Error> {
     match &ast.data {
         Data::Struct(s) => match &s.fields {
-            Fields::Named(fields) => Ok(fields),
-            _ => Err(Error::new_spanned(
+            Fields::Named(_) | Fields::Unit => Ok(&s.fields),
+            Fields::Unnamed(_) => Err(Error::new_spanned(
                 ast,
-                "Component derive macro only supports structs with named fields",
+                "Component derive macro only supports unit structs and structs with named fields",
             )),
         },
         _ => Err(Error::new_spanned(
@@ -207,14 +340,14 @@ fn validate_struct_input(ast: &DeriveInput) -> Result<&FieldsNamed, Error> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::sync::Arc;
+
     use syn::parse_quote;
 
     #[test]
     fn test_simple_struct() {
         let input: DeriveInput = parse_quote! {
             #[derive(Component)]
-            #[component(name = "xxx", scope="Singleton")]
+            #[component(scope = "Singleton")]
             struct SimpleStruct {
                 #[autowired]
                 field1: Arc<DemoStruct>,
@@ -223,6 +356,142 @@ mod tests {
             }
         };
 
-        println!("gen: {}", impl_component_derive(&input))
+        let output = impl_component_derive(&input).to_string();
+        println!("gen: {}", output);
+        assert!(output.contains("ComponentScope :: from_name (\"Singleton\")"));
+        assert!(output.contains("origin : Some (:: verdure :: ComponentOrigin"));
+    }
+
+    #[test]
+    fn test_request_scope_attribute() {
+        let input: DeriveInput = parse_quote! {
+            #[component(scope = "Request")]
+            struct Handler {
+                value: u32,
+            }
+        };
+
+        let output = impl_component_derive(&input).to_string();
+        assert!(output.contains("ComponentScope :: from_name (\"Request\")"));
+    }
+
+    #[test]
+    fn test_unit_and_empty_structs() {
+        let unit: DeriveInput = parse_quote! {
+            struct UserRepository;
+        };
+        let output = impl_component_derive(&unit).to_string();
+        assert!(!output.contains("compile_error"));
+        assert!(output.contains("type Dependencies = ()"));
+
+        let empty: DeriveInput = parse_quote! {
+            struct Empty {}
+        };
+        let output = impl_component_derive(&empty).to_string();
+        assert!(!output.contains("compile_error"));
+        assert!(output.contains("Self { }"));
+
+        let tuple: DeriveInput = parse_quote! {
+            struct Wrapper(u32);
+        };
+        assert!(
+            impl_component_derive(&tuple)
+                .to_string()
+                .contains("compile_error")
+        );
+    }
+
+    #[test]
+    fn test_invalid_scope_attribute() {
+        let input: DeriveInput = parse_quote! {
+            #[component(scope = 1)]
+            struct Handler {
+                value: u32,
+            }
+        };
+
+        let output = impl_component_derive(&input).to_string();
+        assert!(output.contains("compile_error"));
+    }
+
+    #[test]
+    fn test_order_attribute() {
+        let input: DeriveInput = parse_quote! {
+            #[component(scope = "Singleton", order = -5)]
+            struct Logging;
+        };
+        let output = impl_component_derive(&input).to_string();
+        assert!(output.contains("order : || - 5i32"), "{}", output);
+
+        let input: DeriveInput = parse_quote! {
+            struct Metrics;
+        };
+        let output = impl_component_derive(&input).to_string();
+        assert!(output.contains("order : || 0i32"));
+
+        let input: DeriveInput = parse_quote! {
+            #[component(order = 4294967296)]
+            struct TooLarge;
+        };
+        let output = impl_component_derive(&input).to_string();
+        assert!(output.contains("order must fit in an i32"));
+    }
+
+    #[test]
+    fn test_primary_attribute() {
+        let input: DeriveInput = parse_quote! {
+            #[component(primary, order = 1)]
+            struct PostgresRepository;
+        };
+        let output = impl_component_derive(&input).to_string();
+        assert!(output.contains("primary : || true"), "{}", output);
+
+        let input: DeriveInput = parse_quote! {
+            struct MemoryRepository;
+        };
+        let output = impl_component_derive(&input).to_string();
+        assert!(output.contains("primary : || false"), "{}", output);
+    }
+
+    #[test]
+    fn test_register_attribute() {
+        let input: DeriveInput = parse_quote! {
+            #[component(register = false)]
+            struct MockRepository;
+        };
+        let output = impl_component_derive(&input).to_string();
+        assert!(output.contains("ComponentInitializer for MockRepository"));
+        assert!(!output.contains("inventory :: submit"), "{}", output);
+
+        let input: DeriveInput = parse_quote! {
+            #[component(register = true)]
+            struct Repository;
+        };
+        let output = impl_component_derive(&input).to_string();
+        assert!(output.contains("inventory :: submit"));
+
+        let input: DeriveInput = parse_quote! {
+            #[component(register = "no")]
+            struct Invalid;
+        };
+        let output = impl_component_derive(&input).to_string();
+        assert!(output.contains("compile_error"));
+    }
+
+    #[test]
+    fn test_trait_object_dependency() {
+        let input: DeriveInput = parse_quote! {
+            struct UserService {
+                #[autowired]
+                repository: Arc<dyn UserRepository>,
+            }
+        };
+
+        let output = impl_component_derive(&input).to_string();
+        assert!(!output.contains("compile_error"));
+        assert!(
+            output.contains("TypeId :: of :: < std :: sync :: Arc < dyn UserRepository > > ()")
+        );
+        assert!(output.contains(". as_ref () . clone ()"));
     }
 }
@@ -1,12 +1,29 @@
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn::{Attribute, Data, DeriveInput, Expr, Fields, Lit};
+use syn::parse::ParseStream;
+use syn::{
+    Data, DeriveInput, Error, Expr, ExprUnary, Field, Fields, GenericArgument, Ident, Lit,
+    LitFloat, LitInt, LitStr, PathArguments, Token, Type, UnOp,
+};
+
+/// Options given in `#[configuration("key", ...)]`
+struct ConfigurationAttribute {
+    /// Prefix of the configuration keys bound to the struct
+    module_key: String,
+    /// Whether unparsable values are ignored instead of reported
+    lenient: bool,
+}
 
 pub(crate) fn impl_configuration_derive(input: &DeriveInput) -> TokenStream {
     let struct_name = &input.ident;
-    let config_module_key = parse_config_module_key(&input.attrs)
-        .expect("Missing required #[configuration(\"...\")] attribute.");
-    let field_setters = generate_field_setters(&input.data, &config_module_key);
+    let ConfigurationAttribute {
+        module_key: config_module_key,
+        lenient,
+    } = match parse_configuration_attribute(input) {
+        Ok(attribute) => attribute,
+        Err(err) => return err.to_compile_error(),
+    };
+    let field_setters = generate_field_setters(&input.data, &config_module_key, lenient);
     let struct_init = generate_struct_initialization(&input.data);
 
     let expanded = quote! {
@@ -15,8 +32,13 @@ pub(crate) fn impl_configuration_derive(input: &DeriveInput) -> TokenStream {
                 let mut instance = Self {
                     #(#struct_init)*
                 };
+                #[allow(unused_mut)]
+                let mut invalid: Vec<::verdure::ContextError> = Vec::new();
                 #(#field_setters)*
-                Ok(instance)
+                match ::verdure::ContextError::merge_invalid_configurations(invalid) {
+                    Some(err) => Err(err),
+                    None => Ok(instance),
+                }
             }
 
             fn config_module_key() -> &'static str {
@@ -34,92 +56,714 @@ pub(crate) fn impl_configuration_derive(input: &DeriveInput) -> TokenStream {
         }
     };
 
-    TokenStream::from(expanded)
+    expanded
 }
 
 fn generate_struct_initialization(data: &Data) -> Vec<TokenStream> {
     let mut initializers = Vec::new();
 
-    if let Data::Struct(data_struct) = data {
-        if let Fields::Named(fields) = &data_struct.fields {
-            for field in &fields.named {
-                let field_ident = field.ident.as_ref().unwrap();
-
-                let mut has_default = false;
-                let mut default_value: Option<TokenStream> = None;
-
-                for attr in &field.attrs {
-                    if attr.path().is_ident("config_default") {
-                        if let Ok(lit) = attr.parse_args::<Lit>() {
-                            default_value = Some(match lit {
-                                Lit::Int(int_lit) => quote! { Some(#int_lit) },
-                                Lit::Str(str_lit) => quote! { Some(#str_lit.to_string()) },
-                                Lit::Bool(bool_lit) => quote! { Some(#bool_lit) },
-                                Lit::Float(float_lit) => quote! { Some(#float_lit) },
-                                other => quote! { Some(#other) },
-                            });
-                            has_default = true;
-                        }
-                    } else if attr.path().is_ident("config_default_t") {
-                        if let Ok(expr) = attr.parse_args::<Expr>() {
-                            default_value = Some(quote! { #expr });
-                            has_default = true;
+    if let Data::Struct(data_struct) = data
+        && let Fields::Named(fields) = &data_struct.fields
+    {
+        for field in &fields.named {
+            let field_ident = field.ident.as_ref().unwrap();
+
+            if is_config_map_field(field) {
+                initializers.push(quote! { #field_ident: Default::default(), });
+                continue;
+            }
+
+            let optional = is_option_type(&field.ty);
+            let value_ty = single_generic_argument(&field.ty, "Option").unwrap_or(&field.ty);
+            let mut default_value: Option<TokenStream> = None;
+
+            for attr in &field.attrs {
+                if attr.path().is_ident("config_default") {
+                    default_value = Some(
+                        match attr
+                            .parse_args::<Expr>()
+                            .and_then(|expr| check_default_literal(&expr, value_ty).map(|_| expr))
+                        {
+                            Ok(expr) => {
+                                config_default_value(&expr, field_ident, value_ty, optional)
+                            }
+                            Err(err) => err.to_compile_error(),
+                        },
+                    );
+                } else if attr.path().is_ident("config_default_t") {
+                    default_value = Some(match attr.parse_args::<Expr>() {
+                        Ok(expr) => quote! { #expr },
+                        Err(err) => err.to_compile_error(),
+                    });
+                }
+            }
+
+            let field_init = match default_value {
+                Some(default_val) => quote! { #field_ident: #default_val, },
+                // No default attribute - use None for Option fields
+                None if optional => quote! { #field_ident: None, },
+                None => quote! { #field_ident: Default::default(), },
+            };
+
+            initializers.push(field_init);
+        }
+    }
+
+    initializers
+}
+
+/// Converts a `#[config_default(...)]` argument into the field's initial value
+///
+/// Literals are wrapped in `Some` for `Option` fields and used directly otherwise.
+/// Numeric literals lose their suffix so the field's inner type decides the literal type
+/// (`8080u16` on an `Option<u32>` becomes `Some(8080)`), and negated literals such as `-1`
+/// are supported. A string literal on a field that is not a `String` is parsed like a
+/// configured value, through `FromStr` or as a `Duration`, and panics with the field name
+/// if it does not parse. Anything that is not a literal is used verbatim, like
+/// `config_default_t`.
+fn config_default_value(
+    expr: &Expr,
+    field_ident: &Ident,
+    value_ty: &Type,
+    optional: bool,
+) -> TokenStream {
+    let value = match expr {
+        Expr::Lit(expr_lit) => {
+            let lit = unsuffixed_literal(&expr_lit.lit);
+            match lit {
+                Lit::Str(str_lit)
+                    if LiteralKind::of_type(value_ty) == Some(LiteralKind::String) =>
+                {
+                    quote! { #str_lit.to_string() }
+                }
+                Lit::Str(str_lit) => {
+                    let parsed = parse_value(quote! { #str_lit }, value_ty);
+                    let message = format!(
+                        "invalid config_default {} for field `{}` of type `{}`",
+                        str_lit.token(),
+                        field_ident,
+                        quote!(#value_ty)
+                    );
+                    quote! {
+                        match #parsed {
+                            Ok(value) => value,
+                            Err(_) => panic!(#message),
                         }
                     }
                 }
+                other => quote! { #other },
+            }
+        }
+        Expr::Unary(ExprUnary {
+            op: UnOp::Neg(_),
+            expr: inner,
+            ..
+        }) => match inner.as_ref() {
+            Expr::Lit(expr_lit) if matches!(expr_lit.lit, Lit::Int(_) | Lit::Float(_)) => {
+                let lit = unsuffixed_literal(&expr_lit.lit);
+                quote! { -#lit }
+            }
+            _ => return quote! { #expr },
+        },
+        _ => return quote! { #expr },
+    };
 
+    if optional {
+        quote! { Some(#value) }
+    } else {
+        value
+    }
+}
 
-                let field_init = if has_default {
-                    let default_val = default_value.unwrap();
-                    quote! { #field_ident: #default_val, }
-                } else {
-                    // No default attribute - use None for Option fields
-                    quote! { #field_ident: None, }
-                };
+/// The kinds of field types a `#[config_default(...)]` literal is checked against
+#[derive(PartialEq)]
+enum LiteralKind {
+    SignedInteger,
+    UnsignedInteger,
+    Float,
+    Bool,
+    String,
+}
 
-                initializers.push(field_init);
-            }
+impl LiteralKind {
+    /// Classifies primitive and string types; other types are not checked
+    fn of_type(ty: &Type) -> Option<Self> {
+        let Type::Path(type_path) = ty else {
+            return None;
+        };
+        let segment = type_path.path.segments.last()?;
+        if !segment.arguments.is_empty() {
+            return None;
         }
+        Some(match segment.ident.to_string().as_str() {
+            "i8" | "i16" | "i32" | "i64" | "i128" | "isize" => LiteralKind::SignedInteger,
+            "u8" | "u16" | "u32" | "u64" | "u128" | "usize" => LiteralKind::UnsignedInteger,
+            "f32" | "f64" => LiteralKind::Float,
+            "bool" => LiteralKind::Bool,
+            "String" => LiteralKind::String,
+            _ => return None,
+        })
     }
 
-    initializers
+    fn description(&self) -> &'static str {
+        match self {
+            LiteralKind::SignedInteger | LiteralKind::UnsignedInteger => "an integer",
+            LiteralKind::Float => "a float",
+            LiteralKind::Bool => "a boolean",
+            LiteralKind::String => "a string",
+        }
+    }
 }
 
-fn generate_field_setters(data: &Data, config_module_key: &String) -> Vec<TokenStream> {
+/// Rejects `#[config_default(...)]` literals that obviously do not fit the field type
+///
+/// Only primitive and `String` fields are checked; a string default on a field of another
+/// type is parsed when the default is used, see [`config_default_value`].
+fn check_default_literal(expr: &Expr, value_ty: &Type) -> Result<(), Error> {
+    let Some(field_kind) = LiteralKind::of_type(value_ty) else {
+        return Ok(());
+    };
+
+    let (lit, negative) = match expr {
+        Expr::Lit(expr_lit) => (&expr_lit.lit, false),
+        Expr::Unary(ExprUnary {
+            op: UnOp::Neg(_),
+            expr: inner,
+            ..
+        }) => match inner.as_ref() {
+            Expr::Lit(expr_lit) => (&expr_lit.lit, true),
+            _ => return Ok(()),
+        },
+        _ => return Ok(()),
+    };
+
+    let fits = match lit {
+        Lit::Int(_) => matches!(
+            field_kind,
+            LiteralKind::SignedInteger | LiteralKind::UnsignedInteger
+        ),
+        Lit::Float(_) => field_kind == LiteralKind::Float,
+        Lit::Bool(_) => field_kind == LiteralKind::Bool,
+        Lit::Str(_) => field_kind == LiteralKind::String,
+        _ => true,
+    };
+    if !fits {
+        let hint = match (lit, &field_kind) {
+            (Lit::Int(_), LiteralKind::Float) => " (write it as a float, e.g. `1.0`)",
+            _ => "",
+        };
+        return Err(Error::new_spanned(
+            expr,
+            format!(
+                "config_default value does not match the field type `{}`, expected {}{}",
+                quote!(#value_ty),
+                field_kind.description(),
+                hint
+            ),
+        ));
+    }
+
+    if negative && field_kind == LiteralKind::UnsignedInteger {
+        return Err(Error::new_spanned(
+            expr,
+            format!(
+                "config_default value must not be negative for the unsigned field type `{}`",
+                quote!(#value_ty)
+            ),
+        ));
+    }
+    Ok(())
+}
+
+fn unsuffixed_literal(lit: &Lit) -> Lit {
+    match lit {
+        Lit::Int(int_lit) => Lit::Int(LitInt::new(int_lit.base10_digits(), int_lit.span())),
+        Lit::Float(float_lit) => {
+            Lit::Float(LitFloat::new(float_lit.base10_digits(), float_lit.span()))
+        }
+        other => other.clone(),
+    }
+}
+
+fn generate_field_setters(data: &Data, config_module_key: &str, lenient: bool) -> Vec<TokenStream> {
     let mut setters = Vec::new();
-    if let Data::Struct(data_struct) = data {
-        if let Fields::Named(fields) = &data_struct.fields {
-            for field in &fields.named {
-                let field_ident = field.ident.as_ref().unwrap();
-                let field_name = field_ident.to_string();
-                let config_key = format!("{}.{}", config_module_key, field_name);
-
-                let setter = quote! {
-                    if let Some(config_value) = config_manager.get(#config_key) {
-                        // TODO: as_string change as_any_type
-                        if let Some(str_val) = config_value.as_string() {
-                            if let Ok(parsed_val) = str_val.parse() {
-                                instance.#field_ident = Some(parsed_val);
+    if let Data::Struct(data_struct) = data
+        && let Fields::Named(fields) = &data_struct.fields
+    {
+        for field in &fields.named {
+            let field_ident = field.ident.as_ref().unwrap();
+            let field_name = field_ident.to_string();
+            let config_key = format!("{}.{}", config_module_key, field_name);
+
+            if is_config_map_field(field) {
+                let prefix = format!("{}.", config_key);
+                setters.push(quote! {
+                    for key in config_manager.all_keys() {
+                        if let Some(sub_key) = key.strip_prefix(#prefix) {
+                            if let Some(str_val) = config_manager.get(&key).and_then(|v| v.as_string()) {
+                                instance.#field_ident.insert(sub_key.to_string(), str_val);
                             }
                         }
                     }
-                };
-                setters.push(setter);
+                });
+                continue;
             }
+
+            // Lenient structs keep the default of a field whose value cannot be parsed
+            let (report_error, report_list_error) = if lenient {
+                (quote! { let _ = e; }, quote! { let _ = e; })
+            } else {
+                (
+                    quote! {
+                        invalid.push(config_manager.invalid_configuration(
+                            #config_key,
+                            format!("cannot parse '{}': {}", str_val, e),
+                        ));
+                    },
+                    quote! {
+                        invalid.push(config_manager.invalid_configuration(
+                            #config_key,
+                            format!("cannot parse list item: {}", e),
+                        ));
+                    },
+                )
+            };
+
+            // Optional fields are wrapped in `Some`, other fields are assigned directly
+            let optional = is_option_type(&field.ty);
+            let value_ty = single_generic_argument(&field.ty, "Option").unwrap_or(&field.ty);
+            let assigned = if optional {
+                quote! { Some(parsed_val) }
+            } else {
+                quote! { parsed_val }
+            };
+
+            if let Some(item_ty) = single_generic_argument(value_ty, "Vec") {
+                let parse_item = parse_value(quote! { item }, item_ty);
+                setters.push(quote! {
+                    if let Some(items) = config_manager.get_list(#config_key) {
+                        let parsed: Result<Vec<#item_ty>, _> =
+                            items.iter().map(|item| #parse_item).collect();
+                        match parsed {
+                            Ok(parsed_val) => instance.#field_ident = #assigned,
+                            Err(e) => {
+                                #report_list_error
+                            }
+                        }
+                    }
+                });
+                continue;
+            }
+
+            let parse_str_val = parse_value(quote! { str_val }, value_ty);
+            let setter = quote! {
+                if let Some(config_value) = config_manager.get(#config_key) {
+                    // TODO: as_string change as_any_type
+                    if let Some(str_val) = config_value.as_string() {
+                        match #parse_str_val {
+                            Ok(parsed_val) => instance.#field_ident = #assigned,
+                            Err(e) => {
+                                #report_error
+                            }
+                        }
+                    }
+                }
+            };
+            setters.push(setter);
         }
     }
     setters
 }
 
-fn parse_config_module_key(attrs: &Vec<Attribute>) -> Option<String> {
-    for attr in attrs {
-        if attr.path().is_ident("configuration") {
-            if let Ok(meta_list) = attr.meta.require_list() {
-                if let Ok(Lit::Str(lit_str)) = syn::parse2::<Lit>(meta_list.tokens.clone()) {
-                    return Some(lit_str.value());
-                }
+/// Parses the string `value` as `ty`, reading `Duration`s with units like `30s`
+fn parse_value(value: TokenStream, ty: &Type) -> TokenStream {
+    if let Type::Path(type_path) = ty
+        && let Some(segment) = type_path.path.segments.last()
+        && segment.ident == "Duration"
+    {
+        quote! { ::verdure::config::parse_duration(&#value) }
+    } else {
+        quote! { #value.parse::<#ty>() }
+    }
+}
+
+fn is_option_type(ty: &Type) -> bool {
+    single_generic_argument(ty, "Option").is_some()
+}
+
+fn single_generic_argument<'a>(ty: &'a Type, ident: &str) -> Option<&'a Type> {
+    if let Type::Path(type_path) = ty
+        && let Some(segment) = type_path.path.segments.last()
+        && segment.ident == ident
+        && let PathArguments::AngleBracketed(args) = &segment.arguments
+        && let Some(GenericArgument::Type(inner)) = args.args.first()
+    {
+        return Some(inner);
+    }
+    None
+}
+
+fn is_config_map_field(field: &Field) -> bool {
+    field
+        .attrs
+        .iter()
+        .any(|attr| attr.path().is_ident("config_map"))
+}
+
+fn parse_configuration_attribute(input: &DeriveInput) -> Result<ConfigurationAttribute, Error> {
+    let attr = input
+        .attrs
+        .iter()
+        .find(|attr| attr.path().is_ident("configuration"))
+        .ok_or_else(|| {
+            Error::new_spanned(
+                input,
+                "Configuration derive requires a #[configuration(\"...\")] attribute naming the config module",
+            )
+        })?;
+
+    let meta_list = attr.meta.require_list()?;
+    if meta_list.tokens.is_empty() {
+        return Err(Error::new_spanned(
+            attr,
+            "#[configuration(...)] requires a module name, e.g. #[configuration(\"server\")]",
+        ));
+    }
+
+    meta_list.parse_args_with(|input: ParseStream| {
+        let lit_str: LitStr = input.parse().map_err(|_| {
+            Error::new_spanned(
+                &meta_list.tokens,
+                "#[configuration(...)] expects a string literal, e.g. #[configuration(\"server\")]",
+            )
+        })?;
+        let module_key = lit_str.value();
+        if module_key.trim().is_empty() {
+            return Err(Error::new_spanned(
+                lit_str,
+                "#[configuration(...)] module name must not be empty",
+            ));
+        }
+
+        let mut lenient = false;
+        while !input.is_empty() {
+            input.parse::<Token![,]>()?;
+            if input.is_empty() {
+                break;
+            }
+            let option: Ident = input.parse()?;
+            if option == "lenient" {
+                lenient = true;
+            } else {
+                return Err(Error::new_spanned(
+                    option,
+                    "unsupported configuration option, expected `lenient`",
+                ));
             }
         }
+
+        Ok(ConfigurationAttribute {
+            module_key,
+            lenient,
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use syn::parse_quote;
+
+    #[test]
+    fn test_config_map_field() {
+        let input: DeriveInput = parse_quote! {
+            #[derive(Configuration)]
+            #[configuration("features")]
+            struct FeatureConfig {
+                name: Option<String>,
+                #[config_map]
+                flags: HashMap<String, String>,
+            }
+        };
+
+        let generated = impl_configuration_derive(&input).to_string();
+        assert!(generated.contains("flags : Default :: default ()"));
+        assert!(generated.contains("all_keys"));
+        assert!(generated.contains("\"features.flags.\""));
+    }
+
+    #[test]
+    fn test_vec_field_uses_get_list() {
+        let input: DeriveInput = parse_quote! {
+            #[configuration("app")]
+            struct AppConfig {
+                features: Option<Vec<String>>,
+                ports: Option<std::vec::Vec<u16>>,
+                name: Option<String>,
+            }
+        };
+
+        let generated = impl_configuration_derive(&input).to_string();
+        assert!(generated.contains("get_list (\"app.features\")"));
+        assert!(generated.contains("get_list (\"app.ports\")"));
+        assert!(!generated.contains("get_list (\"app.name\")"));
+    }
+
+    #[test]
+    fn test_parse_failures_return_errors() {
+        let input: DeriveInput = parse_quote! {
+            #[configuration("log")]
+            struct LogConfig {
+                level: Option<LogLevel>,
+            }
+        };
+
+        let generated = impl_configuration_derive(&input).to_string();
+        assert!(generated.contains("invalid_configuration (\"log.level\""));
+        assert!(!generated.contains("if let Ok (parsed_val)"));
+    }
+
+    #[test]
+    fn test_lenient_option_skips_error_collection() {
+        let input: DeriveInput = parse_quote! {
+            #[configuration("log", lenient)]
+            struct LogConfig {
+                level: Option<LogLevel>,
+            }
+        };
+
+        let generated = impl_configuration_derive(&input).to_string();
+        assert!(generated.contains("get (\"log.level\")"));
+        assert!(!generated.contains("invalid . push"));
+    }
+
+    #[test]
+    fn test_unknown_configuration_option() {
+        let input: DeriveInput = parse_quote! {
+            #[configuration("log", strict)]
+            struct LogConfig {
+                level: Option<LogLevel>,
+            }
+        };
+
+        let generated = impl_configuration_derive(&input).to_string();
+        assert!(generated.contains("unsupported configuration option"));
+    }
+
+    #[test]
+    fn test_missing_configuration_attribute() {
+        let input: DeriveInput = parse_quote! {
+            struct ServerConfig {
+                port: Option<u16>,
+            }
+        };
+
+        let generated = impl_configuration_derive(&input).to_string();
+        assert!(generated.contains("compile_error"));
+        assert!(generated.contains("requires a #[configuration"));
+    }
+
+    #[test]
+    fn test_invalid_configuration_attribute() {
+        let cases: [DeriveInput; 3] = [
+            parse_quote! {
+                #[configuration()]
+                struct ServerConfig { port: Option<u16> }
+            },
+            parse_quote! {
+                #[configuration("")]
+                struct ServerConfig { port: Option<u16> }
+            },
+            parse_quote! {
+                #[configuration(server)]
+                struct ServerConfig { port: Option<u16> }
+            },
+        ];
+
+        for input in &cases {
+            let generated = impl_configuration_derive(input).to_string();
+            assert!(generated.contains("compile_error"), "{}", generated);
+        }
+    }
+
+    #[test]
+    fn test_config_default_numeric_literals() {
+        let input: DeriveInput = parse_quote! {
+            #[configuration("server")]
+            struct ServerConfig {
+                #[config_default(-1)]
+                retries: Option<i32>,
+                #[config_default(8080u16)]
+                port: Option<u32>,
+                #[config_default(0)]
+                workers: Option<u8>,
+                #[config_default(-0.5f32)]
+                offset: Option<f64>,
+            }
+        };
+
+        let generated = impl_configuration_derive(&input).to_string();
+        assert!(generated.contains("retries : Some (- 1)"));
+        assert!(generated.contains("port : Some (8080)"));
+        assert!(generated.contains("workers : Some (0)"));
+        assert!(generated.contains("offset : Some (- 0.5)"));
+    }
+
+    #[test]
+    fn test_defaults_for_optional_and_required_fields() {
+        let input: DeriveInput = parse_quote! {
+            #[configuration("server")]
+            struct ServerConfig {
+                #[config_default(8080)]
+                port: u16,
+                #[config_default("localhost")]
+                host: String,
+                #[config_default(-1)]
+                retries: i32,
+                #[config_default_t(vec!["a".to_string()])]
+                tags: Vec<String>,
+                #[config_default_t(Some(30))]
+                timeout: Option<u64>,
+                #[config_default("main")]
+                name: Option<String>,
+                workers: usize,
+                label: Option<String>,
+            }
+        };
+
+        let generated = impl_configuration_derive(&input).to_string();
+        assert!(generated.contains("port : 8080 ,"));
+        assert!(generated.contains("host : \"localhost\" . to_string () ,"));
+        assert!(generated.contains("retries : - 1 ,"));
+        assert!(generated.contains("tags : vec ! [\"a\" . to_string ()] ,"));
+        assert!(generated.contains("timeout : Some (30) ,"));
+        assert!(generated.contains("name : Some (\"main\" . to_string ()) ,"));
+        assert!(generated.contains("workers : Default :: default () ,"));
+        assert!(generated.contains("label : None ,"));
+
+        assert!(generated.contains("instance . port = parsed_val"));
+        assert!(generated.contains("get_list (\"server.tags\")"));
+        assert!(generated.contains("instance . tags = parsed_val"));
+        assert!(generated.contains("instance . timeout = Some (parsed_val)"));
+    }
+
+    #[test]
+    fn test_duration_fields_and_expression_defaults() {
+        let input: DeriveInput = parse_quote! {
+            #[configuration("server")]
+            struct ServerConfig {
+                #[config_default_t(std::time::Duration::from_secs(30))]
+                timeout: std::time::Duration,
+                #[config_default_t(vec![Duration::from_millis(100)])]
+                backoff: Vec<Duration>,
+                #[config_default_t(SocketAddr::from(([127, 0, 0, 1], 8080)))]
+                address: SocketAddr,
+            }
+        };
+
+        let generated = impl_configuration_derive(&input).to_string();
+        assert!(generated.contains("timeout : std :: time :: Duration :: from_secs (30) ,"));
+        assert!(generated.contains("address : SocketAddr :: from (([127 , 0 , 0 , 1] , 8080)) ,"));
+        assert!(generated.contains(":: verdure :: config :: parse_duration (& str_val)"));
+        assert!(generated.contains(":: verdure :: config :: parse_duration (& item)"));
+        assert!(generated.contains("str_val . parse :: < SocketAddr > ()"));
+
+        let input: DeriveInput = parse_quote! {
+            #[configuration("server")]
+            struct ServerConfig {
+                #[config_default_t(1 +)]
+                tags: Vec<String>,
+            }
+        };
+        let generated = impl_configuration_derive(&input).to_string();
+        assert!(generated.contains("compile_error"));
+    }
+
+    #[test]
+    fn test_config_default_literal_mismatches() {
+        let mismatches: [(DeriveInput, &str); 5] = [
+            (
+                parse_quote! {
+                    #[configuration("server")]
+                    struct ServerConfig {
+                        #[config_default("localhost")]
+                        port: Option<u16>,
+                    }
+                },
+                "expected an integer",
+            ),
+            (
+                parse_quote! {
+                    #[configuration("server")]
+                    struct ServerConfig {
+                        #[config_default(1)]
+                        enabled: bool,
+                    }
+                },
+                "expected a boolean",
+            ),
+            (
+                parse_quote! {
+                    #[configuration("server")]
+                    struct ServerConfig {
+                        #[config_default(true)]
+                        workers: Option<usize>,
+                    }
+                },
+                "expected an integer",
+            ),
+            (
+                parse_quote! {
+                    #[configuration("server")]
+                    struct ServerConfig {
+                        #[config_default(1)]
+                        ratio: f64,
+                    }
+                },
+                "write it as a float",
+            ),
+            (
+                parse_quote! {
+                    #[configuration("server")]
+                    struct ServerConfig {
+                        #[config_default(-1)]
+                        port: Option<u16>,
+                    }
+                },
+                "must not be negative",
+            ),
+        ];
+
+        for (input, message) in &mismatches {
+            let generated = impl_configuration_derive(input).to_string();
+            assert!(generated.contains("compile_error"), "{}", generated);
+            assert!(generated.contains(message), "{}", generated);
+        }
+
+        let input: DeriveInput = parse_quote! {
+            #[configuration("log")]
+            struct LogConfig {
+                #[config_default("info")]
+                level: Option<LogLevel>,
+                #[config_default("30s")]
+                flush: Duration,
+                #[config_default(-0.5)]
+                offset: f32,
+                #[config_default(false)]
+                verbose: Option<bool>,
+            }
+        };
+        let generated = impl_configuration_derive(&input).to_string();
+        assert!(!generated.contains("compile_error"), "{}", generated);
+        assert!(
+            generated.contains("level : Some (match \"info\" . parse :: < LogLevel > ()"),
+            "{}",
+            generated
+        );
+        assert!(generated.contains("invalid config_default \\\"info\\\" for field `level`"));
+        assert!(
+            generated.contains("flush : match :: verdure :: config :: parse_duration (& \"30s\")")
+        );
     }
-    None
 }
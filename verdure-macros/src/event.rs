@@ -0,0 +1,113 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Attribute, DeriveInput, Error, LitStr};
+
+pub(crate) fn impl_event_derive(input: &DeriveInput) -> TokenStream {
+    let name = match find_event_name(&input.attrs) {
+        Ok(Some(name)) => name,
+        Ok(None) => LitStr::new(&input.ident.to_string(), input.ident.span()),
+        Err(err) => return err.to_compile_error(),
+    };
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics ::verdure::event::Event for #ident #ty_generics #where_clause {
+            fn name(&self) -> &'static str {
+                #name
+            }
+
+            fn as_any(&self) -> &dyn ::std::any::Any {
+                self
+            }
+
+            fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn ::std::any::Any> {
+                self
+            }
+        }
+    }
+}
+
+fn find_event_name(attrs: &[Attribute]) -> Result<Option<LitStr>, Error> {
+    let mut name = None;
+    for attr in attrs {
+        if !attr.path().is_ident("event") {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("name") {
+                let value: LitStr = meta.value()?.parse()?;
+                if value.value().trim().is_empty() {
+                    return Err(Error::new_spanned(&value, "event name must not be empty"));
+                }
+                name = Some(value);
+                Ok(())
+            } else {
+                Err(meta.error("unsupported event attribute, expected `name`"))
+            }
+        })?;
+    }
+    Ok(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use syn::parse_quote;
+
+    #[test]
+    fn test_event_name_defaults_to_ident() {
+        let input: DeriveInput = parse_quote! {
+            struct UserRegistered {
+                user_id: u64,
+            }
+        };
+
+        let output = impl_event_derive(&input).to_string();
+        assert!(output.contains(":: verdure :: event :: Event for UserRegistered"));
+        assert!(output.contains("\"UserRegistered\""));
+    }
+
+    #[test]
+    fn test_event_name_attribute() {
+        let input: DeriveInput = parse_quote! {
+            #[event(name = "user.registered")]
+            struct UserRegistered;
+        };
+
+        let output = impl_event_derive(&input).to_string();
+        assert!(output.contains("\"user.registered\""));
+    }
+
+    #[test]
+    fn test_event_generics() {
+        let input: DeriveInput = parse_quote! {
+            struct Changed<T: Send + Sync + 'static> {
+                value: T,
+            }
+        };
+
+        let output = impl_event_derive(&input).to_string();
+        assert!(output.contains("impl < T : Send + Sync + 'static >"));
+        assert!(output.contains("for Changed < T >"));
+    }
+
+    #[test]
+    fn test_event_attribute_errors() {
+        let input: DeriveInput = parse_quote! {
+            #[event(name = "")]
+            struct Empty;
+        };
+        let output = impl_event_derive(&input).to_string();
+        assert!(output.contains("event name must not be empty"));
+
+        let input: DeriveInput = parse_quote! {
+            #[event(title = "Other")]
+            struct Unknown;
+        };
+        let output = impl_event_derive(&input).to_string();
+        assert!(output.contains("unsupported event attribute"));
+    }
+}
@@ -11,6 +11,7 @@
 //! # Macros
 //!
 //! * `#[derive(Component)]` - Automatically implements `ComponentInitializer` and registers the component
+//! * `#[derive(Event)]` - Implements the context `Event` trait for an event type
 //!
 //! # Attributes
 //!
@@ -19,7 +20,7 @@
 //!
 //! # Examples
 //!
-//! ```rust
+//! ```rust,ignore
 //! use verdure::Component;
 //! use std::sync::Arc;
 //!
@@ -45,6 +46,7 @@
 
 mod component;
 mod configuration;
+mod event;
 
 use proc_macro::TokenStream;
 use syn::{DeriveInput, parse_macro_input};
@@ -59,16 +61,21 @@ use syn::{DeriveInput, parse_macro_input};
 ///
 /// * `#[autowired]` - Marks a field for automatic dependency injection. The field must be of type `Arc<T>`
 /// * `#[component(scope = "...")]` - Sets the component scope (defaults to `Singleton`)
+/// * `#[component(order = N)]` - Creates independent components in ascending order (defaults to `0`)
+/// * `#[component(register = false)]` - Only implements `ComponentInitializer`, without
+///   registering the component for discovery; register an instance manually instead
 ///
 /// # Field Initialization Rules
 ///
-/// 1. **Autowired fields**: Automatically injected by the container
+/// 1. **Autowired fields**: Automatically injected by the container. Under an
+///    `ApplicationContext` this includes `Arc<ConfigManager>`, which is registered before
+///    any component is created
 /// 2. **Option fields**: Initialized to `None`
 /// 3. **Other fields**: Initialized using `Default::default()`
 ///
 /// # Examples
 ///
-/// ```rust
+/// ```rust,ignore
 /// use verdure::Component;
 /// use std::sync::Arc;
 ///
@@ -107,7 +114,7 @@ use syn::{DeriveInput, parse_macro_input};
 ///
 /// The macro will produce compile-time errors in the following cases:
 ///
-/// * Applying to enums or unions (only structs with named fields are supported)
+/// * Applying to enums, unions or tuple structs (only unit structs and structs with named fields are supported)
 /// * Using `#[autowired]` on fields that are not `Arc<T>`
 /// * Invalid syntax in component attributes
 #[proc_macro_derive(Component, attributes(component, autowired))]
@@ -116,8 +123,40 @@ pub fn component_derive(input: TokenStream) -> TokenStream {
     component::impl_component_derive(&ast).into()
 }
 
-#[proc_macro_derive(Configuration, attributes(configuration, config_default, config_default_t))]
+#[proc_macro_derive(Configuration, attributes(configuration, config_default, config_default_t, config_map))]
 pub fn configuration_derive(input: TokenStream) -> TokenStream {
     let ast = parse_macro_input!(input as DeriveInput);
     configuration::impl_configuration_derive(&ast).into()
-}
\ No newline at end of file
+}
+
+/// Derive macro for application context events
+///
+/// Generates the `Event` implementation, including the `as_any`/`into_any` downcasting
+/// helpers. The event name defaults to the type's identifier and can be overridden
+/// with `#[event(name = "...")]`.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use verdure::Event;
+///
+/// #[derive(Debug, Clone, Event)]
+/// #[event(name = "UserRegistered")]
+/// struct UserRegisteredEvent {
+///     user_id: u64,
+/// }
+///
+/// // name() returns "ConfigReloaded"
+/// #[derive(Debug, Clone, Event)]
+/// struct ConfigReloaded;
+/// ```
+///
+/// # Panics
+///
+/// The macro will produce compile-time errors for an empty name or unknown keys in the
+/// `#[event]` attribute.
+#[proc_macro_derive(Event, attributes(event))]
+pub fn event_derive(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+    event::impl_event_derive(&ast).into()
+}
@@ -154,9 +154,7 @@
 //!     context.initialize()?;
 //!     
 //!     // Get your services with all dependencies and config injected
-//!     let user_service: Arc<UserService> = context
-//!         .get_component()
-//!         .ok_or("UserService not found")?;
+//!     let user_service: Arc<UserService> = context.require_component()?;
 //!     
 //!     // Your application is ready!
 //!     Ok(())
@@ -182,9 +180,7 @@
 //!     let container = ComponentContainer::new();
 //!     container.initialize()?;
 //!     
-//!     let user_service: Arc<UserService> = container
-//!         .get_component()
-//!         .ok_or("UserService not found")?;
+//!     let user_service: Arc<UserService> = container.get_component_required()?;
 //!     
 //!     Ok(())
 //! }
@@ -236,19 +232,33 @@
 // Re-export the Component derive macro
 pub use verdure_macros::Component;
 pub use verdure_macros::Configuration;
+pub use verdure_macros::Event;
 
 // Re-export error handling types
 pub use verdure_core::error;
 
+// Re-export the logging hook types
+pub use verdure_core::logging;
+
 // Re-export the lifecycle_listener macro
 pub use verdure_ioc::lifecycle_listener;
 
+// Re-export the context_event_listener macro
+pub use verdure_context::context_event_listener;
+
 // Re-export all IoC container types and traits
 pub use verdure_ioc::{
     ComponentContainer, ComponentDefinition, ComponentFactory, ComponentInitializer,
-    ComponentInstance, ComponentScope, ContainerLifecycleEvent, LifecycleEventPublisher,
-    LifecycleListener, LifecycleListenerDefinition,
+    ComponentInstance, ComponentOrigin, ComponentScope, ContainerLifecycleEvent,
+    LifecycleEventPublisher, LifecycleEventRecord, LifecycleListener, LifecycleListenerDefinition,
+    ScopeContext,
 };
 
+// Re-export the isolated test container
+#[cfg(feature = "test-support")]
+pub use verdure_ioc::IsolatedContainer;
+
 // Re-export context module types and traits
-pub use verdure_context::{ApplicationContext, ContextResult, event, config};
+pub use verdure_context::{
+    ApplicationContext, ApplicationContextRef, ContextError, ContextResult, config, event,
+};
@@ -0,0 +1,77 @@
+use std::sync::Arc;
+use verdure::{Component, ComponentContainer, ComponentFactory};
+
+#[derive(Component)]
+struct DeriveTestUnitRepository;
+
+#[derive(Component)]
+struct DeriveTestEmpty {}
+
+#[derive(Component)]
+struct DeriveTestService {
+    #[autowired]
+    repository: Arc<DeriveTestUnitRepository>,
+    #[autowired]
+    empty: Arc<DeriveTestEmpty>,
+}
+
+#[test]
+fn test_unit_and_empty_structs_are_components() {
+    let container = ComponentContainer::new();
+    container.initialize().unwrap();
+
+    let repository: Arc<DeriveTestUnitRepository> = container.get_component().unwrap();
+    let empty: Arc<DeriveTestEmpty> = container.get_component().unwrap();
+    let service: Arc<DeriveTestService> = container.get_component().unwrap();
+
+    assert!(Arc::ptr_eq(&service.repository, &repository));
+    assert!(Arc::ptr_eq(&service.empty, &empty));
+}
+
+#[test]
+fn test_test_container_skips_derived_components() {
+    let container = ComponentContainer::test();
+    container.register_component(Arc::new(DeriveTestUnitRepository));
+    container.initialize().unwrap();
+
+    assert_eq!(container.component_count(), 1);
+    assert!(container.get_component::<DeriveTestService>().is_none());
+    assert!(
+        container
+            .get_component::<DeriveTestUnitRepository>()
+            .is_some()
+    );
+}
+
+#[derive(Component)]
+#[component(scope = "prototype")]
+struct DeriveTestPrototype;
+
+#[derive(Component)]
+struct DeriveTestFirstUser {
+    #[autowired]
+    prototype: Arc<DeriveTestPrototype>,
+}
+
+#[derive(Component)]
+struct DeriveTestSecondUser {
+    #[autowired]
+    prototype: Arc<DeriveTestPrototype>,
+}
+
+#[test]
+fn test_scope_attribute_ignores_case() {
+    let container = ComponentContainer::new();
+    container.initialize().unwrap();
+
+    let first: Arc<DeriveTestFirstUser> = container.get_component().unwrap();
+    let second: Arc<DeriveTestSecondUser> = container.get_component().unwrap();
+    assert!(!Arc::ptr_eq(&first.prototype, &second.prototype));
+    assert!(container.get_component::<DeriveTestPrototype>().is_none());
+}
+
+#[test]
+fn test_component_attribute_errors() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/component_*.rs");
+}
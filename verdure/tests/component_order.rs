@@ -0,0 +1,70 @@
+use std::any::TypeId;
+use std::sync::{Arc, Mutex};
+use verdure::{Component, ComponentContainer, ComponentDefinition, ComponentScope};
+
+static CREATED: Mutex<Vec<&'static str>> = Mutex::new(Vec::new());
+
+struct Metrics;
+struct Logging;
+struct Tracing;
+
+inventory::submit! {
+    ComponentDefinition::new(
+        TypeId::of::<Metrics>,
+        "Metrics",
+        || ComponentScope::Singleton,
+        Vec::new,
+        |_deps| {
+            CREATED.lock().unwrap().push("Metrics");
+            Ok(Arc::new(Metrics))
+        },
+    )
+    .with_order(|| 10)
+}
+
+inventory::submit! {
+    ComponentDefinition::new(
+        TypeId::of::<Logging>,
+        "Logging",
+        || ComponentScope::Singleton,
+        Vec::new,
+        |_deps| {
+            CREATED.lock().unwrap().push("Logging");
+            Ok(Arc::new(Logging))
+        },
+    )
+    .with_order(|| -10)
+}
+
+// Ordered last, but EarlyService depends on it: dependency edges win over order
+inventory::submit! {
+    ComponentDefinition::new(
+        TypeId::of::<Tracing>,
+        "Tracing",
+        || ComponentScope::Singleton,
+        Vec::new,
+        |_deps| {
+            CREATED.lock().unwrap().push("Tracing");
+            Ok(Arc::new(Tracing))
+        },
+    )
+    .with_order(|| 100)
+}
+
+#[derive(Component)]
+#[component(order = -20)]
+struct EarlyService {
+    #[autowired]
+    _tracing: Arc<Tracing>,
+}
+
+#[test]
+fn test_components_are_created_by_order() {
+    let container = ComponentContainer::new();
+    container.initialize().unwrap();
+
+    assert_eq!(
+        *CREATED.lock().unwrap(),
+        vec!["Tracing", "Logging", "Metrics"]
+    );
+}
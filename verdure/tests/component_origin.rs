@@ -0,0 +1,24 @@
+use std::sync::Arc;
+use verdure::{Component, ComponentContainer};
+
+#[derive(Component)]
+struct OriginCycleA {
+    #[autowired]
+    _b: Arc<OriginCycleB>,
+}
+
+#[derive(Component)]
+struct OriginCycleB {
+    #[autowired]
+    _a: Arc<OriginCycleA>,
+}
+
+#[test]
+fn test_circular_dependency_error_names_origin() {
+    let container = ComponentContainer::new();
+    let message = container.initialize().err().unwrap().to_string();
+
+    assert!(message.contains("OriginCycle"));
+    assert!(message.contains("declared in component_origin at"));
+    assert!(message.contains("tests/component_origin.rs"));
+}
@@ -0,0 +1,81 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use verdure::config::{ConfigContainerExt, ConfigManager, ConfigSource};
+use verdure::{ApplicationContext, Component, ComponentContainer, ComponentFactory, Configuration};
+
+#[derive(Component)]
+struct GreetingService {
+    #[autowired]
+    config: Arc<ConfigManager>,
+}
+
+impl GreetingService {
+    fn greeting(&self) -> String {
+        format!(
+            "Hello from {}",
+            self.config.get_string_or_default("app.name", "unknown")
+        )
+    }
+}
+
+#[test]
+fn test_component_autowires_config_manager() {
+    let context = ApplicationContext::builder()
+        .with_property("app.name", "Verdure")
+        .build()
+        .unwrap();
+    context.initialize().unwrap();
+
+    let service: Arc<GreetingService> = context.get_component().unwrap();
+    assert!(Arc::ptr_eq(&service.config, &context.config_manager()));
+    assert_eq!(service.greeting(), "Hello from Verdure");
+}
+
+#[derive(Debug, Configuration)]
+#[configuration("greeting")]
+struct GreetingConfig {
+    #[config_default("Hello")]
+    prefix: String,
+}
+
+#[derive(Component)]
+struct Greeter {
+    #[autowired]
+    config: Arc<GreetingConfig>,
+}
+
+#[test]
+fn test_container_initializes_config_components_without_context() {
+    let manager = ConfigManager::new();
+    let mut props = HashMap::new();
+    props.insert("greeting.prefix".to_string(), "Hi".to_string());
+    manager.add_source(ConfigSource::Properties(props)).unwrap();
+    let manager = Arc::new(manager);
+
+    let container = ComponentContainer::new();
+    container.initialize_with_config(manager.clone()).unwrap();
+
+    let greeter: Arc<Greeter> = container.get_component().unwrap();
+    assert_eq!(greeter.config.prefix, "Hi");
+
+    let service: Arc<GreetingService> = container.get_component().unwrap();
+    assert!(Arc::ptr_eq(&service.config, &manager));
+}
+
+#[test]
+fn test_component_autowires_configuration() {
+    let context = ApplicationContext::builder().build().unwrap();
+    context.initialize().unwrap();
+    let greeter: Arc<Greeter> = context.get_component().unwrap();
+    assert_eq!(greeter.config.prefix, "Hello");
+
+    let context = ApplicationContext::builder()
+        .with_property("greeting.prefix", "Howdy")
+        .build()
+        .unwrap();
+    context.initialize().unwrap();
+    let greeter: Arc<Greeter> = context.get_component().unwrap();
+    let config: Arc<GreetingConfig> = context.get_component().unwrap();
+    assert_eq!(greeter.config.prefix, "Howdy");
+    assert!(Arc::ptr_eq(&greeter.config, &config));
+}
@@ -0,0 +1,343 @@
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+use verdure::Configuration;
+use verdure::ContextError;
+use verdure::config::{ConfigInitializer, ConfigManager, ConfigSource};
+
+#[derive(Debug, Configuration)]
+#[configuration("features")]
+struct FeatureConfig {
+    name: Option<String>,
+    #[config_map]
+    flags: HashMap<String, String>,
+}
+
+fn manager_with(props: &[(&str, &str)]) -> Arc<ConfigManager> {
+    let manager = ConfigManager::new();
+    let props = props
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect();
+    manager.add_source(ConfigSource::Properties(props)).unwrap();
+    Arc::new(manager)
+}
+
+#[test]
+fn test_config_map_collects_prefixed_keys() {
+    let manager = manager_with(&[
+        ("features.name", "rollout"),
+        ("features.flags.beta", "on"),
+        ("features.flags.dark_mode", "off"),
+        ("features.flags.nested.key", "deep"),
+        ("other.flags.ignored", "x"),
+    ]);
+
+    let config = FeatureConfig::from_config_manager(manager).unwrap();
+
+    assert_eq!(config.name.as_deref(), Some("rollout"));
+    assert_eq!(config.flags.len(), 3);
+    assert_eq!(config.flags["beta"], "on");
+    assert_eq!(config.flags["dark_mode"], "off");
+    assert_eq!(config.flags["nested.key"], "deep");
+}
+
+#[test]
+fn test_config_map_empty_when_absent() {
+    let manager = manager_with(&[("features.name", "rollout")]);
+
+    let config = FeatureConfig::from_config_manager(manager).unwrap();
+
+    assert!(config.flags.is_empty());
+}
+
+#[derive(Debug, Configuration)]
+#[configuration("limits")]
+struct LimitsConfig {
+    #[config_default(-1)]
+    retries: Option<i32>,
+    #[config_default(8080u16)]
+    port: Option<u32>,
+    #[config_default(0)]
+    workers: Option<u8>,
+}
+
+#[test]
+fn test_config_default_negative_and_typed_integers() {
+    let manager = manager_with(&[]);
+
+    let config = LimitsConfig::from_config_manager(manager).unwrap();
+
+    assert_eq!(config.retries, Some(-1));
+    assert_eq!(config.port, Some(8080));
+    assert_eq!(config.workers, Some(0));
+}
+
+#[test]
+fn test_config_default_overridden_by_source() {
+    let manager = manager_with(&[("limits.retries", "3"), ("limits.port", "9090")]);
+
+    let config = LimitsConfig::from_config_manager(manager).unwrap();
+
+    assert_eq!(config.retries, Some(3));
+    assert_eq!(config.port, Some(9090));
+    assert_eq!(config.workers, Some(0));
+}
+
+#[derive(Debug, Configuration)]
+#[configuration("required")]
+struct RequiredFieldsConfig {
+    #[config_default(8080)]
+    port: u16,
+    #[config_default("localhost")]
+    host: String,
+    #[config_default_t(vec!["api".to_string()])]
+    tags: Vec<String>,
+    #[config_default_t(Some(30))]
+    timeout: Option<u64>,
+    workers: usize,
+}
+
+#[test]
+fn test_required_fields_use_defaults() {
+    let config = RequiredFieldsConfig::from_config_manager(manager_with(&[])).unwrap();
+
+    assert_eq!(config.port, 8080);
+    assert_eq!(config.host, "localhost");
+    assert_eq!(config.tags, vec!["api".to_string()]);
+    assert_eq!(config.timeout, Some(30));
+    assert_eq!(config.workers, 0);
+}
+
+#[test]
+fn test_required_fields_bind_from_source() {
+    let manager = manager_with(&[
+        ("required.port", "9090"),
+        ("required.host", "example.com"),
+        ("required.tags", "web, admin"),
+        ("required.workers", "4"),
+    ]);
+
+    let config = RequiredFieldsConfig::from_config_manager(manager).unwrap();
+
+    assert_eq!(config.port, 9090);
+    assert_eq!(config.host, "example.com");
+    assert_eq!(config.tags, vec!["web".to_string(), "admin".to_string()]);
+    assert_eq!(config.timeout, Some(30));
+    assert_eq!(config.workers, 4);
+
+    let err = RequiredFieldsConfig::from_config_manager(manager_with(&[("required.port", "x")]))
+        .unwrap_err();
+    assert!(matches!(err, ContextError::InvalidConfiguration { .. }));
+}
+
+#[derive(Debug, Configuration)]
+#[configuration("http")]
+struct HttpConfig {
+    #[config_default_t(vec!["a".to_string(), "b".to_string()])]
+    routes: Vec<String>,
+    #[config_default_t(Duration::from_secs(30))]
+    timeout: Duration,
+    #[config_default_t(Some(Duration::from_millis(250)))]
+    idle_timeout: Option<Duration>,
+    #[config_default_t(vec![Duration::from_millis(100), Duration::from_secs(1)])]
+    backoff: Vec<Duration>,
+    #[config_default_t(SocketAddr::from(([127, 0, 0, 1], 8080)))]
+    bind: SocketAddr,
+}
+
+#[test]
+fn test_expression_defaults_apply_when_keys_are_absent() {
+    let config = HttpConfig::from_config_manager(manager_with(&[])).unwrap();
+
+    assert_eq!(config.routes, vec!["a".to_string(), "b".to_string()]);
+    assert_eq!(config.timeout, Duration::from_secs(30));
+    assert_eq!(config.idle_timeout, Some(Duration::from_millis(250)));
+    assert_eq!(
+        config.backoff,
+        vec![Duration::from_millis(100), Duration::from_secs(1)]
+    );
+    assert_eq!(config.bind, SocketAddr::from(([127, 0, 0, 1], 8080)));
+}
+
+#[test]
+fn test_duration_fields_read_units() {
+    let manager = manager_with(&[
+        ("http.timeout", "2m"),
+        ("http.idle_timeout", "1500ms"),
+        ("http.backoff", "1s, 5s"),
+        ("http.bind", "0.0.0.0:9000"),
+    ]);
+
+    let config = HttpConfig::from_config_manager(manager).unwrap();
+    assert_eq!(config.timeout, Duration::from_secs(120));
+    assert_eq!(config.idle_timeout, Some(Duration::from_millis(1500)));
+    assert_eq!(
+        config.backoff,
+        vec![Duration::from_secs(1), Duration::from_secs(5)]
+    );
+    assert_eq!(config.bind, SocketAddr::from(([0, 0, 0, 0], 9000)));
+
+    let err =
+        HttpConfig::from_config_manager(manager_with(&[("http.timeout", "soon")])).unwrap_err();
+    assert!(matches!(err, ContextError::InvalidConfiguration { .. }));
+}
+
+#[derive(Debug, Configuration)]
+#[configuration("listing")]
+struct ListConfig {
+    features: Option<Vec<String>>,
+    ports: Option<Vec<u16>>,
+    #[config_default_t(Some(vec![1]))]
+    fallback: Option<Vec<u8>>,
+}
+
+#[test]
+fn test_vec_fields_bind_from_comma_joined_values() {
+    let manager = manager_with(&[
+        ("listing.features", "auth, logging,metrics"),
+        ("listing.ports", "8080,8081"),
+    ]);
+
+    let config = ListConfig::from_config_manager(manager).unwrap();
+
+    assert_eq!(
+        config.features,
+        Some(vec![
+            "auth".to_string(),
+            "logging".to_string(),
+            "metrics".to_string()
+        ])
+    );
+    assert_eq!(config.ports, Some(vec![8080, 8081]));
+    assert_eq!(config.fallback, Some(vec![1]));
+}
+
+#[test]
+fn test_vec_field_rejects_unparsable_item() {
+    let manager = manager_with(&[("listing.ports", "8080,http")]);
+
+    let err = ListConfig::from_config_manager(manager).unwrap_err();
+
+    assert!(matches!(
+        &err,
+        ContextError::InvalidConfiguration { key, .. } if key == "listing.ports"
+    ));
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum LogLevel {
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+impl FromStr for LogLevel {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "debug" => Ok(LogLevel::Debug),
+            "info" => Ok(LogLevel::Info),
+            "warn" => Ok(LogLevel::Warn),
+            "error" => Ok(LogLevel::Error),
+            other => Err(format!("unknown log level '{}'", other)),
+        }
+    }
+}
+
+#[derive(Debug, Configuration)]
+#[configuration("log")]
+struct LogConfig {
+    #[config_default_t(Some(LogLevel::Info))]
+    level: Option<LogLevel>,
+    #[config_default("error")]
+    alert_level: LogLevel,
+    #[config_default("5s")]
+    flush_interval: Option<Duration>,
+}
+
+#[test]
+fn test_enum_field_binds_from_string() {
+    let config = LogConfig::from_config_manager(manager_with(&[])).unwrap();
+    assert_eq!(config.level, Some(LogLevel::Info));
+    assert_eq!(config.alert_level, LogLevel::Error);
+    assert_eq!(config.flush_interval, Some(Duration::from_secs(5)));
+
+    let config = LogConfig::from_config_manager(manager_with(&[("log.level", "WARN")])).unwrap();
+    assert_eq!(config.level, Some(LogLevel::Warn));
+    assert_ne!(config.level, Some(LogLevel::Debug));
+    assert_ne!(config.level, Some(LogLevel::Error));
+}
+
+#[test]
+fn test_enum_field_rejects_unknown_variant() {
+    let manager = manager_with(&[("log.level", "verbose")]);
+
+    let err = LogConfig::from_config_manager(manager).unwrap_err();
+
+    match err {
+        ContextError::InvalidConfiguration { key, reason } => {
+            assert_eq!(key, "log.level");
+            assert!(reason.contains("unknown log level 'verbose'"));
+            assert!(reason.contains("in-memory properties"));
+        }
+        other => panic!("expected InvalidConfiguration, got {:?}", other),
+    }
+}
+
+#[derive(Debug, Configuration)]
+#[configuration("server")]
+struct StrictServerConfig {
+    #[config_default(8080)]
+    port: Option<u16>,
+    workers: Option<u8>,
+    name: Option<String>,
+}
+
+#[derive(Debug, Configuration)]
+#[configuration("server", lenient)]
+struct LenientServerConfig {
+    #[config_default(8080)]
+    port: Option<u16>,
+    workers: Option<u8>,
+}
+
+#[test]
+fn test_strict_config_reports_every_invalid_key() {
+    let manager = manager_with(&[
+        ("server.port", "eighty-eighty"),
+        ("server.workers", "many"),
+        ("server.name", "api"),
+    ]);
+
+    let err = StrictServerConfig::from_config_manager(manager).unwrap_err();
+
+    match err {
+        ContextError::InvalidConfiguration { key, reason } => {
+            assert_eq!(key, "server.port");
+            assert!(reason.contains("cannot parse 'eighty-eighty'"));
+            assert!(reason.contains("also invalid: server.workers"));
+        }
+        other => panic!("expected InvalidConfiguration, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_lenient_config_keeps_defaults() {
+    let manager = manager_with(&[("server.port", "eighty-eighty"), ("server.workers", "4")]);
+
+    let config = LenientServerConfig::from_config_manager(manager).unwrap();
+
+    assert_eq!(config.port, Some(8080));
+    assert_eq!(config.workers, Some(4));
+}
+
+#[test]
+fn test_config_default_type_errors() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/config_default_*.rs");
+}
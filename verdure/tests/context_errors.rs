@@ -0,0 +1,33 @@
+use std::error::Error;
+use std::sync::Arc;
+use verdure::error::container::{ContainerError, ContainerErrorKind};
+use verdure::{ApplicationContext, Component, ContextError};
+
+#[derive(Component)]
+struct BootCycleA {
+    #[autowired]
+    _b: Arc<BootCycleB>,
+}
+
+#[derive(Component)]
+struct BootCycleB {
+    #[autowired]
+    _a: Arc<BootCycleA>,
+}
+
+#[test]
+fn test_initialize_reports_container_error_kind() {
+    let context = ApplicationContext::new();
+    let error = context.initialize().unwrap_err();
+
+    assert!(matches!(
+        error,
+        ContextError::ContainerInitializationFailed {
+            kind: ContainerErrorKind::CircularDependency,
+            ..
+        }
+    ));
+    let source = error.source().unwrap();
+    assert!(source.downcast_ref::<ContainerError>().is_some());
+    assert!(error.to_string().contains("BootCycle"));
+}
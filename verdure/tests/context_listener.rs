@@ -0,0 +1,52 @@
+use std::sync::Mutex;
+use verdure::ApplicationContext;
+use verdure::context_event_listener;
+use verdure::event::{
+    ConfigurationChangedEvent, ContextAwareEventListener, ContextInitializedEvent,
+    ContextListenerDefinition, EventListener,
+};
+
+static STARTED: Mutex<Vec<String>> = Mutex::new(Vec::new());
+static CHANGED: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+struct StartupRecorder;
+
+impl ContextAwareEventListener<ContextInitializedEvent> for StartupRecorder {
+    fn on_context_event(&self, _event: &ContextInitializedEvent, context: &ApplicationContext) {
+        STARTED.lock().unwrap().push(context.get_config("app.name"));
+    }
+}
+
+struct ChangeRecorder;
+
+impl EventListener<ConfigurationChangedEvent> for ChangeRecorder {
+    fn on_event(&self, event: &ConfigurationChangedEvent) {
+        CHANGED.lock().unwrap().push(event.key.clone());
+    }
+}
+
+context_event_listener!("startup_recorder", context_aware StartupRecorder);
+context_event_listener!("change_recorder", ChangeRecorder);
+
+#[test]
+fn test_registered_listeners_are_subscribed() {
+    let names: Vec<_> = inventory::iter::<ContextListenerDefinition>
+        .into_iter()
+        .map(|definition| definition.name)
+        .collect();
+    assert!(names.contains(&"startup_recorder"));
+    assert!(names.contains(&"change_recorder"));
+
+    let context = ApplicationContext::builder()
+        .with_property("app.name", "Discovered")
+        .build()
+        .unwrap();
+    context.initialize().unwrap();
+    context.set_config("feature.enabled", "true");
+
+    assert_eq!(*STARTED.lock().unwrap(), vec!["Discovered".to_string()]);
+    assert_eq!(
+        *CHANGED.lock().unwrap(),
+        vec!["feature.enabled".to_string()]
+    );
+}
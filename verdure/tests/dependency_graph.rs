@@ -0,0 +1,59 @@
+use std::sync::Arc;
+use verdure::{Component, ComponentContainer};
+
+#[derive(Component)]
+struct GraphTestDatabase {
+    url: String,
+}
+
+#[derive(Component)]
+struct GraphTestCache {
+    size: usize,
+}
+
+#[derive(Component)]
+struct GraphTestUserService {
+    #[autowired]
+    database: Arc<GraphTestDatabase>,
+    #[autowired]
+    cache: Arc<GraphTestCache>,
+}
+
+#[test]
+fn test_dependency_graph_lists_dependencies_by_name() {
+    let container = ComponentContainer::new();
+    let graph = container.dependency_graph();
+
+    let (_, dependencies) = graph
+        .iter()
+        .find(|(name, _)| name == "GraphTestUserService")
+        .unwrap();
+    assert_eq!(dependencies, &["GraphTestDatabase", "GraphTestCache"]);
+
+    let (_, dependencies) = graph
+        .iter()
+        .find(|(name, _)| name == "GraphTestDatabase")
+        .unwrap();
+    assert!(dependencies.is_empty());
+}
+
+#[test]
+fn test_dependency_graph_to_dot() {
+    let dot = ComponentContainer::new().to_dot();
+
+    assert!(dot.contains("\"GraphTestUserService\" -> \"GraphTestDatabase\";"));
+    assert!(dot.contains("\"GraphTestUserService\" -> \"GraphTestCache\";"));
+    assert!(dot.ends_with("}\n"));
+}
+
+#[test]
+fn test_graph_components_resolve() {
+    let container = ComponentContainer::new();
+    container.initialize().unwrap();
+
+    let service = container
+        .get_component_required::<GraphTestUserService>()
+        .unwrap();
+    assert_eq!(service.database.url, "");
+    assert_eq!(service.cache.size, 0);
+}
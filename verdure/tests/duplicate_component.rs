@@ -0,0 +1,44 @@
+use std::any::TypeId;
+use std::sync::{Arc, Mutex};
+use verdure::logging::LogLevel;
+use verdure::{Component, ComponentContainer, ComponentDefinition, ComponentScope};
+
+#[derive(Component)]
+struct DuplicatedService;
+
+inventory::submit! {
+    ComponentDefinition::new(
+        TypeId::of::<DuplicatedService>,
+        "DuplicatedService",
+        || ComponentScope::Singleton,
+        Vec::new,
+        |_deps| Ok(Arc::new(DuplicatedService)),
+    )
+}
+
+#[test]
+fn test_duplicate_definition_is_logged() {
+    let warnings = Arc::new(Mutex::new(Vec::new()));
+    let container = ComponentContainer::new();
+    let sink = warnings.clone();
+    container.set_logger(move |level, message| {
+        if level == LogLevel::Warn {
+            sink.lock().unwrap().push(message.to_string());
+        }
+    });
+    container.initialize().unwrap();
+
+    let warnings = warnings.lock().unwrap();
+    let message = warnings
+        .iter()
+        .find(|message| message.contains("'DuplicatedService' is defined more than once"))
+        .unwrap();
+    assert!(message.contains("tests/duplicate_component.rs"));
+    assert!(message.contains("a manual definition"));
+}
+
+#[test]
+fn test_test_container_ignores_duplicates() {
+    let container = ComponentContainer::test();
+    assert!(container.initialize().is_ok());
+}
@@ -0,0 +1,29 @@
+use std::sync::{Arc, Mutex};
+use verdure::ComponentContainer;
+use verdure::logging::LogLevel;
+
+// This test binary declares no components, like an application that only uses the
+// configuration side of the framework
+
+#[test]
+fn test_initialize_without_components_warns() {
+    let container = ComponentContainer::new();
+    let messages = Arc::new(Mutex::new(Vec::new()));
+    let sink = messages.clone();
+    container.set_logger(move |level: LogLevel, message: &str| {
+        sink.lock().unwrap().push((level, message.to_string()));
+    });
+
+    assert_eq!(container.initialize().unwrap(), 0);
+    assert_eq!(container.initialize().unwrap(), 0);
+
+    let messages = messages.lock().unwrap();
+    assert!(
+        messages
+            .iter()
+            .any(|(level, message)| *level == LogLevel::Warn
+                && message.contains("0 components discovered")),
+        "{:?}",
+        *messages
+    );
+}
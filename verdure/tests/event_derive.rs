@@ -0,0 +1,67 @@
+use std::sync::{Arc, Mutex};
+use verdure::Event;
+use verdure::event::{Event as _, EventListener, EventPublisher};
+
+#[derive(Debug, Clone, Event)]
+#[event(name = "UserRegistered")]
+struct UserRegisteredEvent {
+    user_id: u64,
+}
+
+#[derive(Debug, Clone, Event)]
+struct CacheCleared;
+
+#[derive(Debug, Clone, Event)]
+struct ValueChanged<T: Clone + Send + Sync + 'static> {
+    value: T,
+}
+
+struct RecordingListener {
+    received: Arc<Mutex<Vec<u64>>>,
+}
+
+impl EventListener<UserRegisteredEvent> for RecordingListener {
+    fn on_event(&self, event: &UserRegisteredEvent) {
+        self.received.lock().unwrap().push(event.user_id);
+    }
+}
+
+#[test]
+fn test_event_names() {
+    assert_eq!(UserRegisteredEvent { user_id: 1 }.name(), "UserRegistered");
+    assert_eq!(CacheCleared.name(), "CacheCleared");
+    assert_eq!(ValueChanged { value: 1u8 }.name(), "ValueChanged");
+}
+
+#[test]
+fn test_derived_event_downcasts() {
+    let event = ValueChanged {
+        value: "new".to_string(),
+    };
+    let any = event.as_any();
+    assert_eq!(
+        any.downcast_ref::<ValueChanged<String>>().unwrap().value,
+        "new"
+    );
+
+    let boxed: Box<ValueChanged<String>> = Box::new(event.clone());
+    assert!(boxed.into_any().downcast::<ValueChanged<String>>().is_ok());
+}
+
+#[test]
+fn test_derived_event_is_published() {
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let publisher = EventPublisher::new();
+    publisher.subscribe(RecordingListener {
+        received: received.clone(),
+    });
+
+    assert_eq!(publisher.publish(&UserRegisteredEvent { user_id: 7 }), 1);
+    assert_eq!(*received.lock().unwrap(), vec![7]);
+}
+
+#[test]
+fn test_event_attribute_errors() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/event_*.rs");
+}
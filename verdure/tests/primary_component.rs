@@ -0,0 +1,106 @@
+use std::sync::Arc;
+use verdure::{Component, ComponentContainer, ComponentFactory};
+
+trait PaymentGateway: Send + Sync {
+    fn name(&self) -> &'static str;
+}
+
+#[derive(Component)]
+#[component(primary)]
+struct StripeGateway;
+
+impl PaymentGateway for StripeGateway {
+    fn name(&self) -> &'static str {
+        "stripe"
+    }
+}
+
+#[derive(Component)]
+struct SandboxGateway;
+
+impl PaymentGateway for SandboxGateway {
+    fn name(&self) -> &'static str {
+        "sandbox"
+    }
+}
+
+#[derive(Component)]
+struct CheckoutService {
+    #[autowired]
+    gateway: Arc<dyn PaymentGateway>,
+}
+
+#[test]
+fn test_primary_implementation_is_injected() {
+    let container = ComponentContainer::new();
+    container
+        .register_trait_component_of::<dyn PaymentGateway, _>(
+            "sandbox",
+            Arc::new(SandboxGateway),
+            |c| c,
+        )
+        .unwrap();
+    container
+        .register_trait_component_of::<dyn PaymentGateway, _>(
+            "stripe",
+            Arc::new(StripeGateway),
+            |c| c,
+        )
+        .unwrap();
+    container.initialize().unwrap();
+
+    let checkout: Arc<CheckoutService> = container.get_component().unwrap();
+    assert_eq!(checkout.gateway.name(), "stripe");
+
+    let gateway = container
+        .get_trait_component::<dyn PaymentGateway>()
+        .unwrap();
+    assert_eq!(gateway.name(), "stripe");
+    let sandbox = container
+        .get_qualified_trait_component::<dyn PaymentGateway>("sandbox")
+        .unwrap();
+    assert_eq!(sandbox.name(), "sandbox");
+}
+
+#[test]
+fn test_implementations_without_primary_are_ambiguous() {
+    let container = ComponentContainer::new();
+    for qualifier in ["sandbox", "sandbox-eu"] {
+        container
+            .register_trait_component_of::<dyn PaymentGateway, _>(
+                qualifier,
+                Arc::new(SandboxGateway),
+                |c| c,
+            )
+            .unwrap();
+    }
+
+    let message = container.initialize().err().unwrap().to_string();
+    assert!(
+        message.contains("2 implementations (sandbox, sandbox-eu)"),
+        "{}",
+        message
+    );
+    assert!(message.contains("mark one as primary"), "{}", message);
+}
+
+#[test]
+fn test_multiple_primary_implementations_are_rejected() {
+    let container = ComponentContainer::new();
+    for qualifier in ["stripe", "stripe-eu"] {
+        container
+            .register_trait_component_of::<dyn PaymentGateway, _>(
+                qualifier,
+                Arc::new(StripeGateway),
+                |c| c,
+            )
+            .unwrap();
+    }
+
+    let message = container.initialize().err().unwrap().to_string();
+    assert!(
+        message.contains("2 primary implementations (stripe, stripe-eu)"),
+        "{}",
+        message
+    );
+}
@@ -0,0 +1,37 @@
+use std::sync::Arc;
+use verdure::{Component, ComponentContainer, ComponentFactory};
+
+#[derive(Component)]
+struct ScopeTestRepository {
+    name: String,
+}
+
+#[derive(Component)]
+#[component(scope = "Request")]
+struct ScopeTestHandler {
+    #[autowired]
+    repository: Arc<ScopeTestRepository>,
+    handled: u32,
+}
+
+#[test]
+fn test_request_scoped_component_per_scope() {
+    let container = ComponentContainer::new();
+    container.initialize().unwrap();
+
+    // Request-scoped components are not created during initialization
+    assert!(container.get_component::<ScopeTestHandler>().is_none());
+    assert!(container.get_component::<ScopeTestRepository>().is_some());
+
+    let first = container.open_scope();
+    let a: Arc<ScopeTestHandler> = first.get_component().unwrap();
+    let b: Arc<ScopeTestHandler> = first.get_component().unwrap();
+    assert!(Arc::ptr_eq(&a, &b));
+    assert_eq!(a.handled, 0);
+    assert_eq!(a.repository.name, "");
+
+    let second = container.open_scope();
+    let c: Arc<ScopeTestHandler> = second.get_component().unwrap();
+    assert!(!Arc::ptr_eq(&a, &c));
+    assert!(Arc::ptr_eq(&a.repository, &c.repository));
+}
@@ -0,0 +1,59 @@
+use std::sync::Arc;
+use verdure::{Component, ComponentContainer, ComponentFactory};
+
+trait UserRepository: Send + Sync {
+    fn find_name(&self, id: u64) -> Option<String>;
+}
+
+struct InMemoryUserRepository;
+
+impl UserRepository for InMemoryUserRepository {
+    fn find_name(&self, id: u64) -> Option<String> {
+        (id == 1).then(|| "alice".to_string())
+    }
+}
+
+#[derive(Component)]
+struct UserService {
+    #[autowired]
+    repository: Arc<dyn UserRepository>,
+}
+
+#[test]
+fn test_autowire_trait_object() {
+    let container = ComponentContainer::new();
+    container
+        .register_trait_component::<dyn UserRepository>(
+            "in-memory",
+            Arc::new(InMemoryUserRepository),
+        )
+        .unwrap();
+    container.initialize().unwrap();
+
+    let service = container.get_component::<UserService>().unwrap();
+    assert_eq!(service.repository.find_name(1).as_deref(), Some("alice"));
+    assert!(service.repository.find_name(2).is_none());
+}
+
+#[test]
+fn test_autowire_trait_object_without_implementation() {
+    let container = ComponentContainer::new();
+    let message = container.initialize().err().unwrap().to_string();
+    assert!(message.contains("UserService"));
+}
+
+#[test]
+fn test_autowire_trait_object_with_ambiguous_implementations() {
+    let container = ComponentContainer::new();
+    for qualifier in ["primary", "replica"] {
+        container
+            .register_trait_component::<dyn UserRepository>(
+                qualifier,
+                Arc::new(InMemoryUserRepository),
+            )
+            .unwrap();
+    }
+
+    let message = container.initialize().err().unwrap().to_string();
+    assert!(message.contains("2 implementations (primary, replica)"));
+}
@@ -0,0 +1,7 @@
+use verdure::Component;
+
+#[derive(Component)]
+#[component(scope = "Session")]
+struct SessionCache;
+
+fn main() {}
@@ -0,0 +1,10 @@
+use verdure::Configuration;
+
+#[derive(Configuration)]
+#[configuration("server")]
+struct ServerConfig {
+    #[config_default(1)]
+    enabled: bool,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use verdure::Configuration;
+
+#[derive(Configuration)]
+#[configuration("server")]
+struct ServerConfig {
+    #[config_default("8080")]
+    port: Option<u16>,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+use verdure::Event;
+
+#[derive(Event)]
+#[event(name = "")]
+struct Unnamed;
+
+fn main() {}
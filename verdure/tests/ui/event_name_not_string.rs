@@ -0,0 +1,7 @@
+use verdure::Event;
+
+#[derive(Event)]
+#[event(name = 42)]
+struct Numbered;
+
+fn main() {}
@@ -0,0 +1,7 @@
+use verdure::Event;
+
+#[derive(Event)]
+#[event(title = "Other")]
+struct Titled;
+
+fn main() {}
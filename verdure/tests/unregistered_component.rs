@@ -0,0 +1,39 @@
+use std::sync::Arc;
+use verdure::{Component, ComponentContainer, ComponentFactory};
+
+// A test double, only used when registered explicitly
+#[derive(Component)]
+#[component(register = false)]
+struct InMemoryStore {
+    items: Vec<String>,
+}
+
+#[derive(Component)]
+struct ReportService {
+    #[autowired]
+    store: Arc<InMemoryStore>,
+}
+
+#[test]
+fn test_unregistered_component_is_not_discovered() {
+    let container = ComponentContainer::new();
+    assert_eq!(container.registered_type_names(), vec!["ReportService"]);
+
+    let error = container.initialize().err().unwrap();
+    assert!(error.to_string().contains("ReportService"), "{}", error);
+    assert!(container.get_component::<InMemoryStore>().is_none());
+}
+
+#[test]
+fn test_unregistered_component_can_be_registered_manually() {
+    let container = ComponentContainer::new();
+    let store = Arc::new(InMemoryStore {
+        items: vec!["daily".to_string()],
+    });
+    container.register_component(store.clone());
+    container.initialize().unwrap();
+
+    let report: Arc<ReportService> = container.get_component().unwrap();
+    assert!(Arc::ptr_eq(&report.store, &store));
+    assert_eq!(report.store.items, ["daily"]);
+}
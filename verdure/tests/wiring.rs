@@ -0,0 +1,46 @@
+use std::any::TypeId;
+use std::sync::Arc;
+use verdure::{Component, ComponentContainer};
+
+struct WiringTestClient;
+
+#[derive(Component)]
+struct WiringTestStore {
+    name: String,
+}
+
+#[derive(Component)]
+struct WiringTestService {
+    #[autowired]
+    store: Arc<WiringTestStore>,
+    #[autowired]
+    client: Arc<WiringTestClient>,
+}
+
+#[test]
+fn test_check_wiring_reports_dangling_dependencies() {
+    let container = ComponentContainer::new();
+
+    assert_eq!(
+        container.check_wiring(),
+        vec![("WiringTestService", TypeId::of::<WiringTestClient>())]
+    );
+}
+
+#[test]
+fn test_check_wiring_accepts_registered_instances() {
+    let container = ComponentContainer::new();
+    container.register_component(Arc::new(WiringTestClient));
+
+    assert!(container.check_wiring().is_empty());
+
+    container.initialize().unwrap();
+    let service: Arc<WiringTestService> = container.get_component_required().unwrap();
+    assert_eq!(service.store.name, "");
+    assert!(Arc::ptr_eq(
+        &service.client,
+        &container
+            .get_component_required::<WiringTestClient>()
+            .unwrap()
+    ));
+}